@@ -15,15 +15,24 @@ use signatory::{
 #[cfg(feature = "std")]
 use ring::rand::SystemRandom;
 #[cfg(feature = "std")]
+use signatory::batch::{self, BatchError, BatchItem, BatchVerifier};
+#[cfg(feature = "std")]
 use signatory::encoding::{
     self,
     pkcs8::{self, FromPkcs8, GeneratePkcs8},
+    Algorithm, FromSec1,
 };
 
 /// NIST P-384 public key
 pub type PublicKey = signatory::ecdsa::PublicKey<NistP384>;
 
-/// NIST P-384 ECDSA signer
+/// NIST P-384 ECDSA signer.
+///
+/// The signature format is chosen when the signer is constructed, and
+/// signatures can be converted to the other format with
+/// [`SignatureConversion`].
+///
+/// [`SignatureConversion`]: signatory::ecdsa::SignatureConversion
 pub struct Signer<S: Signature>(EcdsaSigner<S>);
 
 #[cfg(feature = "std")]
@@ -48,6 +57,32 @@ impl FromPkcs8 for Signer<FixedSignature> {
     }
 }
 
+#[cfg(feature = "std")]
+impl FromSec1 for Signer<Asn1Signature> {
+    /// Create a new ECDSA signer which produces ASN.1 DER signatures from a
+    /// SEC1 `ECPrivateKey` (which must include the public key)
+    fn from_sec1_der(bytes: &[u8]) -> Result<Self, encoding::Error> {
+        Ok(Signer(EcdsaSigner::from_sec1(
+            &ECDSA_P384_SHA384_ASN1_SIGNING,
+            Algorithm::NistP384,
+            bytes,
+        )?))
+    }
+}
+
+#[cfg(feature = "std")]
+impl FromSec1 for Signer<FixedSignature> {
+    /// Create a new ECDSA signer which produces fixed-width signatures from a
+    /// SEC1 `ECPrivateKey` (which must include the public key)
+    fn from_sec1_der(bytes: &[u8]) -> Result<Self, encoding::Error> {
+        Ok(Signer(EcdsaSigner::from_sec1(
+            &ECDSA_P384_SHA384_FIXED_SIGNING,
+            Algorithm::NistP384,
+            bytes,
+        )?))
+    }
+}
+
 #[cfg(feature = "std")]
 impl GeneratePkcs8 for Signer<Asn1Signature> {
     /// Randomly generate a P-384 **PKCS#8** keypair
@@ -122,17 +157,44 @@ impl signature::Verifier<FixedSignature> for Verifier {
     }
 }
 
+/// NIST P-384 ECDSA verifier which only accepts signatures in "low S" form.
+///
+/// *ring* accepts (and its signers produce) signatures with `s` in either
+/// half of the curve order, so use this verifier where malleable signatures
+/// must be rejected, and normalize signatures with [`LowS::to_low_s`].
+///
+/// [`LowS::to_low_s`]: signatory::ecdsa::LowS::to_low_s
+pub type StrictVerifier = signatory::ecdsa::StrictVerifier<Verifier>;
+
+#[cfg(feature = "std")]
+impl BatchVerifier<PublicKey, Asn1Signature> for Verifier {
+    fn verify_batch(items: &[BatchItem<'_, PublicKey, Asn1Signature>]) -> Result<(), BatchError> {
+        batch::verify_sequentially::<_, _, Self>(items)
+    }
+}
+
+#[cfg(feature = "std")]
+impl BatchVerifier<PublicKey, FixedSignature> for Verifier {
+    fn verify_batch(items: &[BatchItem<'_, PublicKey, FixedSignature>]) -> Result<(), BatchError> {
+        batch::verify_sequentially::<_, _, Self>(items)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{PublicKey, Signer, Verifier};
+    use super::{PublicKey, Signer, StrictVerifier, Verifier};
     use signatory::{
         ecdsa::{
             generic_array::GenericArray,
             nistp384::{
                 test_vectors::SHA384_FIXED_SIZE_TEST_VECTORS, Asn1Signature, FixedSignature,
+                SecretKey,
             },
         },
-        encoding::FromPkcs8,
+        encoding::{
+            spki::SubjectPublicKeyInfo, Algorithm, FromJwk, FromPkcs8, FromPublicKeyDer, ToJwk,
+            ToPublicKeyDer,
+        },
         public_key::PublicKeyed,
         signature::{Signature as _, Signer as _, Verifier as _},
         test_vector::{TestVectorAlgorithm, ToPkcs8},
@@ -217,6 +279,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn der_conversion_matches_ring() {
+        use signatory::ecdsa::SignatureConversion;
+
+        let vector = &SHA384_FIXED_SIZE_TEST_VECTORS[0];
+        let pkcs8 = vector.to_pkcs8(TestVectorAlgorithm::NistP384);
+        let asn1_signer = Signer::<Asn1Signature>::from_pkcs8(&pkcs8).unwrap();
+        let fixed_signer = Signer::<FixedSignature>::from_pkcs8(&pkcs8).unwrap();
+        let verifier = Verifier::from(&asn1_signer.public_key().unwrap());
+
+        for _ in 0..16 {
+            // *ring* only produces (and accepts) strict DER
+            let asn1_signature: Asn1Signature = asn1_signer.sign(vector.msg);
+            let parsed = Asn1Signature::from_der(asn1_signature.as_ref()).unwrap();
+            assert_eq!(parsed.as_ref(), asn1_signature.as_ref());
+
+            let fixed_signature = FixedSignature::from_der(asn1_signature.as_ref()).unwrap();
+            assert!(verifier.verify(vector.msg, &fixed_signature).is_ok());
+            assert_eq!(asn1_signature.to_fixed(), fixed_signature);
+
+            let fixed_signature: FixedSignature = fixed_signer.sign(vector.msg);
+            assert!(verifier
+                .verify(vector.msg, &fixed_signature.to_der())
+                .is_ok());
+        }
+    }
+
+    #[test]
+    fn strict_verifier_rejects_high_s() {
+        use signatory::ecdsa::LowS;
+
+        let vector = &SHA384_FIXED_SIZE_TEST_VECTORS[0];
+        let signer = Signer::from_pkcs8(&vector.to_pkcs8(TestVectorAlgorithm::NistP384)).unwrap();
+        let public_key = signer.public_key().unwrap();
+        let verifier = Verifier::from(&public_key);
+        let strict_verifier = StrictVerifier::from(&public_key);
+
+        // *ring* signatures are randomized, so sign until one has a high `s`
+        let signature: FixedSignature = (0..64)
+            .map(|_| signer.sign(vector.msg))
+            .find(|signature: &FixedSignature| !signature.is_low_s())
+            .expect("no high S signature");
+
+        assert!(verifier.verify(vector.msg, &signature).is_ok());
+        assert!(strict_verifier.verify(vector.msg, &signature).is_err());
+        assert!(strict_verifier
+            .verify(vector.msg, &signature.to_asn1())
+            .is_err());
+
+        let low_s = signature.to_low_s();
+        assert!(verifier.verify(vector.msg, &low_s).is_ok());
+        assert!(strict_verifier.verify(vector.msg, &low_s).is_ok());
+        assert!(strict_verifier.verify(vector.msg, &low_s.to_asn1()).is_ok());
+    }
+
     #[test]
     fn test_fixed_to_asn1_transformed_signature_verifies() {
         for vector in SHA384_FIXED_SIZE_TEST_VECTORS {
@@ -229,4 +346,49 @@ mod tests {
             assert!(verifier.verify(vector.msg, &asn1_signature).is_ok());
         }
     }
+
+    #[test]
+    fn spki_roundtrip() {
+        for vector in SHA384_FIXED_SIZE_TEST_VECTORS {
+            let public_key = PublicKey::from_untagged_point(&GenericArray::from_slice(vector.pk));
+            let der = public_key.to_public_key_der();
+            assert_eq!(PublicKey::from_public_key_der(&der).unwrap(), public_key);
+
+            let spki = SubjectPublicKeyInfo::from_der(&der).unwrap();
+            assert_eq!(spki.algorithm, Algorithm::NistP384);
+            assert_eq!(spki.subject_public_key, public_key.as_ref());
+        }
+    }
+
+    #[test]
+    fn spki_rejects_other_algorithms() {
+        let vector = &SHA384_FIXED_SIZE_TEST_VECTORS[0];
+        let public_key = PublicKey::from_untagged_point(&GenericArray::from_slice(vector.pk));
+        let der = SubjectPublicKeyInfo {
+            algorithm: Algorithm::NistP256,
+            subject_public_key: public_key.as_ref(),
+        }
+        .to_der();
+
+        assert!(PublicKey::from_public_key_der(&der).is_err());
+    }
+
+    #[test]
+    fn jwk_roundtrip() {
+        let vector = &SHA384_FIXED_SIZE_TEST_VECTORS[0];
+        let public_key = PublicKey::from_untagged_point(GenericArray::from_slice(vector.pk));
+        let secret_key = SecretKey::from_bytes(vector.sk).unwrap();
+
+        let jwk = secret_key.to_jwk().unwrap();
+        assert_eq!(jwk.public_key(), public_key.as_ref());
+        assert_eq!(jwk.private_key(), Some(vector.sk));
+        assert_eq!(jwk.kid(), public_key.to_jwk().unwrap().kid());
+
+        let parsed = SecretKey::from_jwk_json(&*jwk.to_json()).unwrap();
+        assert_eq!(parsed.as_bytes(), secret_key.as_bytes());
+
+        // The secret key must match the JWK's public key
+        let mismatched_jwk = jwk.with_private_key(&[1u8; 48]).unwrap();
+        assert!(SecretKey::from_jwk(&mismatched_jwk).is_err());
+    }
 }
@@ -11,6 +11,9 @@ use signatory::{
     signature::{self, Signature},
 };
 
+#[cfg(feature = "std")]
+use signatory::encoding::{pkcs8::PrivateKeyInfo, sec1::EcPrivateKey, Algorithm};
+
 /// Generic ECDSA signer which is wrapped with curve and signature-specific types
 pub(super) struct EcdsaSigner<S: Signature> {
     /// *ring* ECDSA keypair
@@ -45,6 +48,35 @@ where
         })
     }
 
+    /// Create an ECDSA signer from a SEC1 `ECPrivateKey`.
+    ///
+    /// *ring* can only load keypairs from **PKCS#8**, so the key is converted
+    /// to **PKCS#8** first, which requires it to include the public key.
+    #[cfg(feature = "std")]
+    pub fn from_sec1(
+        alg: &'static EcdsaSigningAlgorithm,
+        algorithm: Algorithm,
+        sec1_bytes: &[u8],
+    ) -> Result<Self, encoding::Error> {
+        let ec_private_key = EcPrivateKey::from_der_with_algorithm(sec1_bytes, algorithm)?;
+
+        if ec_private_key.public_key.is_none() {
+            return Err(encoding::Error::new(
+                encoding::error::ErrorKind::Decode,
+                Some("ECPrivateKey is missing public key"),
+            ));
+        }
+
+        let pkcs8_key = PrivateKeyInfo {
+            algorithm,
+            private_key: ec_private_key.private_key,
+            public_key: ec_private_key.public_key,
+        }
+        .to_der();
+
+        Self::from_pkcs8(alg, pkcs8_key.as_ref())
+    }
+
     /// Get the public key for this ECDSA signer
     pub fn public_key(&self) -> &[u8] {
         self.keypair.public_key().as_ref()
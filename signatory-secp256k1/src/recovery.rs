@@ -0,0 +1,146 @@
+//! Recoverable ECDSA signatures, which allow the signer's public key to be
+//! recovered from a signature and the signed message (as used by Ethereum)
+
+use super::{EcdsaSigner, PublicKey};
+use core::fmt::{self, Debug};
+use secp256k1::{
+    recovery::{self, RecoveryId},
+    Message, Secp256k1,
+};
+#[cfg(feature = "sha3")]
+use sha3::Keccak256;
+use signatory::{
+    sha2::Sha256,
+    signature::{self, digest::Digest, DigestSigner, Error, PrehashSignature},
+};
+
+/// Size of a recoverable signature in bytes (`r || s || v`)
+pub const RECOVERABLE_SIGNATURE_SIZE: usize = 65;
+
+/// ECDSA secp256k1 signature along with the recovery ID needed to recover
+/// the signer's public key, serialized as `r || s || v` (65 bytes).
+///
+/// `v` is the raw recovery ID (0-3). Ethereum's legacy transaction format
+/// encodes it as `v + 27` (or includes the chain ID), which must be undone
+/// before parsing.
+#[derive(Copy, Clone)]
+pub struct RecoverableSignature([u8; RECOVERABLE_SIGNATURE_SIZE]);
+
+impl RecoverableSignature {
+    /// Get the recovery ID (i.e. `v`)
+    pub fn recovery_id(&self) -> u8 {
+        self.0[64]
+    }
+
+    /// Convert signature into owned byte array
+    pub fn to_bytes(&self) -> [u8; RECOVERABLE_SIGNATURE_SIZE] {
+        self.0
+    }
+
+    /// Convert this signature into a `secp256k1::recovery::RecoverableSignature`
+    fn to_secp256k1(self) -> Result<recovery::RecoverableSignature, Error> {
+        let recovery_id =
+            RecoveryId::from_i32(i32::from(self.recovery_id())).map_err(Error::from_source)?;
+
+        recovery::RecoverableSignature::from_compact(&self.0[..64], recovery_id)
+            .map_err(Error::from_source)
+    }
+}
+
+impl signature::Signature for RecoverableSignature {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() == RECOVERABLE_SIGNATURE_SIZE && bytes[64] <= 3 {
+            let mut signature = [0u8; RECOVERABLE_SIGNATURE_SIZE];
+            signature.copy_from_slice(bytes);
+            Ok(RecoverableSignature(signature))
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+impl PrehashSignature for RecoverableSignature {
+    type Digest = Sha256;
+}
+
+impl AsRef<[u8]> for RecoverableSignature {
+    fn as_ref(&self) -> &[u8] {
+        &self.0[..]
+    }
+}
+
+impl Debug for RecoverableSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RecoverableSignature({:?})", self.as_ref())
+    }
+}
+
+// Arrays larger than 32 elements don't implement these traits on our MSRV
+impl Eq for RecoverableSignature {}
+
+impl PartialEq for RecoverableSignature {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl DigestSigner<Sha256, RecoverableSignature> for EcdsaSigner {
+    /// Compute a recoverable signature of the given 32-byte SHA-256 digest
+    fn try_sign_digest(&self, digest: Sha256) -> Result<RecoverableSignature, Error> {
+        self.raw_sign_digest_recoverable(digest)
+    }
+}
+
+#[cfg(feature = "sha3")]
+impl DigestSigner<Keccak256, RecoverableSignature> for EcdsaSigner {
+    /// Compute a recoverable signature of the given 32-byte Keccak-256 digest
+    fn try_sign_digest(&self, digest: Keccak256) -> Result<RecoverableSignature, Error> {
+        self.raw_sign_digest_recoverable(digest)
+    }
+}
+
+impl EcdsaSigner {
+    /// Sign a digest and produce a `RecoverableSignature`
+    fn raw_sign_digest_recoverable<D: Digest>(
+        &self,
+        digest: D,
+    ) -> Result<RecoverableSignature, Error> {
+        let msg = Message::from_slice(digest.finalize().as_slice()).map_err(Error::from_source)?;
+        let (recovery_id, compact) = self
+            .engine
+            .sign_recoverable(&msg, &self.secret_key)
+            .serialize_compact();
+
+        let mut signature = [0u8; RECOVERABLE_SIGNATURE_SIZE];
+        signature[..64].copy_from_slice(&compact);
+        signature[64] = recovery_id.to_i32() as u8;
+        Ok(RecoverableSignature(signature))
+    }
+}
+
+/// Recover the (compressed) public key which produced the given signature of
+/// the given message, hashing the message with SHA-256.
+///
+/// The signature is valid for the returned key, so it only needs to be
+/// checked against the expected signer (e.g. by comparing keys or addresses).
+pub fn recover_public_key(
+    msg: &[u8],
+    signature: &RecoverableSignature,
+) -> Result<PublicKey, Error> {
+    recover_public_key_from_digest(Sha256::new().chain(msg), signature)
+}
+
+/// Recover the (compressed) public key which produced the given signature of
+/// the message which has been input into the given 32-byte digest (e.g.
+/// `Keccak256` for Ethereum)
+pub fn recover_public_key_from_digest<D: Digest>(
+    digest: D,
+    signature: &RecoverableSignature,
+) -> Result<PublicKey, Error> {
+    let msg = Message::from_slice(digest.finalize().as_slice()).map_err(Error::from_source)?;
+    let public_key = Secp256k1::verification_only()
+        .recover(&msg, &signature.to_secp256k1()?)
+        .map_err(Error::from_source)?;
+
+    PublicKey::from_bytes(&public_key.serialize()[..]).ok_or_else(Error::new)
+}
@@ -0,0 +1,314 @@
+//! BIP-340 Schnorr signatures over secp256k1
+//!
+//! Signatures are 64 bytes and are verified against 32-byte "x-only" public
+//! keys, as used by Taproot. All hashes are tagged as described in BIP-340:
+//! <https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki>
+//!
+//! The secp256k1 crate doesn't support Schnorr signatures at the version
+//! we depend on, so this is implemented using the arithmetic in `k256`.
+
+use core::fmt::{self, Debug};
+use k256::{
+    elliptic_curve::{
+        subtle::{Choice, ConditionallySelectable},
+        weierstrass::public_key::FromPublicKey,
+        FromBytes,
+    },
+    AffinePoint, CompressedPoint, ElementBytes, ProjectivePoint, Scalar,
+};
+use signatory::{
+    batch::{self, BatchError, BatchItem, BatchVerifier},
+    ecdsa::secp256k1::{PublicKey, SecretKey},
+    public_key::PublicKeyed,
+    sha2::{Digest, Sha256},
+    signature::{self, Error},
+};
+use zeroize::Zeroize;
+
+/// Size of a BIP-340 Schnorr signature in bytes (the x-coordinate of `R`
+/// followed by the scalar `s`)
+pub const SIGNATURE_SIZE: usize = 64;
+
+/// Size of an x-only public key in bytes
+pub const PUBLIC_KEY_SIZE: usize = 32;
+
+/// Tag for hashing auxiliary random data
+const AUX_TAG: &[u8] = b"BIP0340/aux";
+
+/// Tag for deriving nonces
+const NONCE_TAG: &[u8] = b"BIP0340/nonce";
+
+/// Tag for computing challenges
+const CHALLENGE_TAG: &[u8] = b"BIP0340/challenge";
+
+/// BIP-340 Schnorr signatures
+#[derive(Copy, Clone)]
+pub struct Signature([u8; SIGNATURE_SIZE]);
+
+impl Signature {
+    /// Create a BIP-340 signature from a 64-byte array
+    pub fn new(bytes: [u8; SIGNATURE_SIZE]) -> Self {
+        Signature(bytes)
+    }
+
+    /// Convert signature into owned byte array
+    pub fn to_bytes(&self) -> [u8; SIGNATURE_SIZE] {
+        self.0
+    }
+}
+
+impl signature::Signature for Signature {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() == SIGNATURE_SIZE {
+            let mut signature = [0u8; SIGNATURE_SIZE];
+            signature.copy_from_slice(bytes);
+            Ok(Signature(signature))
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+impl AsRef<[u8]> for Signature {
+    fn as_ref(&self) -> &[u8] {
+        &self.0[..]
+    }
+}
+
+impl Debug for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "schnorr::Signature({:?})", self.as_ref())
+    }
+}
+
+// Arrays larger than 32 elements don't implement these traits on our MSRV
+impl Eq for Signature {}
+
+impl PartialEq for Signature {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+/// BIP-340 x-only public keys: the x-coordinate of a point whose
+/// y-coordinate is even
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct XOnlyPublicKey([u8; PUBLIC_KEY_SIZE]);
+
+impl XOnlyPublicKey {
+    /// Create an x-only public key from a 32-byte array
+    pub fn new(bytes: [u8; PUBLIC_KEY_SIZE]) -> Self {
+        XOnlyPublicKey(bytes)
+    }
+
+    /// Create an x-only public key from a byte slice, returning `None` if
+    /// the slice is not the correct size (32-bytes)
+    pub fn from_bytes<B>(bytes: B) -> Option<Self>
+    where
+        B: AsRef<[u8]>,
+    {
+        if bytes.as_ref().len() == PUBLIC_KEY_SIZE {
+            let mut public_key = [0u8; PUBLIC_KEY_SIZE];
+            public_key.copy_from_slice(bytes.as_ref());
+            Some(XOnlyPublicKey(public_key))
+        } else {
+            None
+        }
+    }
+
+    /// Obtain public key as a byte array reference
+    pub fn as_bytes(&self) -> &[u8; PUBLIC_KEY_SIZE] {
+        &self.0
+    }
+
+    /// Decode the point with this x-coordinate and an even y-coordinate
+    /// (`lift_x` in BIP-340), returning `None` if there isn't one
+    fn lift_x(&self) -> Option<AffinePoint> {
+        let mut compressed = [0u8; PUBLIC_KEY_SIZE + 1];
+        compressed[0] = 0x02;
+        compressed[1..].copy_from_slice(&self.0);
+
+        PublicKey::from_bytes(&compressed[..])
+            .and_then(|public_key| AffinePoint::from_public_key(&public_key).into())
+    }
+}
+
+impl AsRef<[u8]> for XOnlyPublicKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.0[..]
+    }
+}
+
+impl signatory::public_key::PublicKey for XOnlyPublicKey {}
+
+/// BIP-340 Schnorr signature provider
+pub struct SchnorrSigner {
+    /// Secret scalar, negated if necessary so the public key has an even
+    /// y-coordinate
+    secret_scalar: Scalar,
+
+    /// Public key corresponding to the secret scalar
+    public_key: XOnlyPublicKey,
+}
+
+impl From<&SecretKey> for SchnorrSigner {
+    /// Create a new BIP-340 signer from the given `SecretKey`
+    fn from(secret_key: &SecretKey) -> SchnorrSigner {
+        let secret_scalar = Option::<Scalar>::from(Scalar::from_bytes(secret_key.as_bytes()))
+            .filter(|scalar| !bool::from(scalar.is_zero()))
+            .expect("invalid secp256k1 secret key");
+
+        let (secret_scalar, public_key) = even_y(secret_scalar).unwrap();
+
+        SchnorrSigner {
+            secret_scalar,
+            public_key,
+        }
+    }
+}
+
+impl SchnorrSigner {
+    /// Sign the given message using the given auxiliary random data, which
+    /// is mixed into the nonce to protect against side-channel attacks.
+    ///
+    /// BIP-340 recommends using 32 fresh random bytes for every signature,
+    /// but signatures remain secure if they're all zero.
+    pub fn sign_with_aux_rand(&self, msg: &[u8], aux_rand: &[u8; 32]) -> Result<Signature, Error> {
+        let mut masked_key = self.secret_scalar.to_bytes();
+
+        for (key_byte, aux_byte) in masked_key
+            .iter_mut()
+            .zip(tagged_hash(AUX_TAG).chain(aux_rand).finalize())
+        {
+            *key_byte ^= aux_byte;
+        }
+
+        let mut nonce_hasher = tagged_hash(NONCE_TAG);
+        nonce_hasher.update(masked_key.as_slice());
+        nonce_hasher.update(self.public_key.as_bytes());
+        nonce_hasher.update(msg);
+        masked_key.as_mut_slice().zeroize();
+
+        // `R` is encoded the same way as an x-only public key. Signing fails
+        // (with negligible probability) if the nonce is zero.
+        let (mut nonce, r) =
+            even_y(Scalar::from_bytes_reduced(&nonce_hasher.finalize())).ok_or_else(Error::new)?;
+
+        let e = challenge(r.as_bytes(), &self.public_key, msg);
+        let s = nonce + &(e * &self.secret_scalar);
+        nonce.zeroize();
+
+        let mut signature = [0u8; SIGNATURE_SIZE];
+        signature[..32].copy_from_slice(r.as_bytes());
+        signature[32..].copy_from_slice(&s.to_bytes());
+        Ok(Signature(signature))
+    }
+}
+
+impl signature::Signer<Signature> for SchnorrSigner {
+    /// Sign the given message with all-zero auxiliary random data. Use
+    /// [`SchnorrSigner::sign_with_aux_rand`] to supply randomness.
+    fn try_sign(&self, msg: &[u8]) -> Result<Signature, Error> {
+        self.sign_with_aux_rand(msg, &[0u8; 32])
+    }
+}
+
+impl PublicKeyed<XOnlyPublicKey> for SchnorrSigner {
+    fn public_key(&self) -> Result<XOnlyPublicKey, Error> {
+        Ok(self.public_key)
+    }
+}
+
+impl Drop for SchnorrSigner {
+    fn drop(&mut self) {
+        self.secret_scalar.zeroize();
+    }
+}
+
+/// BIP-340 Schnorr signature verifier
+#[derive(Clone, Debug)]
+pub struct SchnorrVerifier {
+    /// Public key being verified against
+    public_key: XOnlyPublicKey,
+
+    /// Decoded public key point, or `None` if the public key isn't the
+    /// x-coordinate of a point (in which case all signatures are rejected)
+    point: Option<AffinePoint>,
+}
+
+impl<'a> From<&'a XOnlyPublicKey> for SchnorrVerifier {
+    fn from(public_key: &'a XOnlyPublicKey) -> Self {
+        SchnorrVerifier {
+            public_key: *public_key,
+            point: public_key.lift_x(),
+        }
+    }
+}
+
+impl signature::Verifier<Signature> for SchnorrVerifier {
+    fn verify(&self, msg: &[u8], signature: &Signature) -> Result<(), Error> {
+        let point = self.point.ok_or_else(Error::new)?;
+        let (r, s) = signature.0.split_at(32);
+
+        let s = Option::<Scalar>::from(Scalar::from_bytes(ElementBytes::from_slice(s)))
+            .ok_or_else(Error::new)?;
+        let e = challenge(r, &self.public_key, msg);
+
+        // Compute `R = [s]G - [e]P`, which must not be the point at infinity,
+        // must have an even y-coordinate, and must have the x-coordinate `r`.
+        // Any `r` which isn't less than the field size never matches.
+        let expected_r = (ProjectivePoint::generator() * &s) - &(ProjectivePoint::from(point) * &e);
+        let expected_r = Option::<AffinePoint>::from(expected_r.to_affine())
+            .map(CompressedPoint::from)
+            .ok_or_else(Error::new)?;
+
+        let bytes = expected_r.as_bytes();
+
+        if bytes[0] == 0x02 && &bytes[1..] == r {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+impl BatchVerifier<XOnlyPublicKey, Signature> for SchnorrVerifier {
+    fn verify_batch(items: &[BatchItem<'_, XOnlyPublicKey, Signature>]) -> Result<(), BatchError> {
+        batch::verify_sequentially::<_, _, Self>(items)
+    }
+}
+
+/// Compute `[k]G`, returning `k` (negated if necessary so the point has an
+/// even y-coordinate) along with the x-coordinate of the point, or `None`
+/// if `k` is zero
+fn even_y(k: Scalar) -> Option<(Scalar, XOnlyPublicKey)> {
+    let point = Option::<AffinePoint>::from((ProjectivePoint::generator() * &k).to_affine())?;
+    let compressed = CompressedPoint::from(point);
+    let bytes = compressed.as_bytes();
+    let is_odd = Choice::from(bytes[0] & 1);
+
+    Some((
+        Scalar::conditional_select(&k, &-k, is_odd),
+        XOnlyPublicKey::from_bytes(&bytes[1..])?,
+    ))
+}
+
+/// Create a SHA-256 hasher for the given BIP-340 tag, i.e. one which has
+/// already been fed `SHA256(tag) || SHA256(tag)`
+fn tagged_hash(tag: &[u8]) -> Sha256 {
+    let tag_hash = Sha256::digest(tag);
+    Sha256::new()
+        .chain(tag_hash.as_slice())
+        .chain(tag_hash.as_slice())
+}
+
+/// Compute the challenge scalar `e = H_challenge(r || P || m) mod n`
+fn challenge(r: &[u8], public_key: &XOnlyPublicKey, msg: &[u8]) -> Scalar {
+    let digest = tagged_hash(CHALLENGE_TAG)
+        .chain(r)
+        .chain(public_key.as_bytes())
+        .chain(msg)
+        .finalize();
+
+    Scalar::from_bytes_reduced(&digest)
+}
@@ -8,7 +8,11 @@
     html_root_url = "https://docs.rs/signatory-sodiumoxide/0.21.0"
 )]
 
+#[cfg(test)]
+extern crate std;
+
 use signatory::{
+    batch::{self, BatchError, BatchItem, BatchVerifier},
     ed25519,
     public_key::PublicKeyed,
     signature::{Error, Signature, Signer, Verifier},
@@ -49,6 +53,8 @@ impl Signer<ed25519::Signature> for Ed25519Signer {
 }
 
 /// Ed25519 verifier for sodiumoxide
+///
+/// Implements `ed25519::VerificationPolicy::Libsodium`.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Ed25519Verifier(sodiumoxide_ed25519::PublicKey);
 
@@ -69,8 +75,17 @@ impl Verifier<ed25519::Signature> for Ed25519Verifier {
     }
 }
 
+impl BatchVerifier<ed25519::PublicKey, ed25519::Signature> for Ed25519Verifier {
+    fn verify_batch(
+        items: &[BatchItem<'_, ed25519::PublicKey, ed25519::Signature>],
+    ) -> Result<(), BatchError> {
+        batch::verify_sequentially::<_, _, Self>(items)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{Ed25519Signer, Ed25519Verifier};
-    signatory::ed25519_tests!(Ed25519Signer, Ed25519Verifier);
+    signatory::ed25519_tests!(Ed25519Signer, Ed25519Verifier, Libsodium);
+    signatory::ed25519_batch_tests!(Ed25519Verifier);
 }
@@ -0,0 +1,110 @@
+//! Batch signature verification
+//!
+//! Verifies many (public key, message, signature) triples at once. Providers
+//! which support a faster batch equation (e.g. the built-in Ed25519 backend
+//! with the `ed25519-batch` feature) implement [`BatchVerifier`] directly,
+//! whereas the rest fall back to [`verify_sequentially`].
+
+use alloc::vec::Vec;
+use core::fmt::{self, Display};
+use signature::Verifier;
+
+/// Entry in a batch of signatures to be verified
+#[derive(Debug)]
+pub struct BatchItem<'a, K, S> {
+    /// Public key the signature is expected to verify under
+    pub public_key: &'a K,
+
+    /// Message which was signed
+    pub msg: &'a [u8],
+
+    /// Signature to be verified
+    pub signature: &'a S,
+}
+
+impl<'a, K, S> BatchItem<'a, K, S> {
+    /// Create a new batch item
+    pub fn new(public_key: &'a K, msg: &'a [u8], signature: &'a S) -> Self {
+        Self {
+            public_key,
+            msg,
+            signature,
+        }
+    }
+}
+
+impl<'a, K, S> Clone for BatchItem<'a, K, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, K, S> Copy for BatchItem<'a, K, S> {}
+
+/// Error indicating one or more signatures in a batch failed to verify
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchError {
+    /// Indexes of the batch items which failed to verify (in ascending order)
+    failed: Vec<usize>,
+}
+
+impl BatchError {
+    /// Create a new batch error from the indexes of the items which failed
+    pub fn new(mut failed: Vec<usize>) -> Self {
+        failed.sort_unstable();
+        failed.dedup();
+        Self { failed }
+    }
+
+    /// Indexes of the batch items which failed to verify (in ascending order)
+    pub fn failed(&self) -> &[usize] {
+        &self.failed
+    }
+}
+
+impl Display for BatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "batch verification failed for {} signature(s)",
+            self.failed.len()
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BatchError {}
+
+/// Verify a batch of signatures, each under its own public key (to be
+/// implemented by Signatory providers)
+pub trait BatchVerifier<K, S> {
+    /// Verify every item in the batch, returning a [`BatchError`] listing the
+    /// items which failed if any of them are invalid
+    fn verify_batch(items: &[BatchItem<'_, K, S>]) -> Result<(), BatchError>;
+}
+
+/// Verify each item in a batch one at a time using the verifier type `V`
+///
+/// This is the fallback used by providers with no faster batch equation.
+pub fn verify_sequentially<K, S, V>(items: &[BatchItem<'_, K, S>]) -> Result<(), BatchError>
+where
+    V: for<'k> From<&'k K> + Verifier<S>,
+    S: signature::Signature,
+{
+    let failed = items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| {
+            V::from(item.public_key)
+                .verify(item.msg, item.signature)
+                .is_err()
+        })
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(BatchError::new(failed))
+    }
+}
@@ -0,0 +1,33 @@
+//! Traits for Diffie-Hellman key agreement
+//!
+//! These allow secret keys used for signing to also establish a shared
+//! secret with a peer's public key, e.g. to set up an encrypted channel
+//! between parties which already know each other's identity keys.
+//!
+//! Implementations are provided for the following key types:
+//!
+//! - ECDH (SEC1 §3.3.1) for the NIST P-256 and secp256k1 `SecretKey` types
+//!   with the `ecdsa-software` feature, and for the NIST P-384 `SecretKey`
+//!   with the `p384` feature. The shared secret is the `x`-coordinate of the
+//!   shared point.
+//! - X25519 (RFC 7748) for `ed25519::Seed` with the `ed25519-software`
+//!   feature, after converting both keys to their Curve25519 (Montgomery)
+//!   equivalents.
+//!
+//! Shared secrets are zeroized when dropped. They aren't uniformly random,
+//! so they should be passed through a key derivation function (e.g. HKDF)
+//! along with a transcript of both public keys rather than being used as
+//! keys directly.
+
+use signature::Error;
+
+/// Secret keys which can compute a Diffie-Hellman shared secret with the
+/// given type of public key
+pub trait DiffieHellman<K> {
+    /// Shared secret produced by the key agreement
+    type SharedSecret;
+
+    /// Compute a shared secret with the given public key, returning an error
+    /// if either key is invalid or the result is the identity
+    fn diffie_hellman(&self, public_key: &K) -> Result<Self::SharedSecret, Error>;
+}
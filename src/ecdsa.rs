@@ -7,9 +7,22 @@ pub mod nistp256;
 #[cfg(feature = "p384")]
 pub mod nistp384;
 
+#[cfg(feature = "p521")]
+pub mod nistp521;
+
 #[cfg(feature = "k256")]
 pub mod secp256k1;
 
+mod der;
+mod low_s;
+mod point;
+#[cfg(feature = "ecdsa-software")]
+mod rfc6979;
+
+pub use self::der::SignatureConversion;
+pub use self::low_s::{CurveOrder, LowS, StrictVerifier};
+pub use self::point::{PointConversion, PointEncoding, PublicKeyedWithEncoding};
+
 // Re-export key types from the `elliptic_curve` crate
 pub use ::ecdsa::elliptic_curve::{self, weierstrass::Curve, weierstrass::PublicKey, SecretKey};
 
@@ -0,0 +1,167 @@
+//! Strict conversions between ASN.1 DER and fixed-width ECDSA signatures
+//!
+//! Some consumers (e.g. X.509 and TLS) expect ECDSA signatures encoded as an
+//! ASN.1 DER `SEQUENCE` of two `INTEGER`s, whereas others (e.g. JWS and COSE)
+//! expect the fixed-width concatenation `r || s`.
+//!
+//! [`SignatureConversion`] converts between the two for every supported
+//! curve. Unlike `Asn1Signature::from_bytes`, [`SignatureConversion::from_der`]
+//! only accepts the unique DER encoding of a signature, rejecting BER length
+//! forms, superfluous leading zeroes, negative integers and trailing data.
+
+use super::{
+    elliptic_curve::{generic_array::ArrayLength, weierstrass::Curve},
+    Asn1Signature, FixedSignature,
+};
+use core::ops::Add;
+use ecdsa::{
+    asn1::{MaxOverhead, MaxSize},
+    SignatureBytes,
+};
+use signature::{Error, Signature};
+
+/// ASN.1 `INTEGER` tag
+const INTEGER_TAG: u8 = 0x02;
+
+/// ASN.1 `SEQUENCE` tag
+const SEQUENCE_TAG: u8 = 0x30;
+
+/// ECDSA signatures which can be converted between ASN.1 DER and fixed-width
+/// encodings for the curve `C`
+pub trait SignatureConversion<C>: Sized
+where
+    C: Curve,
+    C::ElementSize: Add + ArrayLength<u8>,
+    MaxSize<C>: ArrayLength<u8>,
+    <C::ElementSize as Add>::Output: Add<MaxOverhead> + ArrayLength<u8>,
+{
+    /// Parse a signature from ASN.1 DER, returning an error if it isn't the
+    /// minimal (i.e. DER rather than BER) encoding of `r` and `s`
+    fn from_der(bytes: &[u8]) -> Result<Self, Error>;
+
+    /// Serialize this signature as ASN.1 DER
+    fn to_der(&self) -> Asn1Signature<C>;
+
+    /// Convert this signature into a fixed-width (`r || s`) signature
+    fn to_fixed(&self) -> FixedSignature<C>;
+}
+
+impl<C> SignatureConversion<C> for FixedSignature<C>
+where
+    C: Curve,
+    C::ElementSize: Add + ArrayLength<u8>,
+    MaxSize<C>: ArrayLength<u8>,
+    <C::ElementSize as Add>::Output: Add<MaxOverhead> + ArrayLength<u8>,
+{
+    fn from_der(bytes: &[u8]) -> Result<Self, Error> {
+        let mut fixed = SignatureBytes::<C>::default();
+        parse_der(bytes, &mut fixed)?;
+        FixedSignature::from_bytes(&fixed)
+    }
+
+    fn to_der(&self) -> Asn1Signature<C> {
+        self.to_asn1()
+    }
+
+    fn to_fixed(&self) -> FixedSignature<C> {
+        self.clone()
+    }
+}
+
+impl<C> SignatureConversion<C> for Asn1Signature<C>
+where
+    C: Curve,
+    C::ElementSize: Add + ArrayLength<u8>,
+    MaxSize<C>: ArrayLength<u8>,
+    <C::ElementSize as Add>::Output: Add<MaxOverhead> + ArrayLength<u8>,
+{
+    fn from_der(bytes: &[u8]) -> Result<Self, Error> {
+        // Minimal encodings round trip, so this is identical to `bytes`
+        FixedSignature::<C>::from_der(bytes).map(|signature| signature.to_asn1())
+    }
+
+    fn to_der(&self) -> Asn1Signature<C> {
+        self.to_fixed().to_asn1()
+    }
+
+    fn to_fixed(&self) -> FixedSignature<C> {
+        // `r` and `s` were checked to fit in a scalar when the signature was parsed
+        FixedSignature::from_asn1(self.as_ref()).expect("invalid ASN.1 signature")
+    }
+}
+
+/// Parse a strict ASN.1 DER-encoded signature, writing `r` and `s` into
+/// `output` as big-endian integers which are each half its size
+fn parse_der(bytes: &[u8], output: &mut [u8]) -> Result<(), Error> {
+    let (sequence, trailing) = parse_tlv(SEQUENCE_TAG, bytes)?;
+    let (r, sequence) = parse_integer(sequence)?;
+    let (s, sequence) = parse_integer(sequence)?;
+
+    if !trailing.is_empty() || !sequence.is_empty() {
+        return Err(Error::new());
+    }
+
+    let scalar_size = output.len() / 2;
+    copy_integer(r, &mut output[..scalar_size])?;
+    copy_integer(s, &mut output[scalar_size..])
+}
+
+/// Parse a positive, minimally encoded ASN.1 `INTEGER`, returning its value
+/// (including any leading zero needed to keep it positive) and the rest of
+/// the input
+fn parse_integer(bytes: &[u8]) -> Result<(&[u8], &[u8]), Error> {
+    let (integer, rest) = parse_tlv(INTEGER_TAG, bytes)?;
+
+    // Empty and negative integers are invalid
+    if integer.is_empty() || integer[0] & 0x80 != 0 {
+        return Err(Error::new());
+    }
+
+    // A leading zero is only allowed if the next byte has its high bit set
+    if integer.len() > 1 && integer[0] == 0 && integer[1] & 0x80 == 0 {
+        return Err(Error::new());
+    }
+
+    Ok((integer, rest))
+}
+
+/// Parse a tag-length-value with the given tag, returning the value and the
+/// rest of the input.
+///
+/// Lengths of up to 255 bytes are supported, which is enough for every
+/// supported curve, and must use the short form when they're below 128.
+fn parse_tlv(tag: u8, bytes: &[u8]) -> Result<(&[u8], &[u8]), Error> {
+    if bytes.len() < 2 || bytes[0] != tag {
+        return Err(Error::new());
+    }
+
+    let (len, rest) = match bytes[1] {
+        len if len < 0x80 => (usize::from(len), &bytes[2..]),
+        0x81 if bytes.len() > 2 && bytes[2] >= 0x80 => (usize::from(bytes[2]), &bytes[3..]),
+        _ => return Err(Error::new()),
+    };
+
+    if len > rest.len() {
+        return Err(Error::new());
+    }
+
+    Ok(rest.split_at(len))
+}
+
+/// Copy the value of a positive `INTEGER` into `output` as a big-endian
+/// integer, returning an error if it doesn't fit
+fn copy_integer(integer: &[u8], output: &mut [u8]) -> Result<(), Error> {
+    let value = if integer.len() > 1 && integer[0] == 0 {
+        &integer[1..]
+    } else {
+        integer
+    };
+
+    if value.len() > output.len() {
+        return Err(Error::new());
+    }
+
+    let offset = output.len() - value.len();
+    output[offset..].copy_from_slice(value);
+    Ok(())
+}
@@ -0,0 +1,195 @@
+//! "Low S" normalization of ECDSA signatures (BIP 62)
+//!
+//! If `(r, s)` is a valid ECDSA signature then so is `(r, n - s)`, which lets
+//! anyone change ("malleate") a signature without knowing the secret key.
+//! Some consumers (e.g. Bitcoin and Cosmos) only accept signatures whose `s`
+//! is in the lower half of the curve order, whereas some providers (e.g.
+//! *ring*) produce either form.
+//!
+//! [`LowS`] normalizes signatures for every supported curve, and
+//! [`StrictVerifier`] wraps any ECDSA verifier to reject signatures which
+//! haven't been normalized.
+
+use super::{
+    elliptic_curve::{generic_array::ArrayLength, weierstrass::Curve},
+    Asn1Signature, FixedSignature, SignatureConversion,
+};
+#[cfg(feature = "alloc")]
+use crate::batch::{BatchError, BatchItem, BatchVerifier};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::ops::Add;
+use ecdsa::{
+    asn1::{MaxOverhead, MaxSize},
+    SignatureSize,
+};
+#[cfg(feature = "digest")]
+use signature::{digest::Digest, DigestVerifier};
+use signature::{Error, Signature, Verifier};
+
+/// Elliptic curves with a known order, which is needed to normalize
+/// signatures
+pub trait CurveOrder: Curve {
+    /// Order of the curve's base point (`n`), serialized as a big-endian
+    /// integer the same size as a scalar
+    const ORDER: &'static [u8];
+}
+
+/// ECDSA signatures which can be normalized into "low S" form
+pub trait LowS: Sized {
+    /// Is `s` in the lower half of the curve order (i.e. `s <= n / 2`)?
+    fn is_low_s(&self) -> bool;
+
+    /// Normalize this signature into "low S" form, replacing `s` with `n - s`
+    /// if it's in the upper half of the curve order.
+    ///
+    /// Signatures whose `s` isn't less than `n` are invalid, and are returned
+    /// unchanged.
+    fn to_low_s(&self) -> Self;
+}
+
+impl<C> LowS for FixedSignature<C>
+where
+    C: CurveOrder,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn is_low_s(&self) -> bool {
+        !is_high(C::ORDER, self.s())
+    }
+
+    fn to_low_s(&self) -> Self {
+        let s = self.s();
+
+        if is_high(C::ORDER, s) && s.as_slice() < C::ORDER {
+            let mut low_s = s.clone();
+            sub_from_order(C::ORDER, s, &mut low_s);
+            FixedSignature::from_scalars(self.r(), &low_s)
+        } else {
+            self.clone()
+        }
+    }
+}
+
+impl<C> LowS for Asn1Signature<C>
+where
+    C: CurveOrder,
+    C::ElementSize: Add + ArrayLength<u8>,
+    MaxSize<C>: ArrayLength<u8>,
+    <C::ElementSize as Add>::Output: Add<MaxOverhead> + ArrayLength<u8>,
+{
+    fn is_low_s(&self) -> bool {
+        self.to_fixed().is_low_s()
+    }
+
+    fn to_low_s(&self) -> Self {
+        self.to_fixed().to_low_s().to_asn1()
+    }
+}
+
+/// ECDSA verifier which rejects signatures that aren't in "low S" form
+/// before passing the rest to the wrapped verifier `V`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StrictVerifier<V>(V);
+
+impl<V> StrictVerifier<V> {
+    /// Only accept "low S" signatures which are valid according to the
+    /// given verifier
+    pub fn new(verifier: V) -> Self {
+        StrictVerifier(verifier)
+    }
+}
+
+impl<'a, K, V> From<&'a K> for StrictVerifier<V>
+where
+    V: From<&'a K>,
+{
+    fn from(public_key: &'a K) -> Self {
+        StrictVerifier(V::from(public_key))
+    }
+}
+
+impl<S, V> Verifier<S> for StrictVerifier<V>
+where
+    S: LowS + Signature,
+    V: Verifier<S>,
+{
+    fn verify(&self, msg: &[u8], signature: &S) -> Result<(), Error> {
+        if signature.is_low_s() {
+            self.0.verify(msg, signature)
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl<D, S, V> DigestVerifier<D, S> for StrictVerifier<V>
+where
+    D: Digest,
+    S: LowS + Signature,
+    V: DigestVerifier<D, S>,
+{
+    fn verify_digest(&self, digest: D, signature: &S) -> Result<(), Error> {
+        if signature.is_low_s() {
+            self.0.verify_digest(digest, signature)
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K, S, V> BatchVerifier<K, S> for StrictVerifier<V>
+where
+    S: LowS,
+    V: BatchVerifier<K, S>,
+{
+    fn verify_batch(items: &[BatchItem<'_, K, S>]) -> Result<(), BatchError> {
+        let mut failed = items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| !item.signature.is_low_s())
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+
+        if let Err(err) = V::verify_batch(items) {
+            failed.extend_from_slice(err.failed());
+        }
+
+        if failed.is_empty() {
+            Ok(())
+        } else {
+            Err(BatchError::new(failed))
+        }
+    }
+}
+
+/// Is the big-endian integer `s` greater than `order / 2`?
+///
+/// Not constant time, but signatures are public.
+fn is_high(order: &[u8], s: &[u8]) -> bool {
+    debug_assert_eq!(order.len(), s.len());
+    let mut carry = 0;
+
+    for (&order_byte, &s_byte) in order.iter().zip(s) {
+        let half_byte = (order_byte >> 1) | carry;
+        carry = (order_byte & 1) << 7;
+
+        if s_byte != half_byte {
+            return s_byte > half_byte;
+        }
+    }
+
+    false
+}
+
+/// Compute `order - s` (where `s < order`) as a big-endian integer
+fn sub_from_order(order: &[u8], s: &[u8], output: &mut [u8]) {
+    let mut borrow = 0;
+
+    for ((out_byte, &order_byte), &s_byte) in output.iter_mut().zip(order).zip(s).rev() {
+        let diff = i16::from(order_byte) - i16::from(s_byte) - borrow;
+        *out_byte = diff as u8;
+        borrow = if diff < 0 { 1 } else { 0 };
+    }
+}
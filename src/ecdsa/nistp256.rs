@@ -1,12 +1,279 @@
 //! NIST P-256
 
+#[cfg(feature = "ecdsa-software")]
+mod software;
 #[cfg(feature = "test-vectors")]
 pub mod test_vectors;
 
+#[cfg(feature = "ecdsa-software")]
+pub use self::software::{Signer, Verifier};
 pub use p256::{NistP256, PublicKey, SecretKey};
 
+use super::{CurveOrder, PointConversion, PointEncoding};
+#[cfg(feature = "ecdsa-software")]
+use crate::ecdh::DiffieHellman;
+#[cfg(all(feature = "alloc", feature = "spki"))]
+use crate::encoding::ToPublicKeyDer;
+#[cfg(all(feature = "alloc", feature = "sec1"))]
+use crate::encoding::ToSec1;
+#[cfg(any(
+    feature = "cose",
+    feature = "jwk",
+    feature = "openssh",
+    feature = "sec1",
+    feature = "spki"
+))]
+use crate::encoding::{self, Algorithm};
+#[cfg(feature = "cose")]
+use crate::encoding::{cose::CoseKey, FromCoseKey, ToCoseKey};
+#[cfg(feature = "openssh")]
+use crate::encoding::{openssh, FromOpenSshPrivateKey, FromOpenSshPublicKey, ToOpenSshPublicKey};
+#[cfg(feature = "sec1")]
+use crate::encoding::{sec1::EcPrivateKey, FromSec1};
+#[cfg(feature = "spki")]
+use crate::encoding::{spki::SubjectPublicKeyInfo, FromPublicKeyDer};
+#[cfg(feature = "jwk")]
+use crate::encoding::{FromJwk, Jwk, ToJwk};
+#[cfg(feature = "ecdsa-software")]
+use crate::public_key::PublicKeyed;
+#[cfg(feature = "openssh")]
+use alloc::string::String;
+#[cfg(any(
+    feature = "cose",
+    all(feature = "alloc", any(feature = "sec1", feature = "spki"))
+))]
+use alloc::vec::Vec;
+use p256::{elliptic_curve::weierstrass::public_key::FromPublicKey, AffinePoint};
+#[cfg(feature = "ecdsa-software")]
+use p256::{elliptic_curve::FromBytes, CompressedPoint, ProjectivePoint, Scalar};
+#[cfg(feature = "ecdsa-software")]
+use zeroize::Zeroize;
+#[cfg(any(feature = "ecdsa-software", all(feature = "alloc", feature = "sec1")))]
+use zeroize::Zeroizing;
+
 /// NIST P-256 ASN.1 signature
 pub type Asn1Signature = super::Asn1Signature<NistP256>;
 
 /// NIST P-256 fixed signature
 pub type FixedSignature = super::FixedSignature<NistP256>;
+
+impl CurveOrder for NistP256 {
+    const ORDER: &'static [u8] = b"\xff\xff\xff\xff\x00\x00\x00\x00\xff\xff\xff\xff\xff\xff\xff\xff\xbc\xe6\xfa\xad\xa7\x17\x9e\x84\xf3\xb9\xca\xc2\xfc\x63\x25\x51";
+}
+
+impl PointConversion for PublicKey {
+    /// Convert this public key to the given SEC1 encoding, returning an error
+    /// if it isn't a valid point on the NIST P-256 curve
+    fn to_encoding(&self, encoding: PointEncoding) -> Result<Self, signature::Error> {
+        let point = Option::<AffinePoint>::from(AffinePoint::from_public_key(self))
+            .ok_or_else(signature::Error::new)?;
+
+        Ok(match encoding {
+            PointEncoding::Compressed => PublicKey::Compressed(point.into()),
+            PointEncoding::Uncompressed => PublicKey::Uncompressed(point.into()),
+        })
+    }
+}
+
+#[cfg(feature = "ecdsa-software")]
+impl PublicKeyed<PublicKey> for SecretKey {
+    /// Derive the NIST P-256 public key for this secret key (as an uncompressed
+    /// point), returning an error if the secret key is zero or not less than
+    /// the curve order
+    fn public_key(&self) -> Result<PublicKey, signature::Error> {
+        PublicKey::from_secret_key(self, false).map_err(|_| signature::Error::new())
+    }
+}
+
+#[cfg(feature = "ecdsa-software")]
+impl DiffieHellman<PublicKey> for SecretKey {
+    type SharedSecret = Zeroizing<[u8; 32]>;
+
+    /// Compute the ECDH shared secret with the given NIST P-256 public key (i.e.
+    /// the `x`-coordinate of the shared point), returning an error if the
+    /// secret key is zero or not less than the curve order, or the public key
+    /// isn't a valid point
+    fn diffie_hellman(
+        &self,
+        public_key: &PublicKey,
+    ) -> Result<Self::SharedSecret, signature::Error> {
+        let point = Option::<AffinePoint>::from(AffinePoint::from_public_key(public_key))
+            .ok_or_else(signature::Error::new)?;
+
+        let mut secret_scalar = Option::<Scalar>::from(Scalar::from_bytes(self.as_bytes()))
+            .filter(|scalar| !bool::from(scalar.is_zero()))
+            .ok_or_else(signature::Error::new)?;
+
+        let shared_point = (ProjectivePoint::from(point) * &secret_scalar).to_affine();
+        secret_scalar.zeroize();
+
+        let mut shared_point = Option::<AffinePoint>::from(shared_point)
+            .map(CompressedPoint::from)
+            .ok_or_else(signature::Error::new)?;
+
+        let mut shared_secret = Zeroizing::new([0u8; 32]);
+        shared_secret.copy_from_slice(&shared_point.as_bytes()[1..]);
+        shared_point.zeroize();
+
+        Ok(shared_secret)
+    }
+}
+
+#[cfg(feature = "spki")]
+impl FromPublicKeyDer for PublicKey {
+    /// Decode a NIST P-256 public key from a DER-encoded **SubjectPublicKeyInfo**
+    fn from_public_key_der(bytes: &[u8]) -> Result<Self, encoding::Error> {
+        let spki = SubjectPublicKeyInfo::from_der_with_algorithm(bytes, Algorithm::NistP256)?;
+        PublicKey::from_bytes(spki.subject_public_key)
+            .ok_or_else(|| encoding::error::ErrorKind::Decode.into())
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "spki"))]
+impl ToPublicKeyDer for PublicKey {
+    /// Encode a NIST P-256 public key as a DER-encoded **SubjectPublicKeyInfo**
+    fn to_public_key_der(&self) -> Vec<u8> {
+        SubjectPublicKeyInfo {
+            algorithm: Algorithm::NistP256,
+            subject_public_key: self.as_ref(),
+        }
+        .to_der()
+    }
+}
+
+#[cfg(feature = "sec1")]
+impl FromSec1 for SecretKey {
+    /// Decode a NIST P-256 secret key from a DER-encoded SEC1 `ECPrivateKey`
+    fn from_sec1_der(bytes: &[u8]) -> Result<Self, encoding::Error> {
+        let ec_private_key = EcPrivateKey::from_der_with_algorithm(bytes, Algorithm::NistP256)?;
+        SecretKey::from_bytes(ec_private_key.private_key)
+            .map_err(|_| encoding::error::ErrorKind::Decode.into())
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "sec1"))]
+impl ToSec1 for SecretKey {
+    /// Encode a NIST P-256 secret key as a DER-encoded SEC1 `ECPrivateKey`.
+    ///
+    /// The curve is identified by the `parameters` field. The optional public
+    /// key is omitted, as computing it requires elliptic curve arithmetic.
+    fn to_sec1_der(&self) -> Zeroizing<Vec<u8>> {
+        EcPrivateKey {
+            private_key: self.as_bytes(),
+            parameters: Some(Algorithm::NistP256),
+            public_key: None,
+        }
+        .to_der()
+    }
+}
+
+#[cfg(feature = "openssh")]
+impl FromOpenSshPublicKey for PublicKey {
+    /// Decode a NIST P-256 public key from an OpenSSH `ecdsa-sha2-nistp256`
+    /// public key line
+    fn from_openssh_public_key<S: AsRef<str>>(line: S) -> Result<Self, encoding::Error> {
+        let public_key =
+            openssh::PublicKey::from_openssh_with_algorithm(line.as_ref(), Algorithm::NistP256)?;
+
+        PublicKey::from_bytes(public_key.public_key())
+            .ok_or_else(|| encoding::error::ErrorKind::Decode.into())
+    }
+}
+
+#[cfg(feature = "openssh")]
+impl ToOpenSshPublicKey for PublicKey {
+    /// Encode a NIST P-256 public key as an OpenSSH `ecdsa-sha2-nistp256` public
+    /// key line.
+    ///
+    /// OpenSSH only accepts uncompressed points, so compressed public keys are
+    /// decompressed first (keys which aren't valid points are encoded as-is).
+    fn to_openssh_public_key(&self, comment: &str) -> String {
+        let public_key = self.to_uncompressed().unwrap_or(*self);
+        openssh::encode_public_key(Algorithm::NistP256, public_key.as_ref(), comment)
+    }
+}
+
+#[cfg(feature = "openssh")]
+impl FromOpenSshPrivateKey for SecretKey {
+    /// Obtain a NIST P-256 secret key from an OpenSSH `ecdsa-sha2-nistp256`
+    /// private key
+    fn from_openssh_private_key(
+        private_key: &openssh::PrivateKey,
+    ) -> Result<Self, encoding::Error> {
+        if private_key.algorithm != Algorithm::NistP256 {
+            Err(encoding::error::ErrorKind::Decode)?
+        }
+
+        SecretKey::from_bytes(&*private_key.private_key)
+            .map_err(|_| encoding::error::ErrorKind::Decode.into())
+    }
+}
+
+#[cfg(feature = "jwk")]
+impl FromJwk for PublicKey {
+    /// Decode a NIST P-256 public key from an `EC` JWK
+    fn from_jwk(jwk: &Jwk) -> Result<Self, encoding::Error> {
+        PublicKey::from_bytes(jwk.public_key_for(Algorithm::NistP256)?)
+            .ok_or_else(|| encoding::error::ErrorKind::Decode.into())
+    }
+}
+
+#[cfg(feature = "jwk")]
+impl ToJwk for PublicKey {
+    /// Encode a NIST P-256 public key as an `EC` JWK.
+    ///
+    /// JWKs contain both coordinates, so compressed public keys can't be
+    /// encoded and will return an error.
+    fn to_jwk(&self) -> Result<Jwk, encoding::Error> {
+        Ok(Jwk::new(Algorithm::NistP256, self.as_ref())?.with_thumbprint_kid())
+    }
+}
+
+#[cfg(all(feature = "jwk", feature = "ecdsa-software"))]
+impl FromJwk for SecretKey {
+    /// Obtain a NIST P-256 secret key from the `d` member of an `EC` JWK,
+    /// returning an error if it doesn't match the JWK's public key
+    fn from_jwk(jwk: &Jwk) -> Result<Self, encoding::Error> {
+        let secret_key = SecretKey::from_bytes(jwk.private_key_for(Algorithm::NistP256)?)
+            .map_err(|_| encoding::error::ErrorKind::Decode)?;
+
+        match secret_key.public_key() {
+            Ok(ref public_key) if public_key.as_ref() == jwk.public_key() => Ok(secret_key),
+            _ => Err(encoding::error::ErrorKind::Decode.into()),
+        }
+    }
+}
+
+#[cfg(all(feature = "jwk", feature = "ecdsa-software"))]
+impl ToJwk for SecretKey {
+    /// Serialize this secret key as an `EC` JWK containing both the secret
+    /// key (`d`) and the derived public key (`x` and `y`)
+    fn to_jwk(&self) -> Result<Jwk, encoding::Error> {
+        let public_key = self
+            .public_key()
+            .map_err(|_| encoding::error::ErrorKind::Encode)?;
+
+        Ok(Jwk::new(Algorithm::NistP256, public_key.as_ref())?
+            .with_private_key(self.as_bytes())?
+            .with_thumbprint_kid())
+    }
+}
+
+#[cfg(feature = "cose")]
+impl FromCoseKey for PublicKey {
+    /// Decode a NIST P-256 public key from an `EC2` COSE_Key
+    fn from_cose_key(bytes: &[u8]) -> Result<Self, encoding::Error> {
+        PublicKey::from_bytes(CoseKey::from_bytes(bytes)?.public_key_for(Algorithm::NistP256)?)
+            .ok_or_else(|| encoding::error::ErrorKind::Decode.into())
+    }
+}
+
+#[cfg(feature = "cose")]
+impl ToCoseKey for PublicKey {
+    /// Encode a NIST P-256 public key as an `EC2` COSE_Key.
+    ///
+    /// Compressed public keys are encoded using the sign bit of `y`.
+    fn to_cose_key(&self) -> Result<Vec<u8>, encoding::Error> {
+        Ok(CoseKey::new(Algorithm::NistP256, self.as_ref())?.to_bytes())
+    }
+}
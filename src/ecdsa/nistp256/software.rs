@@ -0,0 +1,141 @@
+//! Pure-Rust software implementation of ECDSA/P-256 built on the `p256` crate
+//!
+//! Signatures are deterministic: the ephemeral scalar `k` is derived from the
+//! secret key and message digest as described in RFC 6979, so no random
+//! number generator is needed.
+
+use super::{Asn1Signature, FixedSignature, NistP256, PublicKey, SecretKey};
+#[cfg(feature = "alloc")]
+use crate::batch::{self, BatchError, BatchItem, BatchVerifier};
+use crate::{ecdsa::rfc6979, public_key::PublicKeyed};
+use ::ecdsa::hazmat::{SignPrimitive, VerifyPrimitive};
+use p256::{
+    elliptic_curve::{weierstrass::public_key::FromPublicKey, FromBytes},
+    AffinePoint, ElementBytes, Scalar,
+};
+use sha2::{Digest, Sha256};
+use signature::{DigestSigner, DigestVerifier, Error};
+use zeroize::Zeroize;
+
+/// NIST P-256 ECDSA signer
+pub struct Signer {
+    /// Secret scalar
+    secret_scalar: Scalar,
+
+    /// Public key (as an uncompressed point)
+    public_key: PublicKey,
+}
+
+impl Signer {
+    /// Create a new signer from the given secret key, returning an error if
+    /// it's zero or not less than the curve order
+    pub fn new(secret_key: &SecretKey) -> Result<Self, Error> {
+        let secret_scalar = Option::<Scalar>::from(Scalar::from_bytes(secret_key.as_bytes()))
+            .filter(|scalar| !bool::from(scalar.is_zero()))
+            .ok_or_else(Error::new)?;
+
+        Ok(Self {
+            secret_scalar,
+            public_key: secret_key.public_key()?,
+        })
+    }
+}
+
+impl PublicKeyed<PublicKey> for Signer {
+    fn public_key(&self) -> Result<PublicKey, Error> {
+        Ok(self.public_key)
+    }
+}
+
+impl DigestSigner<Sha256, FixedSignature> for Signer {
+    fn try_sign_digest(&self, digest: Sha256) -> Result<FixedSignature, Error> {
+        let hashed_msg = digest.finalize();
+        let mut x = ElementBytes::from(self.secret_scalar);
+        let h1 = ElementBytes::from(Scalar::from_bytes_reduced(&hashed_msg));
+
+        let mut k = rfc6979::generate_k::<NistP256, Sha256, _, _>(&x, &h1, |bytes| {
+            Option::<Scalar>::from(Scalar::from_bytes(bytes))
+                .filter(|scalar| !bool::from(scalar.is_zero()))
+        });
+
+        x.as_mut_slice().zeroize();
+        let result = self.secret_scalar.try_sign_prehashed(&k, &hashed_msg);
+        k.zeroize();
+        result
+    }
+}
+
+impl DigestSigner<Sha256, Asn1Signature> for Signer {
+    fn try_sign_digest(&self, digest: Sha256) -> Result<Asn1Signature, Error> {
+        DigestSigner::<Sha256, FixedSignature>::try_sign_digest(self, digest)
+            .map(|signature| signature.to_asn1())
+    }
+}
+
+impl signature::Signer<FixedSignature> for Signer {
+    fn try_sign(&self, msg: &[u8]) -> Result<FixedSignature, Error> {
+        self.try_sign_digest(Sha256::new().chain(msg))
+    }
+}
+
+impl signature::Signer<Asn1Signature> for Signer {
+    fn try_sign(&self, msg: &[u8]) -> Result<Asn1Signature, Error> {
+        self.try_sign_digest(Sha256::new().chain(msg))
+    }
+}
+
+impl Drop for Signer {
+    fn drop(&mut self) {
+        self.secret_scalar.zeroize();
+    }
+}
+
+/// NIST P-256 ECDSA verifier
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Verifier(PublicKey);
+
+impl<'a> From<&'a PublicKey> for Verifier {
+    fn from(public_key: &'a PublicKey) -> Self {
+        Verifier(*public_key)
+    }
+}
+
+impl DigestVerifier<Sha256, FixedSignature> for Verifier {
+    fn verify_digest(&self, digest: Sha256, signature: &FixedSignature) -> Result<(), Error> {
+        Option::<AffinePoint>::from(AffinePoint::from_public_key(&self.0))
+            .ok_or_else(Error::new)?
+            .verify_prehashed(&digest.finalize(), signature)
+    }
+}
+
+impl DigestVerifier<Sha256, Asn1Signature> for Verifier {
+    fn verify_digest(&self, digest: Sha256, signature: &Asn1Signature) -> Result<(), Error> {
+        self.verify_digest(digest, &FixedSignature::from_asn1(signature.as_ref())?)
+    }
+}
+
+impl signature::Verifier<FixedSignature> for Verifier {
+    fn verify(&self, msg: &[u8], signature: &FixedSignature) -> Result<(), Error> {
+        self.verify_digest(Sha256::new().chain(msg), signature)
+    }
+}
+
+impl signature::Verifier<Asn1Signature> for Verifier {
+    fn verify(&self, msg: &[u8], signature: &Asn1Signature) -> Result<(), Error> {
+        self.verify_digest(Sha256::new().chain(msg), signature)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl BatchVerifier<PublicKey, Asn1Signature> for Verifier {
+    fn verify_batch(items: &[BatchItem<'_, PublicKey, Asn1Signature>]) -> Result<(), BatchError> {
+        batch::verify_sequentially::<_, _, Self>(items)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl BatchVerifier<PublicKey, FixedSignature> for Verifier {
+    fn verify_batch(items: &[BatchItem<'_, PublicKey, FixedSignature>]) -> Result<(), BatchError> {
+        batch::verify_sequentially::<_, _, Self>(items)
+    }
+}
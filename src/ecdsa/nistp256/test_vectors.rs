@@ -114,3 +114,26 @@ pub const SHA256_FIXED_SIZE_TEST_VECTORS: &[TestVector] = &[
         sig: b"\x54\x2c\x40\xa1\x81\x40\xa6\x26\x6d\x6f\x02\x86\xe2\x4e\x9a\x7b\xad\x76\x50\xe7\x2e\xf0\xe2\x13\x1e\x62\x9c\x07\x6d\x96\x26\x63\x4f\x7f\x65\x30\x5e\x24\xa6\xbb\xb5\xcf\xf7\x14\xba\x8f\x5a\x2c\xee\x5b\xdc\x89\xba\x8d\x75\xdc\xbf\x21\x96\x6c\xe3\x8e\xb6\x6f",
     },
 ];
+
+/// ECDSA P-256 test vectors for deterministic signatures (RFC 6979), with
+/// SHA-256 as the message digest and HMAC-DRBG hash function
+///
+/// <https://tools.ietf.org/html/rfc6979#appendix-A.2.5>
+pub const SHA256_RFC6979_TEST_VECTORS: &[TestVector] = &[
+    // RFC 6979 Appendix A.2.5, with SHA-256 and message "sample"
+    TestVector {
+        sk: b"\xc9\xaf\xa9\xd8\x45\xba\x75\x16\x6b\x5c\x21\x57\x67\xb1\xd6\x93\x4e\x50\xc3\xdb\x36\xe8\x9b\x12\x7b\x8a\x62\x2b\x12\x0f\x67\x21",
+        pk: b"\x60\xfe\xd4\xba\x25\x5a\x9d\x31\xc9\x61\xeb\x74\xc6\x35\x6d\x68\xc0\x49\xb8\x92\x3b\x61\xfa\x6c\xe6\x69\x62\x2e\x60\xf2\x9f\xb6\x79\x03\xfe\x10\x08\xb8\xbc\x99\xa4\x1a\xe9\xe9\x56\x28\xbc\x64\xf2\xf1\xb2\x0c\x2d\x7e\x9f\x51\x77\xa3\xc2\x94\xd4\x46\x22\x99",
+        nonce: Some(b"\xa6\xe3\xc5\x7d\xd0\x1a\xbe\x90\x08\x65\x38\x39\x83\x55\xdd\x4c\x3b\x17\xaa\x87\x33\x82\xb0\xf2\x4d\x61\x29\x49\x3d\x8a\xad\x60"),
+        msg: b"\x73\x61\x6d\x70\x6c\x65",
+        sig: b"\xef\xd4\x8b\x2a\xac\xb6\xa8\xfd\x11\x40\xdd\x9c\xd4\x5e\x81\xd6\x9d\x2c\x87\x7b\x56\xaa\xf9\x91\xc3\x4d\x0e\xa8\x4e\xaf\x37\x16\xf7\xcb\x1c\x94\x2d\x65\x7c\x41\xd4\x36\xc7\xa1\xb6\xe2\x9f\x65\xf3\xe9\x00\xdb\xb9\xaf\xf4\x06\x4d\xc4\xab\x2f\x84\x3a\xcd\xa8",
+    },
+    // RFC 6979 Appendix A.2.5, with SHA-256 and message "test"
+    TestVector {
+        sk: b"\xc9\xaf\xa9\xd8\x45\xba\x75\x16\x6b\x5c\x21\x57\x67\xb1\xd6\x93\x4e\x50\xc3\xdb\x36\xe8\x9b\x12\x7b\x8a\x62\x2b\x12\x0f\x67\x21",
+        pk: b"\x60\xfe\xd4\xba\x25\x5a\x9d\x31\xc9\x61\xeb\x74\xc6\x35\x6d\x68\xc0\x49\xb8\x92\x3b\x61\xfa\x6c\xe6\x69\x62\x2e\x60\xf2\x9f\xb6\x79\x03\xfe\x10\x08\xb8\xbc\x99\xa4\x1a\xe9\xe9\x56\x28\xbc\x64\xf2\xf1\xb2\x0c\x2d\x7e\x9f\x51\x77\xa3\xc2\x94\xd4\x46\x22\x99",
+        nonce: Some(b"\xd1\x6b\x6a\xe8\x27\xf1\x71\x75\xe0\x40\x87\x1a\x1c\x7e\xc3\x50\x01\x92\xc4\xc9\x26\x77\x33\x6e\xc2\x53\x7a\xca\xee\x00\x08\xe0"),
+        msg: b"\x74\x65\x73\x74",
+        sig: b"\xf1\xab\xb0\x23\x51\x83\x51\xcd\x71\xd8\x81\x56\x7b\x1e\xa6\x63\xed\x3e\xfc\xf6\xc5\x13\x2b\x35\x4f\x28\xd3\xb0\xb7\xd3\x83\x67\x01\x9f\x41\x13\x74\x2a\x2b\x14\xbd\x25\x92\x6b\x49\xc6\x49\x15\x5f\x26\x7e\x60\xd3\x81\x4b\x4c\x0c\xc8\x42\x50\xe4\x6f\x00\x83",
+    },
+];
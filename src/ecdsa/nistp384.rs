@@ -1,12 +1,330 @@
 //! NIST P-384
+//!
+//! Version 0.3 of the `p384` crate only provides the curve's types, not any
+//! curve arithmetic, so the arithmetic needed for point validation, public
+//! key derivation, ECDH and the built-in software provider (the
+//! `ecdsa-software` feature) is hand-rolled in this module's `field`,
+//! `scalar` and `point` submodules.
 
+mod field;
+mod point;
+#[cfg(feature = "ecdsa-software")]
+mod scalar;
+#[cfg(feature = "ecdsa-software")]
+mod software;
 #[cfg(feature = "test-vectors")]
 pub mod test_vectors;
 
+#[cfg(feature = "ecdsa-software")]
+pub use self::software::{Signer, Verifier};
 pub use p384::{NistP384, PublicKey, SecretKey};
 
+use self::{field::FieldElement, point::ProjectivePoint};
+use super::{CurveOrder, PointConversion, PointEncoding};
+use crate::ecdh::DiffieHellman;
+#[cfg(all(feature = "alloc", feature = "spki"))]
+use crate::encoding::ToPublicKeyDer;
+#[cfg(all(feature = "alloc", feature = "sec1"))]
+use crate::encoding::ToSec1;
+#[cfg(any(
+    feature = "cose",
+    feature = "jwk",
+    feature = "openssh",
+    feature = "sec1",
+    feature = "spki"
+))]
+use crate::encoding::{self, Algorithm};
+#[cfg(feature = "cose")]
+use crate::encoding::{cose::CoseKey, FromCoseKey, ToCoseKey};
+#[cfg(feature = "openssh")]
+use crate::encoding::{openssh, FromOpenSshPrivateKey, FromOpenSshPublicKey, ToOpenSshPublicKey};
+#[cfg(feature = "sec1")]
+use crate::encoding::{sec1::EcPrivateKey, FromSec1};
+#[cfg(feature = "spki")]
+use crate::encoding::{spki::SubjectPublicKeyInfo, FromPublicKeyDer};
+#[cfg(feature = "jwk")]
+use crate::encoding::{FromJwk, Jwk, ToJwk};
+use crate::public_key::PublicKeyed;
+#[cfg(feature = "openssh")]
+use alloc::string::String;
+#[cfg(any(
+    feature = "cose",
+    all(feature = "alloc", any(feature = "sec1", feature = "spki"))
+))]
+use alloc::vec::Vec;
+use p384::elliptic_curve::{
+    generic_array::GenericArray,
+    weierstrass::point::{CompressedPoint, UncompressedPoint},
+};
+use zeroize::Zeroizing;
+
 /// NIST P-384 ASN.1 signature
 pub type Asn1Signature = super::Asn1Signature<NistP384>;
 
 /// NIST P-384 fixed signature
 pub type FixedSignature = super::FixedSignature<NistP384>;
+
+impl CurveOrder for NistP384 {
+    const ORDER: &'static [u8] = b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xc7\x63\x4d\x81\xf4\x37\x2d\xdf\x58\x1a\x0d\xb2\x48\xb0\xa7\x7a\xec\xec\x19\x6a\xcc\xc5\x29\x73";
+}
+
+impl PointConversion for PublicKey {
+    /// Convert this public key to the given SEC1 encoding, returning an error
+    /// if it isn't a valid point on the NIST P-384 curve
+    fn to_encoding(&self, encoding: PointEncoding) -> Result<Self, signature::Error> {
+        let (x, y) = affine_coordinates(self)?;
+        Ok(encode_point(x, y, encoding))
+    }
+}
+
+impl PublicKeyed<PublicKey> for SecretKey {
+    /// Derive the NIST P-384 public key for this secret key (as an uncompressed
+    /// point), returning an error if the secret key is zero or not less than
+    /// the curve order
+    fn public_key(&self) -> Result<PublicKey, signature::Error> {
+        if !bool::from(point::is_valid_scalar(self.as_bytes())) {
+            return Err(signature::Error::new());
+        }
+
+        let (x, y) = ProjectivePoint::generator()
+            .mul(self.as_bytes())
+            .to_affine()
+            .ok_or_else(signature::Error::new)?;
+
+        Ok(encode_point(x, y, PointEncoding::Uncompressed))
+    }
+}
+
+impl DiffieHellman<PublicKey> for SecretKey {
+    type SharedSecret = Zeroizing<[u8; 48]>;
+
+    /// Compute the ECDH shared secret with the given NIST P-384 public key
+    /// (i.e. the `x`-coordinate of the shared point), returning an error if
+    /// the secret key is zero or not less than the curve order, or the public
+    /// key isn't a valid point
+    fn diffie_hellman(
+        &self,
+        public_key: &PublicKey,
+    ) -> Result<Self::SharedSecret, signature::Error> {
+        let (x, y) = affine_coordinates(public_key)?;
+        let point = ProjectivePoint::from_affine(x, y).ok_or_else(signature::Error::new)?;
+
+        if !bool::from(point::is_valid_scalar(self.as_bytes())) {
+            return Err(signature::Error::new());
+        }
+
+        let (shared_x, _) = point
+            .mul(self.as_bytes())
+            .to_affine()
+            .ok_or_else(signature::Error::new)?;
+
+        Ok(Zeroizing::new(shared_x.to_bytes()))
+    }
+}
+
+/// Serialize the given affine coordinates as a public key
+fn encode_point(x: FieldElement, y: FieldElement, encoding: PointEncoding) -> PublicKey {
+    let x = GenericArray::clone_from_slice(&x.to_bytes());
+    let y = GenericArray::clone_from_slice(&y.to_bytes());
+
+    match encoding {
+        PointEncoding::Compressed => {
+            PublicKey::Compressed(CompressedPoint::from_affine_coords(&x, &y))
+        }
+        PointEncoding::Uncompressed => {
+            PublicKey::Uncompressed(UncompressedPoint::from_affine_coords(&x, &y))
+        }
+    }
+}
+
+/// Decode the affine coordinates of a public key, returning an error if it
+/// isn't a valid point on the NIST P-384 curve
+fn affine_coordinates(
+    public_key: &PublicKey,
+) -> Result<(FieldElement, FieldElement), signature::Error> {
+    let bytes = public_key.as_bytes();
+    let x = FieldElement::from_bytes(&bytes[1..49]).ok_or_else(signature::Error::new)?;
+    let y_squared = x.curve_equation_rhs();
+
+    let y = match public_key {
+        PublicKey::Compressed(_) => {
+            let y = y_squared.sqrt().ok_or_else(signature::Error::new)?;
+
+            if y.is_odd() == (bytes[0] == 0x03) {
+                y
+            } else {
+                y.neg()
+            }
+        }
+        PublicKey::Uncompressed(_) => {
+            let y = FieldElement::from_bytes(&bytes[49..]).ok_or_else(signature::Error::new)?;
+
+            if y.mul(y) != y_squared {
+                return Err(signature::Error::new());
+            }
+
+            y
+        }
+    };
+
+    Ok((x, y))
+}
+
+#[cfg(feature = "spki")]
+impl FromPublicKeyDer for PublicKey {
+    /// Decode a NIST P-384 public key from a DER-encoded **SubjectPublicKeyInfo**
+    fn from_public_key_der(bytes: &[u8]) -> Result<Self, encoding::Error> {
+        let spki = SubjectPublicKeyInfo::from_der_with_algorithm(bytes, Algorithm::NistP384)?;
+        PublicKey::from_bytes(spki.subject_public_key)
+            .ok_or_else(|| encoding::error::ErrorKind::Decode.into())
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "spki"))]
+impl ToPublicKeyDer for PublicKey {
+    /// Encode a NIST P-384 public key as a DER-encoded **SubjectPublicKeyInfo**
+    fn to_public_key_der(&self) -> Vec<u8> {
+        SubjectPublicKeyInfo {
+            algorithm: Algorithm::NistP384,
+            subject_public_key: self.as_ref(),
+        }
+        .to_der()
+    }
+}
+
+#[cfg(feature = "sec1")]
+impl FromSec1 for SecretKey {
+    /// Decode a NIST P-384 secret key from a DER-encoded SEC1 `ECPrivateKey`
+    fn from_sec1_der(bytes: &[u8]) -> Result<Self, encoding::Error> {
+        let ec_private_key = EcPrivateKey::from_der_with_algorithm(bytes, Algorithm::NistP384)?;
+        SecretKey::from_bytes(ec_private_key.private_key)
+            .map_err(|_| encoding::error::ErrorKind::Decode.into())
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "sec1"))]
+impl ToSec1 for SecretKey {
+    /// Encode a NIST P-384 secret key as a DER-encoded SEC1 `ECPrivateKey`.
+    ///
+    /// The curve is identified by the `parameters` field. The optional public
+    /// key is omitted.
+    fn to_sec1_der(&self) -> Zeroizing<Vec<u8>> {
+        EcPrivateKey {
+            private_key: self.as_bytes(),
+            parameters: Some(Algorithm::NistP384),
+            public_key: None,
+        }
+        .to_der()
+    }
+}
+
+#[cfg(feature = "openssh")]
+impl FromOpenSshPublicKey for PublicKey {
+    /// Decode a NIST P-384 public key from an OpenSSH `ecdsa-sha2-nistp384`
+    /// public key line
+    fn from_openssh_public_key<S: AsRef<str>>(line: S) -> Result<Self, encoding::Error> {
+        let public_key =
+            openssh::PublicKey::from_openssh_with_algorithm(line.as_ref(), Algorithm::NistP384)?;
+
+        PublicKey::from_bytes(public_key.public_key())
+            .ok_or_else(|| encoding::error::ErrorKind::Decode.into())
+    }
+}
+
+#[cfg(feature = "openssh")]
+impl ToOpenSshPublicKey for PublicKey {
+    /// Encode a NIST P-384 public key as an OpenSSH `ecdsa-sha2-nistp384` public
+    /// key line.
+    ///
+    /// OpenSSH only accepts uncompressed points, so compressed public keys are
+    /// decompressed first (keys which aren't valid points are encoded as-is).
+    fn to_openssh_public_key(&self, comment: &str) -> String {
+        let public_key = self.to_uncompressed().unwrap_or(*self);
+        openssh::encode_public_key(Algorithm::NistP384, public_key.as_ref(), comment)
+    }
+}
+
+#[cfg(feature = "openssh")]
+impl FromOpenSshPrivateKey for SecretKey {
+    /// Obtain a NIST P-384 secret key from an OpenSSH `ecdsa-sha2-nistp384`
+    /// private key
+    fn from_openssh_private_key(
+        private_key: &openssh::PrivateKey,
+    ) -> Result<Self, encoding::Error> {
+        if private_key.algorithm != Algorithm::NistP384 {
+            Err(encoding::error::ErrorKind::Decode)?
+        }
+
+        SecretKey::from_bytes(&*private_key.private_key)
+            .map_err(|_| encoding::error::ErrorKind::Decode.into())
+    }
+}
+
+#[cfg(feature = "jwk")]
+impl FromJwk for PublicKey {
+    /// Decode a NIST P-384 public key from an `EC` JWK
+    fn from_jwk(jwk: &Jwk) -> Result<Self, encoding::Error> {
+        PublicKey::from_bytes(jwk.public_key_for(Algorithm::NistP384)?)
+            .ok_or_else(|| encoding::error::ErrorKind::Decode.into())
+    }
+}
+
+#[cfg(feature = "jwk")]
+impl ToJwk for PublicKey {
+    /// Encode a NIST P-384 public key as an `EC` JWK.
+    ///
+    /// JWKs contain both coordinates, so compressed public keys can't be
+    /// encoded and will return an error.
+    fn to_jwk(&self) -> Result<Jwk, encoding::Error> {
+        Ok(Jwk::new(Algorithm::NistP384, self.as_ref())?.with_thumbprint_kid())
+    }
+}
+
+#[cfg(feature = "jwk")]
+impl FromJwk for SecretKey {
+    /// Obtain a NIST P-384 secret key from the `d` member of an `EC` JWK,
+    /// returning an error if it doesn't match the JWK's public key
+    fn from_jwk(jwk: &Jwk) -> Result<Self, encoding::Error> {
+        let secret_key = SecretKey::from_bytes(jwk.private_key_for(Algorithm::NistP384)?)
+            .map_err(|_| encoding::error::ErrorKind::Decode)?;
+
+        match secret_key.public_key() {
+            Ok(ref public_key) if public_key.as_ref() == jwk.public_key() => Ok(secret_key),
+            _ => Err(encoding::error::ErrorKind::Decode.into()),
+        }
+    }
+}
+
+#[cfg(feature = "jwk")]
+impl ToJwk for SecretKey {
+    /// Serialize this secret key as an `EC` JWK containing both the secret
+    /// key (`d`) and the derived public key (`x` and `y`)
+    fn to_jwk(&self) -> Result<Jwk, encoding::Error> {
+        let public_key = self
+            .public_key()
+            .map_err(|_| encoding::error::ErrorKind::Encode)?;
+
+        Ok(Jwk::new(Algorithm::NistP384, public_key.as_ref())?
+            .with_private_key(self.as_bytes())?
+            .with_thumbprint_kid())
+    }
+}
+
+#[cfg(feature = "cose")]
+impl FromCoseKey for PublicKey {
+    /// Decode a NIST P-384 public key from an `EC2` COSE_Key
+    fn from_cose_key(bytes: &[u8]) -> Result<Self, encoding::Error> {
+        PublicKey::from_bytes(CoseKey::from_bytes(bytes)?.public_key_for(Algorithm::NistP384)?)
+            .ok_or_else(|| encoding::error::ErrorKind::Decode.into())
+    }
+}
+
+#[cfg(feature = "cose")]
+impl ToCoseKey for PublicKey {
+    /// Encode a NIST P-384 public key as an `EC2` COSE_Key.
+    ///
+    /// Compressed public keys are encoded using the sign bit of `y`.
+    fn to_cose_key(&self) -> Result<Vec<u8>, encoding::Error> {
+        Ok(CoseKey::new(Algorithm::NistP384, self.as_ref())?.to_bytes())
+    }
+}
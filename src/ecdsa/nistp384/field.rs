@@ -0,0 +1,316 @@
+//! Minimal arithmetic in the NIST P-384 base field, used to validate and
+//! decompress public keys, for ECDH and by the built-in ECDSA signer.
+//!
+//! Elements are stored in Montgomery form as little endian 64-bit limbs.
+//! Addition, subtraction, multiplication and inversion are constant time.
+//! Decoding, square roots and comparisons aren't, so they must only ever be
+//! used with public values such as public keys.
+//!
+//! The multi-limb helpers at the bottom of this file are shared with the
+//! scalar field, which has a different modulus.
+
+use crate::ecdsa::elliptic_curve::subtle::{Choice, ConditionallySelectable};
+use core::cmp::Ordering;
+use zeroize::Zeroize;
+
+/// Number of 64-bit limbs in a field element or scalar
+pub(super) const LIMBS: usize = 6;
+
+/// Field modulus: `p = 2^384 - 2^128 - 2^96 + 2^32 - 1`
+const MODULUS: [u64; LIMBS] = [
+    0x0000_0000_ffff_ffff,
+    0xffff_ffff_0000_0000,
+    0xffff_ffff_ffff_fffe,
+    0xffff_ffff_ffff_ffff,
+    0xffff_ffff_ffff_ffff,
+    0xffff_ffff_ffff_ffff,
+];
+
+/// `R^2 mod p`, where `R = 2^384` (used to convert into Montgomery form)
+const R2: [u64; LIMBS] = [
+    0xffff_fffe_0000_0001,
+    0x0000_0002_0000_0000,
+    0xffff_fffe_0000_0000,
+    0x0000_0002_0000_0000,
+    0x0000_0000_0000_0001,
+    0x0000_0000_0000_0000,
+];
+
+/// `p - 2`: by Fermat's little theorem, `a^(p - 2)` is the inverse of `a`
+const INVERT_EXPONENT: [u64; LIMBS] = [
+    0x0000_0000_ffff_fffd,
+    0xffff_ffff_0000_0000,
+    0xffff_ffff_ffff_fffe,
+    0xffff_ffff_ffff_ffff,
+    0xffff_ffff_ffff_ffff,
+    0xffff_ffff_ffff_ffff,
+];
+
+/// `-p^-1 mod 2^64`
+const MODULUS_INV: u64 = 0x0000_0001_0000_0001;
+
+/// `(p + 1) / 4`: as `p = 3 (mod 4)`, `a^((p + 1) / 4)` is a square root of
+/// `a` whenever one exists
+const SQRT_EXPONENT: [u64; LIMBS] = [
+    0x0000_0000_4000_0000,
+    0xbfff_ffff_c000_0000,
+    0xffff_ffff_ffff_ffff,
+    0xffff_ffff_ffff_ffff,
+    0xffff_ffff_ffff_ffff,
+    0x3fff_ffff_ffff_ffff,
+];
+
+/// Curve equation coefficient `b` (serialized as big endian bytes)
+const EQUATION_B: &[u8; 48] = b"\xb3\x31\x2f\xa7\xe2\x3e\xe7\xe4\x98\x8e\x05\x6b\xe3\xf8\x2d\x19\
+                                \x18\x1d\x9c\x6e\xfe\x81\x41\x12\x03\x14\x08\x8f\x50\x13\x87\x5a\
+                                \xc6\x56\x39\x8d\x8a\x2e\xd1\x9d\x2a\x85\xc8\xed\xd3\xec\x2a\xef";
+
+/// Element of the P-384 base field (in Montgomery form)
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(super) struct FieldElement([u64; LIMBS]);
+
+impl FieldElement {
+    /// The additive identity
+    pub fn zero() -> Self {
+        FieldElement([0; LIMBS])
+    }
+
+    /// The multiplicative identity
+    pub fn one() -> Self {
+        FieldElement(montgomery_mul(
+            &[1, 0, 0, 0, 0, 0],
+            &R2,
+            &MODULUS,
+            MODULUS_INV,
+        ))
+    }
+
+    /// Curve equation coefficient `b`
+    pub fn equation_b() -> Self {
+        Self::from_bytes(EQUATION_B).expect("invalid curve equation coefficient")
+    }
+
+    /// Decode a field element from big endian bytes, returning `None` if it
+    /// isn't less than the modulus
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let limbs = decode_limbs(bytes);
+
+        if compare(&limbs, &MODULUS) == Ordering::Less {
+            Some(FieldElement(montgomery_mul(
+                &limbs,
+                &R2,
+                &MODULUS,
+                MODULUS_INV,
+            )))
+        } else {
+            None
+        }
+    }
+
+    /// Serialize this field element as big endian bytes
+    pub fn to_bytes(self) -> [u8; LIMBS * 8] {
+        let mut one = [0u64; LIMBS];
+        one[0] = 1;
+
+        encode_limbs(&montgomery_mul(&self.0, &one, &MODULUS, MODULUS_INV))
+    }
+
+    /// Is the (canonical) value of this field element odd?
+    pub fn is_odd(self) -> bool {
+        self.to_bytes()[LIMBS * 8 - 1] & 1 == 1
+    }
+
+    /// Compute `x^3 - 3x + b`, i.e. the value of `y^2` for a point on the
+    /// curve with this `x`-coordinate
+    pub fn curve_equation_rhs(self) -> Self {
+        let b = Self::equation_b();
+        let three_x = self.add(self).add(self);
+        self.mul(self).mul(self).sub(three_x).add(b)
+    }
+
+    /// Compute a square root of this field element, returning `None` if it
+    /// isn't a square
+    pub fn sqrt(self) -> Option<Self> {
+        let root = self.pow(&SQRT_EXPONENT);
+
+        if root.mul(root) == self {
+            Some(root)
+        } else {
+            None
+        }
+    }
+
+    /// Compute `p - self`
+    pub fn neg(self) -> Self {
+        Self::zero().sub(self)
+    }
+
+    /// Add two field elements
+    pub fn add(self, other: Self) -> Self {
+        let (sum, carry) = add_limbs(&self.0, &other.0);
+        let (reduced, borrow) = sub_limbs(&sum, &MODULUS);
+        let use_reduced = Choice::from((carry | !borrow) as u8);
+        FieldElement(select(&sum, &reduced, use_reduced))
+    }
+
+    /// Subtract two field elements
+    pub fn sub(self, other: Self) -> Self {
+        let (difference, borrow) = sub_limbs(&self.0, &other.0);
+        let (wrapped, _) = add_limbs(&difference, &MODULUS);
+        FieldElement(select(&difference, &wrapped, Choice::from(borrow as u8)))
+    }
+
+    /// Multiply two field elements
+    pub fn mul(self, other: Self) -> Self {
+        FieldElement(montgomery_mul(&self.0, &other.0, &MODULUS, MODULUS_INV))
+    }
+
+    /// Compute the multiplicative inverse of this field element (zero if
+    /// it's zero)
+    pub fn invert(self) -> Self {
+        self.pow(&INVERT_EXPONENT)
+    }
+
+    /// Raise this field element to the given power (little endian limbs).
+    /// The exponent is public, so branching on its bits is fine.
+    fn pow(self, exponent: &[u64; LIMBS]) -> Self {
+        let mut result = Self::one();
+
+        for limb in exponent.iter().rev() {
+            for i in (0..64).rev() {
+                result = result.mul(result);
+
+                if (limb >> i) & 1 == 1 {
+                    result = result.mul(self);
+                }
+            }
+        }
+
+        result
+    }
+}
+
+impl ConditionallySelectable for FieldElement {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        FieldElement(select(&a.0, &b.0, choice))
+    }
+}
+
+/// Decode a big endian integer into little endian limbs
+pub(super) fn decode_limbs(bytes: &[u8]) -> [u64; LIMBS] {
+    debug_assert_eq!(bytes.len(), LIMBS * 8);
+    let mut limbs = [0u64; LIMBS];
+
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks(8).rev()) {
+        for &byte in chunk {
+            *limb = (*limb << 8) | u64::from(byte);
+        }
+    }
+
+    limbs
+}
+
+/// Serialize little endian limbs as a big endian integer
+pub(super) fn encode_limbs(limbs: &[u64; LIMBS]) -> [u8; LIMBS * 8] {
+    let mut bytes = [0u8; LIMBS * 8];
+
+    for (limb, chunk) in limbs.iter().zip(bytes.chunks_mut(8).rev()) {
+        chunk.copy_from_slice(&limb.to_be_bytes());
+    }
+
+    bytes
+}
+
+/// Compare two multi-limb integers
+fn compare(a: &[u64; LIMBS], b: &[u64; LIMBS]) -> Ordering {
+    a.iter().rev().cmp(b.iter().rev())
+}
+
+/// Select `b` if `choice` is set, otherwise `a` (in constant time)
+pub(super) fn select(a: &[u64; LIMBS], b: &[u64; LIMBS], choice: Choice) -> [u64; LIMBS] {
+    let mut result = [0u64; LIMBS];
+
+    for i in 0..LIMBS {
+        result[i] = u64::conditional_select(&a[i], &b[i], choice);
+    }
+
+    result
+}
+
+/// Add two multi-limb integers, returning the sum and the carry
+pub(super) fn add_limbs(a: &[u64; LIMBS], b: &[u64; LIMBS]) -> ([u64; LIMBS], bool) {
+    let mut sum = [0u64; LIMBS];
+    let mut carry = 0u128;
+
+    for i in 0..LIMBS {
+        let tmp = u128::from(a[i]) + u128::from(b[i]) + carry;
+        sum[i] = tmp as u64;
+        carry = tmp >> 64;
+    }
+
+    (sum, carry != 0)
+}
+
+/// Subtract two multi-limb integers, returning the difference and the borrow
+pub(super) fn sub_limbs(a: &[u64; LIMBS], b: &[u64; LIMBS]) -> ([u64; LIMBS], bool) {
+    let mut difference = [0u64; LIMBS];
+    let mut borrow = false;
+
+    for i in 0..LIMBS {
+        let (tmp, borrow1) = a[i].overflowing_sub(b[i]);
+        let (tmp, borrow2) = tmp.overflowing_sub(borrow as u64);
+        difference[i] = tmp;
+        borrow = borrow1 | borrow2;
+    }
+
+    (difference, borrow)
+}
+
+/// Montgomery multiplication: compute `a * b * R^-1 mod m` (CIOS method),
+/// where `inv = -m^-1 mod 2^64`
+pub(super) fn montgomery_mul(
+    a: &[u64; LIMBS],
+    b: &[u64; LIMBS],
+    modulus: &[u64; LIMBS],
+    inv: u64,
+) -> [u64; LIMBS] {
+    let mut t = [0u64; LIMBS + 2];
+
+    for &b_i in b.iter() {
+        let mut carry = 0u128;
+
+        for j in 0..LIMBS {
+            let tmp = u128::from(t[j]) + u128::from(a[j]) * u128::from(b_i) + carry;
+            t[j] = tmp as u64;
+            carry = tmp >> 64;
+        }
+
+        let tmp = u128::from(t[LIMBS]) + carry;
+        t[LIMBS] = tmp as u64;
+        t[LIMBS + 1] = (tmp >> 64) as u64;
+
+        let q = t[0].wrapping_mul(inv);
+        let mut carry = (u128::from(t[0]) + u128::from(q) * u128::from(modulus[0])) >> 64;
+
+        for j in 1..LIMBS {
+            let tmp = u128::from(t[j]) + u128::from(q) * u128::from(modulus[j]) + carry;
+            t[j - 1] = tmp as u64;
+            carry = tmp >> 64;
+        }
+
+        let tmp = u128::from(t[LIMBS]) + carry;
+        t[LIMBS - 1] = tmp as u64;
+        t[LIMBS] = t[LIMBS + 1] + (tmp >> 64) as u64;
+    }
+
+    let mut result = [0u64; LIMBS];
+    result.copy_from_slice(&t[..LIMBS]);
+
+    // The result is less than `2m`, so at most one subtraction is needed
+    let (reduced, borrow) = sub_limbs(&result, modulus);
+    let use_reduced = Choice::from(t[LIMBS] as u8 | u8::from(!borrow));
+    let result = select(&result, &reduced, use_reduced);
+
+    t.zeroize();
+    result
+}
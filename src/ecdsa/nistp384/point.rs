@@ -0,0 +1,175 @@
+//! Constant time arithmetic on NIST P-384 points, used for ECDH and ECDSA.
+//!
+//! Points use projective coordinates and the complete addition formula for
+//! `a = -3` curves from Renes, Costello and Batina, "Complete addition
+//! formulas for prime order elliptic curves" (2016), so the same code path
+//! handles doubling and the identity.
+
+use super::field::FieldElement;
+use crate::ecdsa::{
+    elliptic_curve::subtle::{Choice, ConditionallySelectable, ConstantTimeEq},
+    CurveOrder,
+};
+use p384::NistP384;
+
+/// `x`-coordinate of the base point (serialized as big endian bytes)
+const GENERATOR_X: &[u8; 48] = b"\xaa\x87\xca\x22\xbe\x8b\x05\x37\x8e\xb1\xc7\x1e\xf3\x20\xad\x74\
+                                 \x6e\x1d\x3b\x62\x8b\xa7\x9b\x98\x59\xf7\x41\xe0\x82\x54\x2a\x38\
+                                 \x55\x02\xf2\x5d\xbf\x55\x29\x6c\x3a\x54\x5e\x38\x72\x76\x0a\xb7";
+
+/// `y`-coordinate of the base point (serialized as big endian bytes)
+const GENERATOR_Y: &[u8; 48] = b"\x36\x17\xde\x4a\x96\x26\x2c\x6f\x5d\x9e\x98\xbf\x92\x92\xdc\x29\
+                                 \xf8\xf4\x1d\xbd\x28\x9a\x14\x7c\xe9\xda\x31\x13\xb5\xf0\xb8\xc0\
+                                 \x0a\x60\xb1\xce\x1d\x7e\x81\x9d\x7a\x43\x1d\x7c\x90\xea\x0e\x5f";
+
+/// Point on the P-384 curve in projective coordinates
+#[derive(Copy, Clone, Debug)]
+pub(super) struct ProjectivePoint {
+    x: FieldElement,
+    y: FieldElement,
+    z: FieldElement,
+}
+
+impl ProjectivePoint {
+    /// The point at infinity
+    pub fn identity() -> Self {
+        Self {
+            x: FieldElement::zero(),
+            y: FieldElement::one(),
+            z: FieldElement::zero(),
+        }
+    }
+
+    /// The base point
+    pub fn generator() -> Self {
+        Self::from_affine(
+            FieldElement::from_bytes(GENERATOR_X).unwrap(),
+            FieldElement::from_bytes(GENERATOR_Y).unwrap(),
+        )
+        .expect("invalid base point")
+    }
+
+    /// Create a point from affine coordinates, returning `None` if it isn't
+    /// on the curve
+    pub fn from_affine(x: FieldElement, y: FieldElement) -> Option<Self> {
+        if y.mul(y) == x.curve_equation_rhs() {
+            Some(Self {
+                x,
+                y,
+                z: FieldElement::one(),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Get the affine coordinates of this point, returning `None` if it's
+    /// the point at infinity
+    pub fn to_affine(self) -> Option<(FieldElement, FieldElement)> {
+        if self.z == FieldElement::zero() {
+            return None;
+        }
+
+        let z_inv = self.z.invert();
+        Some((self.x.mul(z_inv), self.y.mul(z_inv)))
+    }
+
+    /// Add two points (Renes-Costello-Batina Algorithm 4)
+    pub fn add(&self, other: &Self) -> Self {
+        let b = FieldElement::equation_b();
+        let (x1, y1, z1) = (self.x, self.y, self.z);
+        let (x2, y2, z2) = (other.x, other.y, other.z);
+
+        let mut t0 = x1.mul(x2);
+        let mut t1 = y1.mul(y2);
+        let mut t2 = z1.mul(z2);
+        let mut t3 = x1.add(y1);
+        let mut t4 = x2.add(y2);
+        t3 = t3.mul(t4);
+        t4 = t0.add(t1);
+        t3 = t3.sub(t4);
+        t4 = y1.add(z1);
+        let mut x3 = y2.add(z2);
+        t4 = t4.mul(x3);
+        x3 = t1.add(t2);
+        t4 = t4.sub(x3);
+        x3 = x1.add(z1);
+        let mut y3 = x2.add(z2);
+        x3 = x3.mul(y3);
+        y3 = t0.add(t2);
+        y3 = x3.sub(y3);
+        let mut z3 = b.mul(t2);
+        x3 = y3.sub(z3);
+        z3 = x3.add(x3);
+        x3 = x3.add(z3);
+        z3 = t1.sub(x3);
+        x3 = t1.add(x3);
+        y3 = b.mul(y3);
+        t1 = t2.add(t2);
+        t2 = t1.add(t2);
+        y3 = y3.sub(t2);
+        y3 = y3.sub(t0);
+        t1 = y3.add(y3);
+        y3 = t1.add(y3);
+        t1 = t0.add(t0);
+        t0 = t1.add(t0);
+        t0 = t0.sub(t2);
+        t1 = t4.mul(y3);
+        t2 = t0.mul(y3);
+        y3 = x3.mul(z3);
+        y3 = y3.add(t2);
+        x3 = t3.mul(x3);
+        x3 = x3.sub(t1);
+        z3 = t4.mul(z3);
+        t1 = t3.mul(t0);
+        z3 = z3.add(t1);
+
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// Multiply this point by the given big endian scalar (in constant time)
+    pub fn mul(&self, scalar: &[u8]) -> Self {
+        let mut result = Self::identity();
+
+        for byte in scalar {
+            for i in (0..8).rev() {
+                result = result.add(&result);
+                let sum = result.add(self);
+                result = Self::conditional_select(&result, &sum, Choice::from((byte >> i) & 1));
+            }
+        }
+
+        result
+    }
+}
+
+impl ConditionallySelectable for ProjectivePoint {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            x: FieldElement::conditional_select(&a.x, &b.x, choice),
+            y: FieldElement::conditional_select(&a.y, &b.y, choice),
+            z: FieldElement::conditional_select(&a.z, &b.z, choice),
+        }
+    }
+}
+
+/// Is the given big endian scalar nonzero and less than the curve order?
+/// (in constant time)
+pub(super) fn is_valid_scalar(scalar: &[u8]) -> Choice {
+    debug_assert_eq!(scalar.len(), NistP384::ORDER.len());
+    let mut borrow = 0u16;
+
+    // Compute the borrow of `scalar - n`, which is set iff `scalar < n`
+    for (&scalar_byte, &order_byte) in scalar.iter().zip(NistP384::ORDER).rev() {
+        let difference = u16::from(scalar_byte)
+            .wrapping_sub(u16::from(order_byte))
+            .wrapping_sub(borrow);
+        borrow = difference >> 15;
+    }
+
+    Choice::from(borrow as u8) & !scalar.ct_eq(&[0u8; 48][..])
+}
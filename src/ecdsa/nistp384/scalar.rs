@@ -0,0 +1,129 @@
+//! Arithmetic modulo the order of the NIST P-384 base point, used by the
+//! built-in ECDSA signer and verifier.
+//!
+//! Scalars are stored in Montgomery form as little endian 64-bit limbs, using
+//! the same limb arithmetic as the base field. Everything except inversion
+//! (whose exponent is public) is constant time.
+
+use super::field::{
+    add_limbs, decode_limbs, encode_limbs, montgomery_mul, select, sub_limbs, LIMBS,
+};
+use crate::ecdsa::elliptic_curve::subtle::{Choice, ConstantTimeEq};
+use zeroize::Zeroize;
+
+/// Order of the base point: `n`
+const ORDER: [u64; LIMBS] = [
+    0xecec_196a_ccc5_2973,
+    0x581a_0db2_48b0_a77a,
+    0xc763_4d81_f437_2ddf,
+    0xffff_ffff_ffff_ffff,
+    0xffff_ffff_ffff_ffff,
+    0xffff_ffff_ffff_ffff,
+];
+
+/// `R^2 mod n`, where `R = 2^384` (used to convert into Montgomery form)
+const R2: [u64; LIMBS] = [
+    0x2d31_9b24_19b4_09a9,
+    0xff3d_81e5_df1a_a419,
+    0xbc3e_483a_fcb8_2947,
+    0xd40d_4917_4aab_1cc5,
+    0x3fb0_5b7a_2826_6895,
+    0x0c84_ee01_2b39_bf21,
+];
+
+/// `-n^-1 mod 2^64`
+const ORDER_INV: u64 = 0x6ed4_6089_e88f_dc45;
+
+/// The integer one
+const ONE: [u64; LIMBS] = [1, 0, 0, 0, 0, 0];
+
+/// Element of the scalar field, i.e. integers modulo the order of the base
+/// point (in Montgomery form)
+#[derive(Copy, Clone, Debug, Default)]
+pub(super) struct Scalar([u64; LIMBS]);
+
+impl Scalar {
+    /// Decode a scalar from big endian bytes, returning `None` if it isn't
+    /// less than the curve order
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut limbs = decode_limbs(bytes);
+        let (_, borrow) = sub_limbs(&limbs, &ORDER);
+
+        let result = if borrow {
+            Some(Scalar(to_montgomery(&limbs)))
+        } else {
+            None
+        };
+
+        limbs.zeroize();
+        result
+    }
+
+    /// Decode a 384-bit big endian integer (e.g. a SHA-384 digest or the
+    /// `x`-coordinate of a point) and reduce it modulo the curve order
+    pub fn from_bytes_reduced(bytes: &[u8]) -> Self {
+        // The value is less than `2^384 < 2n`, so at most one subtraction is
+        // needed
+        let limbs = decode_limbs(bytes);
+        let (reduced, borrow) = sub_limbs(&limbs, &ORDER);
+        Scalar(to_montgomery(&select(
+            &reduced,
+            &limbs,
+            Choice::from(borrow as u8),
+        )))
+    }
+
+    /// Serialize this scalar as big endian bytes
+    pub fn to_bytes(self) -> [u8; LIMBS * 8] {
+        encode_limbs(&montgomery_mul(&self.0, &ONE, &ORDER, ORDER_INV))
+    }
+
+    /// Is this scalar zero?
+    pub fn is_zero(&self) -> Choice {
+        self.0.ct_eq(&[0; LIMBS])
+    }
+
+    /// Add two scalars
+    pub fn add(&self, other: &Self) -> Self {
+        let (sum, carry) = add_limbs(&self.0, &other.0);
+        let (reduced, borrow) = sub_limbs(&sum, &ORDER);
+        let use_reduced = Choice::from((carry | !borrow) as u8);
+        Scalar(select(&sum, &reduced, use_reduced))
+    }
+
+    /// Multiply two scalars
+    pub fn mul(&self, other: &Self) -> Self {
+        Scalar(montgomery_mul(&self.0, &other.0, &ORDER, ORDER_INV))
+    }
+
+    /// Compute the multiplicative inverse of this scalar as `a^(n - 2)`
+    /// (zero if it's zero). The exponent is public, so branching on its bits
+    /// is fine.
+    pub fn invert(&self) -> Self {
+        let (exponent, _) = sub_limbs(&ORDER, &[2, 0, 0, 0, 0, 0]);
+        let mut result = Scalar(to_montgomery(&ONE));
+
+        for limb in exponent.iter().rev() {
+            for i in (0..64).rev() {
+                result = result.mul(&result);
+
+                if (limb >> i) & 1 == 1 {
+                    result = result.mul(self);
+                }
+            }
+        }
+
+        result
+    }
+}
+
+impl Zeroize for Scalar {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// Convert an integer less than `n` into Montgomery form
+fn to_montgomery(limbs: &[u64; LIMBS]) -> [u64; LIMBS] {
+    montgomery_mul(limbs, &R2, &ORDER, ORDER_INV)
+}
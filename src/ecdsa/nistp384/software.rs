@@ -0,0 +1,225 @@
+//! Pure-Rust software implementation of ECDSA/P-384
+//!
+//! Signatures are deterministic: the ephemeral scalar `k` is derived from the
+//! secret key and message digest as described in RFC 6979, so no random
+//! number generator is needed.
+
+use super::{
+    affine_coordinates, point::ProjectivePoint, scalar::Scalar, Asn1Signature, FixedSignature,
+    NistP384, PublicKey, SecretKey,
+};
+#[cfg(feature = "alloc")]
+use crate::batch::{self, BatchError, BatchItem, BatchVerifier};
+#[cfg(feature = "pkcs8")]
+use crate::{
+    ecdsa::PointConversion,
+    encoding::{self, pkcs8::PrivateKeyInfo, Algorithm, FromPkcs8},
+};
+use crate::{
+    ecdsa::{generic_array::GenericArray, rfc6979},
+    public_key::PublicKeyed,
+};
+use sha2::{Digest, Sha384};
+use signature::{DigestSigner, DigestVerifier, Error, Signature};
+use zeroize::Zeroize;
+
+/// NIST P-384 ECDSA signer
+pub struct Signer {
+    /// Secret scalar
+    secret_scalar: Scalar,
+
+    /// Public key (as an uncompressed point)
+    public_key: PublicKey,
+}
+
+impl Signer {
+    /// Create a new signer from the given secret key, returning an error if
+    /// it's zero or not less than the curve order
+    pub fn new(secret_key: &SecretKey) -> Result<Self, Error> {
+        let secret_scalar = Scalar::from_bytes(secret_key.as_bytes())
+            .filter(|scalar| !bool::from(scalar.is_zero()))
+            .ok_or_else(Error::new)?;
+
+        Ok(Self {
+            secret_scalar,
+            public_key: secret_key.public_key()?,
+        })
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+impl FromPkcs8 for Signer {
+    /// Create a new NIST P-384 signer from a PKCS#8 private key, checking it
+    /// against the public key if the document contains one
+    fn from_pkcs8<K: AsRef<[u8]>>(secret_key: K) -> Result<Self, encoding::Error> {
+        let private_key_info = PrivateKeyInfo::from_der(secret_key.as_ref())?;
+
+        if private_key_info.algorithm != Algorithm::NistP384 {
+            return Err(encoding::error::ErrorKind::Decode.into());
+        }
+
+        let signer = SecretKey::from_bytes(private_key_info.private_key)
+            .ok()
+            .and_then(|secret_key| Signer::new(&secret_key).ok())
+            .ok_or(encoding::error::ErrorKind::Decode)?;
+
+        if let Some(public_key) = private_key_info.public_key {
+            let public_key = PublicKey::from_bytes(public_key)
+                .and_then(|public_key| public_key.to_uncompressed().ok());
+
+            if public_key != Some(signer.public_key) {
+                return Err(encoding::error::ErrorKind::Decode.into());
+            }
+        }
+
+        Ok(signer)
+    }
+}
+
+impl PublicKeyed<PublicKey> for Signer {
+    fn public_key(&self) -> Result<PublicKey, Error> {
+        Ok(self.public_key)
+    }
+}
+
+impl DigestSigner<Sha384, FixedSignature> for Signer {
+    fn try_sign_digest(&self, digest: Sha384) -> Result<FixedSignature, Error> {
+        let e = Scalar::from_bytes_reduced(&digest.finalize());
+        let mut x = GenericArray::clone_from_slice(&self.secret_scalar.to_bytes());
+        let h1 = GenericArray::clone_from_slice(&e.to_bytes());
+
+        // The curve order is exactly 384 bits, so `bits2int` is the identity
+        let mut k = rfc6979::generate_k::<NistP384, Sha384, _, _>(&x, &h1, |bytes| {
+            Scalar::from_bytes(bytes).filter(|k| !bool::from(k.is_zero()))
+        });
+
+        x.as_mut_slice().zeroize();
+
+        // r = x-coordinate of kG (mod n)
+        let mut k_bytes = k.to_bytes();
+        let r = ProjectivePoint::generator()
+            .mul(&k_bytes)
+            .to_affine()
+            .map(|(x, _)| Scalar::from_bytes_reduced(&x.to_bytes()))
+            .filter(|r| !bool::from(r.is_zero()));
+
+        k_bytes.zeroize();
+
+        // s = k^-1 * (e + r * x) (mod n)
+        let result = r
+            .map(|r| {
+                let s = k.invert().mul(&e.add(&r.mul(&self.secret_scalar)));
+
+                (r, s)
+            })
+            .filter(|(_, s)| !bool::from(s.is_zero()))
+            .map(|(r, s)| {
+                FixedSignature::from_scalars(
+                    GenericArray::from_slice(&r.to_bytes()),
+                    GenericArray::from_slice(&s.to_bytes()),
+                )
+            })
+            .ok_or_else(Error::new);
+
+        k.zeroize();
+        result
+    }
+}
+
+impl DigestSigner<Sha384, Asn1Signature> for Signer {
+    fn try_sign_digest(&self, digest: Sha384) -> Result<Asn1Signature, Error> {
+        DigestSigner::<Sha384, FixedSignature>::try_sign_digest(self, digest)
+            .map(|signature| signature.to_asn1())
+    }
+}
+
+impl signature::Signer<FixedSignature> for Signer {
+    fn try_sign(&self, msg: &[u8]) -> Result<FixedSignature, Error> {
+        self.try_sign_digest(Sha384::new().chain(msg))
+    }
+}
+
+impl signature::Signer<Asn1Signature> for Signer {
+    fn try_sign(&self, msg: &[u8]) -> Result<Asn1Signature, Error> {
+        self.try_sign_digest(Sha384::new().chain(msg))
+    }
+}
+
+impl Drop for Signer {
+    fn drop(&mut self) {
+        self.secret_scalar.zeroize();
+    }
+}
+
+/// NIST P-384 ECDSA verifier
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Verifier(PublicKey);
+
+impl<'a> From<&'a PublicKey> for Verifier {
+    fn from(public_key: &'a PublicKey) -> Self {
+        Verifier(*public_key)
+    }
+}
+
+impl DigestVerifier<Sha384, FixedSignature> for Verifier {
+    fn verify_digest(&self, digest: Sha384, signature: &FixedSignature) -> Result<(), Error> {
+        let (x, y) = affine_coordinates(&self.0)?;
+        let public_point = ProjectivePoint::from_affine(x, y).ok_or_else(Error::new)?;
+
+        let (r, s) = signature.as_bytes().split_at(48);
+        let r = Scalar::from_bytes(r)
+            .filter(|r| !bool::from(r.is_zero()))
+            .ok_or_else(Error::new)?;
+        let s = Scalar::from_bytes(s)
+            .filter(|s| !bool::from(s.is_zero()))
+            .ok_or_else(Error::new)?;
+
+        // R = (e * s^-1)G + (r * s^-1)Q
+        let e = Scalar::from_bytes_reduced(&digest.finalize());
+        let s_inv = s.invert();
+        let point = ProjectivePoint::generator()
+            .mul(&e.mul(&s_inv).to_bytes())
+            .add(&public_point.mul(&r.mul(&s_inv).to_bytes()));
+
+        match point.to_affine() {
+            Some((x, _))
+                if Scalar::from_bytes_reduced(&x.to_bytes()).to_bytes() == r.to_bytes() =>
+            {
+                Ok(())
+            }
+            _ => Err(Error::new()),
+        }
+    }
+}
+
+impl DigestVerifier<Sha384, Asn1Signature> for Verifier {
+    fn verify_digest(&self, digest: Sha384, signature: &Asn1Signature) -> Result<(), Error> {
+        self.verify_digest(digest, &FixedSignature::from_asn1(signature.as_ref())?)
+    }
+}
+
+impl signature::Verifier<FixedSignature> for Verifier {
+    fn verify(&self, msg: &[u8], signature: &FixedSignature) -> Result<(), Error> {
+        self.verify_digest(Sha384::new().chain(msg), signature)
+    }
+}
+
+impl signature::Verifier<Asn1Signature> for Verifier {
+    fn verify(&self, msg: &[u8], signature: &Asn1Signature) -> Result<(), Error> {
+        self.verify_digest(Sha384::new().chain(msg), signature)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl BatchVerifier<PublicKey, Asn1Signature> for Verifier {
+    fn verify_batch(items: &[BatchItem<'_, PublicKey, Asn1Signature>]) -> Result<(), BatchError> {
+        batch::verify_sequentially::<_, _, Self>(items)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl BatchVerifier<PublicKey, FixedSignature> for Verifier {
+    fn verify_batch(items: &[BatchItem<'_, PublicKey, FixedSignature>]) -> Result<(), BatchError> {
+        batch::verify_sequentially::<_, _, Self>(items)
+    }
+}
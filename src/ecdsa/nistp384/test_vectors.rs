@@ -3,6 +3,8 @@
 //! Sourced from NIST's CAVP web site (FIPS 186-4 ECDSA Test Vectors):
 //!
 //! <https://csrc.nist.gov/Projects/Cryptographic-Algorithm-Validation-Program/Digital-Signatures>
+//!
+//! Deterministic (RFC 6979) signatures are from RFC 6979 Appendix A.2.6.
 
 use crate::ecdsa::TestVector;
 
@@ -114,3 +116,35 @@ pub const SHA384_FIXED_SIZE_TEST_VECTORS: &[TestVector] = &[
         sig: b"\xee\x82\xc0\xf9\x05\x01\x13\x6e\xb0\xdc\x0e\x45\x9a\xd1\x7b\xf3\xbe\x1b\x1c\x8b\x8d\x05\xc6\x00\x68\xa9\x30\x6a\x34\x63\x26\xff\x73\x44\x77\x6a\x95\xf1\xf7\xe2\xe2\xcf\x94\x77\x13\x0e\x73\x5c\xaf\x10\xb9\x0f\x20\x3a\xf2\x3b\x75\x00\xe0\x70\x53\x6e\x64\x62\x9b\xa1\x92\x45\xd6\xef\x39\xaa\xb5\x7f\xcd\xb1\xb7\x3c\x4c\x6b\xf7\x07\x0c\x62\x63\x54\x46\x33\xd3\xd3\x58\xc1\x2a\x17\x81\x38",
     },
 ];
+
+/// ECDSA P-384 test vectors for deterministic signatures (RFC 6979), with
+/// SHA-384 as the message digest and HMAC-DRBG hash function
+///
+/// <https://tools.ietf.org/html/rfc6979#appendix-A.2.6>
+pub const SHA384_RFC6979_TEST_VECTORS: &[TestVector] = &[
+    // RFC 6979 Appendix A.2.6, with SHA-384 and message "sample"
+    TestVector {
+        sk: b"\x6b\x9d\x3d\xad\x2e\x1b\x8c\x1c\x05\xb1\x98\x75\xb6\x65\x9f\x4d\xe2\x3c\x3b\x66\x7b\xf2\x97\xba\x9a\xa4\x77\x40\x78\x71\x37\xd8\x96\xd5\x72\x4e\x4c\x70\xa8\x25\xf8\x72\xc9\xea\x60\xd2\xed\xf5",
+        pk: b"\xec\x3a\x4e\x41\x5b\x4e\x19\xa4\x56\x86\x18\x02\x9f\x42\x7f\xa5\xda\x9a\x8b\xc4\xae\x92\xe0\x2e\x06\xaa\xe5\x28\x6b\x30\x0c\x64\xde\xf8\xf0\xea\x90\x55\x86\x60\x64\xa2\x54\x51\x54\x80\xbc\x13\x80\x15\xd9\xb7\x2d\x7d\x57\x24\x4e\xa8\xef\x9a\xc0\xc6\x21\x89\x67\x08\xa5\x93\x67\xf9\xdf\xb9\xf5\x4c\xa8\x4b\x3f\x1c\x9d\xb1\x28\x8b\x23\x1c\x3a\xe0\xd4\xfe\x73\x44\xfd\x25\x33\x26\x47\x20",
+        nonce: Some(b"\x94\xed\x91\x0d\x1a\x09\x9d\xad\x32\x54\xe9\x24\x2a\xe8\x5a\xbd\xe4\xba\x15\x16\x8e\xaf\x0c\xa8\x7a\x55\x5f\xd5\x6d\x10\xfb\xca\x29\x07\xe3\xe8\x3b\xa9\x53\x68\x62\x3b\x8c\x46\x86\x91\x5c\xf9"),
+        msg: b"\x73\x61\x6d\x70\x6c\x65",
+        sig: b"\x94\xed\xbb\x92\xa5\xec\xb8\xaa\xd4\x73\x6e\x56\xc6\x91\x91\x6b\x3f\x88\x14\x06\x66\xce\x9f\xa7\x3d\x64\xc4\xea\x95\xad\x13\x3c\x81\xa6\x48\x15\x2e\x44\xac\xf9\x6e\x36\xdd\x1e\x80\xfa\xbe\x46\x99\xef\x4a\xeb\x15\xf1\x78\xce\xa1\xfe\x40\xdb\x26\x03\x13\x8f\x13\x0e\x74\x0a\x19\x62\x45\x26\x20\x3b\x63\x51\xd0\xa3\xa9\x4f\xa3\x29\xc1\x45\x78\x6e\x67\x9e\x7b\x82\xc7\x1a\x38\x62\x8a\xc8",
+    },
+    // RFC 6979 Appendix A.2.6, with SHA-384 and message "test"
+    TestVector {
+        sk: b"\x6b\x9d\x3d\xad\x2e\x1b\x8c\x1c\x05\xb1\x98\x75\xb6\x65\x9f\x4d\xe2\x3c\x3b\x66\x7b\xf2\x97\xba\x9a\xa4\x77\x40\x78\x71\x37\xd8\x96\xd5\x72\x4e\x4c\x70\xa8\x25\xf8\x72\xc9\xea\x60\xd2\xed\xf5",
+        pk: b"\xec\x3a\x4e\x41\x5b\x4e\x19\xa4\x56\x86\x18\x02\x9f\x42\x7f\xa5\xda\x9a\x8b\xc4\xae\x92\xe0\x2e\x06\xaa\xe5\x28\x6b\x30\x0c\x64\xde\xf8\xf0\xea\x90\x55\x86\x60\x64\xa2\x54\x51\x54\x80\xbc\x13\x80\x15\xd9\xb7\x2d\x7d\x57\x24\x4e\xa8\xef\x9a\xc0\xc6\x21\x89\x67\x08\xa5\x93\x67\xf9\xdf\xb9\xf5\x4c\xa8\x4b\x3f\x1c\x9d\xb1\x28\x8b\x23\x1c\x3a\xe0\xd4\xfe\x73\x44\xfd\x25\x33\x26\x47\x20",
+        nonce: Some(b"\x01\x5e\xe4\x6a\x5b\xf8\x87\x73\xed\x91\x23\xa5\xab\x08\x07\x96\x2d\x19\x37\x19\x50\x3c\x52\x7b\x03\x1b\x4c\x2d\x22\x50\x92\xad\xa7\x1f\x4a\x45\x9b\xc0\xda\x98\xad\xb9\x58\x37\xdb\x83\x12\xea"),
+        msg: b"\x74\x65\x73\x74",
+        sig: b"\x82\x03\xb6\x3d\x3c\x85\x3e\x8d\x77\x22\x7f\xb3\x77\xbc\xf7\xb7\xb7\x72\xe9\x78\x92\xa8\x0f\x36\xab\x77\x5d\x50\x9d\x7a\x5f\xeb\x05\x42\xa7\xf0\x81\x29\x98\xda\x8f\x1d\xd3\xca\x3c\xf0\x23\xdb\xdd\xd0\x76\x04\x48\xd4\x2d\x8a\x43\xaf\x45\xaf\x83\x6f\xce\x4d\xe8\xbe\x06\xb4\x85\xe9\xb6\x1b\x82\x7c\x2f\x13\x17\x39\x23\xe0\x6a\x73\x9f\x04\x06\x49\xa6\x67\xbf\x3b\x82\x82\x46\xba\xa5\xa5",
+    },
+    // secret key n - 1, with SHA-384 and message "sample" (cross-checked
+    // against OpenSSL's deterministic ECDSA)
+    TestVector {
+        sk: b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xc7\x63\x4d\x81\xf4\x37\x2d\xdf\x58\x1a\x0d\xb2\x48\xb0\xa7\x7a\xec\xec\x19\x6a\xcc\xc5\x29\x72",
+        pk: b"\xaa\x87\xca\x22\xbe\x8b\x05\x37\x8e\xb1\xc7\x1e\xf3\x20\xad\x74\x6e\x1d\x3b\x62\x8b\xa7\x9b\x98\x59\xf7\x41\xe0\x82\x54\x2a\x38\x55\x02\xf2\x5d\xbf\x55\x29\x6c\x3a\x54\x5e\x38\x72\x76\x0a\xb7\xc9\xe8\x21\xb5\x69\xd9\xd3\x90\xa2\x61\x67\x40\x6d\x6d\x23\xd6\x07\x0b\xe2\x42\xd7\x65\xeb\x83\x16\x25\xce\xec\x4a\x0f\x47\x3e\xf5\x9f\x4e\x30\xe2\x81\x7e\x62\x85\xbc\xe2\x84\x6f\x15\xf1\xa0",
+        nonce: Some(b"\xdc\xf7\x74\xf2\x14\x76\xb8\x7f\xc6\xa2\x61\xb5\x15\x22\x5e\xbd\x48\xb6\xbb\xc3\x9c\x1c\x19\x79\xcb\x7a\x05\xd7\x10\x7f\x57\x73\xc1\x17\x37\x5c\x41\xfa\x2b\xa4\x42\x6b\x9d\x10\x52\xb5\x9c\x10"),
+        msg: b"\x73\x61\x6d\x70\x6c\x65",
+        sig: b"\xe1\x14\xe5\xc6\x35\x92\xa2\xaf\x18\x74\x17\x38\x50\x85\x9b\xf9\x21\x1d\x51\xea\x41\x0e\x5e\x97\x3b\x0b\x56\x43\x8b\x0f\xb7\x54\xee\xe0\xd8\xf0\xbd\xb5\xe9\x13\x81\x0b\x99\xd4\x27\x1c\xa3\xa4\x24\x9f\xc8\x34\xc9\xe5\x32\xf1\x97\x53\xd1\x1f\x35\xe7\xb1\x71\xd2\x18\x6e\xf4\x8e\x1f\x92\x6f\x13\xfe\x69\xf1\xa6\x3b\x7e\xa2\xeb\xfc\x40\x44\x0f\xa0\x63\xfe\xc2\x36\x74\xa3\xf8\xdd\x2e\x9f",
+    },
+];
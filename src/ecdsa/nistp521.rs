@@ -0,0 +1,325 @@
+//! NIST P-521
+//!
+//! There's no RustCrypto crate for P-521 yet, so the curve type and the
+//! arithmetic needed for point validation and the built-in software provider
+//! (the `ecdsa-software` feature) live in this module.
+
+// Scalars and points are only needed by the built-in software provider
+#[cfg_attr(not(feature = "ecdsa-software"), allow(dead_code))]
+mod arithmetic;
+#[cfg(feature = "ecdsa-software")]
+mod software;
+#[cfg(feature = "test-vectors")]
+pub mod test_vectors;
+
+#[cfg(feature = "ecdsa-software")]
+pub use self::software::{Signer, Verifier};
+
+use self::arithmetic::{FieldElement, ELEMENT_SIZE};
+#[cfg(feature = "ecdsa-software")]
+use self::arithmetic::{ProjectivePoint, Scalar};
+use super::{
+    elliptic_curve::{
+        self,
+        generic_array::{typenum::U66, GenericArray},
+        weierstrass::point::{CompressedPoint, UncompressedPoint},
+    },
+    CurveOrder, PointConversion, PointEncoding,
+};
+#[cfg(all(feature = "alloc", feature = "spki"))]
+use crate::encoding::ToPublicKeyDer;
+#[cfg(all(feature = "alloc", feature = "sec1"))]
+use crate::encoding::ToSec1;
+#[cfg(any(
+    feature = "cose",
+    feature = "jwk",
+    feature = "openssh",
+    feature = "sec1",
+    feature = "spki"
+))]
+use crate::encoding::{self, Algorithm};
+#[cfg(feature = "cose")]
+use crate::encoding::{cose::CoseKey, FromCoseKey, ToCoseKey};
+#[cfg(feature = "openssh")]
+use crate::encoding::{openssh, FromOpenSshPrivateKey, FromOpenSshPublicKey, ToOpenSshPublicKey};
+#[cfg(feature = "sec1")]
+use crate::encoding::{sec1::EcPrivateKey, FromSec1};
+#[cfg(feature = "spki")]
+use crate::encoding::{spki::SubjectPublicKeyInfo, FromPublicKeyDer};
+#[cfg(feature = "jwk")]
+use crate::encoding::{FromJwk, Jwk, ToJwk};
+#[cfg(feature = "ecdsa-software")]
+use crate::public_key::PublicKeyed;
+#[cfg(feature = "openssh")]
+use alloc::string::String;
+#[cfg(any(
+    feature = "cose",
+    all(feature = "alloc", any(feature = "sec1", feature = "spki"))
+))]
+use alloc::vec::Vec;
+use sha2::Sha512;
+#[cfg(feature = "ecdsa-software")]
+use zeroize::Zeroize;
+#[cfg(all(feature = "alloc", feature = "sec1"))]
+use zeroize::Zeroizing;
+
+/// NIST P-521 elliptic curve (a.k.a. secp521r1)
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub struct NistP521;
+
+impl elliptic_curve::Curve for NistP521 {
+    /// 521-bit field modulus, serialized as 66 bytes
+    type ElementSize = U66;
+}
+
+impl elliptic_curve::weierstrass::Curve for NistP521 {
+    /// NIST P-521 points are typically uncompressed
+    const COMPRESS_POINTS: bool = false;
+}
+
+impl ::ecdsa::hazmat::DigestPrimitive for NistP521 {
+    type Digest = Sha512;
+}
+
+/// NIST P-521 public key
+pub type PublicKey = elliptic_curve::weierstrass::PublicKey<NistP521>;
+
+/// NIST P-521 secret key
+pub type SecretKey = elliptic_curve::SecretKey<NistP521>;
+
+/// NIST P-521 ASN.1 signature
+pub type Asn1Signature = super::Asn1Signature<NistP521>;
+
+/// NIST P-521 fixed signature
+pub type FixedSignature = super::FixedSignature<NistP521>;
+
+impl CurveOrder for NistP521 {
+    const ORDER: &'static [u8] = b"\x01\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xfa\x51\x86\x87\x83\xbf\x2f\x96\x6b\x7f\xcc\x01\x48\xf7\x09\xa5\xd0\x3b\xb5\xc9\xb8\x89\x9c\x47\xae\xbb\x6f\xb7\x1e\x91\x38\x64\x09";
+}
+
+impl PointConversion for PublicKey {
+    /// Convert this public key to the given SEC1 encoding, returning an error
+    /// if it isn't a valid point on the NIST P-521 curve
+    fn to_encoding(&self, encoding: PointEncoding) -> Result<Self, signature::Error> {
+        let bytes = self.as_bytes();
+        let x =
+            FieldElement::from_bytes(&bytes[1..=ELEMENT_SIZE]).ok_or_else(signature::Error::new)?;
+        let y_squared = x.curve_equation_rhs();
+
+        let y = match self {
+            PublicKey::Compressed(_) => {
+                let y = y_squared.sqrt().ok_or_else(signature::Error::new)?;
+
+                if y.is_odd() == (bytes[0] == 0x03) {
+                    y
+                } else {
+                    y.neg()
+                }
+            }
+            PublicKey::Uncompressed(_) => {
+                let y = FieldElement::from_bytes(&bytes[(ELEMENT_SIZE + 1)..])
+                    .ok_or_else(signature::Error::new)?;
+
+                if y.mul(y) != y_squared {
+                    return Err(signature::Error::new());
+                }
+
+                y
+            }
+        };
+
+        Ok(encode_point(x, y, encoding))
+    }
+}
+
+#[cfg(feature = "ecdsa-software")]
+impl PublicKeyed<PublicKey> for SecretKey {
+    /// Derive the NIST P-521 public key for this secret key (as an uncompressed
+    /// point), returning an error if the secret key is zero or not less than
+    /// the curve order
+    fn public_key(&self) -> Result<PublicKey, signature::Error> {
+        let mut scalar = Scalar::from_bytes(self.as_bytes())
+            .filter(|scalar| !bool::from(scalar.is_zero()))
+            .ok_or_else(signature::Error::new)?;
+
+        let point = ProjectivePoint::generator().mul(&scalar);
+        scalar.zeroize();
+
+        let (x, y) = point.to_affine().ok_or_else(signature::Error::new)?;
+        Ok(encode_point(x, y, PointEncoding::Uncompressed))
+    }
+}
+
+/// Serialize the given affine coordinates as a public key
+fn encode_point(x: FieldElement, y: FieldElement, encoding: PointEncoding) -> PublicKey {
+    let x = GenericArray::clone_from_slice(&x.to_bytes());
+    let y = GenericArray::clone_from_slice(&y.to_bytes());
+
+    match encoding {
+        PointEncoding::Compressed => {
+            PublicKey::Compressed(CompressedPoint::from_affine_coords(&x, &y))
+        }
+        PointEncoding::Uncompressed => {
+            PublicKey::Uncompressed(UncompressedPoint::from_affine_coords(&x, &y))
+        }
+    }
+}
+
+#[cfg(feature = "spki")]
+impl FromPublicKeyDer for PublicKey {
+    /// Decode a NIST P-521 public key from a DER-encoded **SubjectPublicKeyInfo**
+    fn from_public_key_der(bytes: &[u8]) -> Result<Self, encoding::Error> {
+        let spki = SubjectPublicKeyInfo::from_der_with_algorithm(bytes, Algorithm::NistP521)?;
+        PublicKey::from_bytes(spki.subject_public_key)
+            .ok_or_else(|| encoding::error::ErrorKind::Decode.into())
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "spki"))]
+impl ToPublicKeyDer for PublicKey {
+    /// Encode a NIST P-521 public key as a DER-encoded **SubjectPublicKeyInfo**
+    fn to_public_key_der(&self) -> Vec<u8> {
+        SubjectPublicKeyInfo {
+            algorithm: Algorithm::NistP521,
+            subject_public_key: self.as_ref(),
+        }
+        .to_der()
+    }
+}
+
+#[cfg(feature = "sec1")]
+impl FromSec1 for SecretKey {
+    /// Decode a NIST P-521 secret key from a DER-encoded SEC1 `ECPrivateKey`
+    fn from_sec1_der(bytes: &[u8]) -> Result<Self, encoding::Error> {
+        let ec_private_key = EcPrivateKey::from_der_with_algorithm(bytes, Algorithm::NistP521)?;
+        SecretKey::from_bytes(ec_private_key.private_key)
+            .map_err(|_| encoding::error::ErrorKind::Decode.into())
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "sec1"))]
+impl ToSec1 for SecretKey {
+    /// Encode a NIST P-521 secret key as a DER-encoded SEC1 `ECPrivateKey`.
+    ///
+    /// The curve is identified by the `parameters` field. The optional public
+    /// key is omitted.
+    fn to_sec1_der(&self) -> Zeroizing<Vec<u8>> {
+        EcPrivateKey {
+            private_key: self.as_bytes(),
+            parameters: Some(Algorithm::NistP521),
+            public_key: None,
+        }
+        .to_der()
+    }
+}
+
+#[cfg(feature = "openssh")]
+impl FromOpenSshPublicKey for PublicKey {
+    /// Decode a NIST P-521 public key from an OpenSSH `ecdsa-sha2-nistp521`
+    /// public key line
+    fn from_openssh_public_key<S: AsRef<str>>(line: S) -> Result<Self, encoding::Error> {
+        let public_key =
+            openssh::PublicKey::from_openssh_with_algorithm(line.as_ref(), Algorithm::NistP521)?;
+
+        PublicKey::from_bytes(public_key.public_key())
+            .ok_or_else(|| encoding::error::ErrorKind::Decode.into())
+    }
+}
+
+#[cfg(feature = "openssh")]
+impl ToOpenSshPublicKey for PublicKey {
+    /// Encode a NIST P-521 public key as an OpenSSH `ecdsa-sha2-nistp521` public
+    /// key line.
+    ///
+    /// OpenSSH only accepts uncompressed points, so compressed public keys are
+    /// decompressed first (keys which aren't valid points are encoded as-is).
+    fn to_openssh_public_key(&self, comment: &str) -> String {
+        let public_key = self.to_uncompressed().unwrap_or(*self);
+        openssh::encode_public_key(Algorithm::NistP521, public_key.as_ref(), comment)
+    }
+}
+
+#[cfg(feature = "openssh")]
+impl FromOpenSshPrivateKey for SecretKey {
+    /// Obtain a NIST P-521 secret key from an OpenSSH `ecdsa-sha2-nistp521`
+    /// private key
+    fn from_openssh_private_key(
+        private_key: &openssh::PrivateKey,
+    ) -> Result<Self, encoding::Error> {
+        if private_key.algorithm != Algorithm::NistP521 {
+            Err(encoding::error::ErrorKind::Decode)?
+        }
+
+        SecretKey::from_bytes(&*private_key.private_key)
+            .map_err(|_| encoding::error::ErrorKind::Decode.into())
+    }
+}
+
+#[cfg(feature = "jwk")]
+impl FromJwk for PublicKey {
+    /// Decode a NIST P-521 public key from an `EC` JWK
+    fn from_jwk(jwk: &Jwk) -> Result<Self, encoding::Error> {
+        PublicKey::from_bytes(jwk.public_key_for(Algorithm::NistP521)?)
+            .ok_or_else(|| encoding::error::ErrorKind::Decode.into())
+    }
+}
+
+#[cfg(feature = "jwk")]
+impl ToJwk for PublicKey {
+    /// Encode a NIST P-521 public key as an `EC` JWK.
+    ///
+    /// JWKs contain both coordinates, so compressed public keys can't be
+    /// encoded and will return an error.
+    fn to_jwk(&self) -> Result<Jwk, encoding::Error> {
+        Ok(Jwk::new(Algorithm::NistP521, self.as_ref())?.with_thumbprint_kid())
+    }
+}
+
+#[cfg(all(feature = "jwk", feature = "ecdsa-software"))]
+impl FromJwk for SecretKey {
+    /// Obtain a NIST P-521 secret key from the `d` member of an `EC` JWK,
+    /// returning an error if it doesn't match the JWK's public key
+    fn from_jwk(jwk: &Jwk) -> Result<Self, encoding::Error> {
+        let secret_key = SecretKey::from_bytes(jwk.private_key_for(Algorithm::NistP521)?)
+            .map_err(|_| encoding::error::ErrorKind::Decode)?;
+
+        match secret_key.public_key() {
+            Ok(ref public_key) if public_key.as_ref() == jwk.public_key() => Ok(secret_key),
+            _ => Err(encoding::error::ErrorKind::Decode.into()),
+        }
+    }
+}
+
+#[cfg(all(feature = "jwk", feature = "ecdsa-software"))]
+impl ToJwk for SecretKey {
+    /// Serialize this secret key as an `EC` JWK containing both the secret
+    /// key (`d`) and the derived public key (`x` and `y`)
+    fn to_jwk(&self) -> Result<Jwk, encoding::Error> {
+        let public_key = self
+            .public_key()
+            .map_err(|_| encoding::error::ErrorKind::Encode)?;
+
+        Ok(Jwk::new(Algorithm::NistP521, public_key.as_ref())?
+            .with_private_key(self.as_bytes())?
+            .with_thumbprint_kid())
+    }
+}
+
+#[cfg(feature = "cose")]
+impl FromCoseKey for PublicKey {
+    /// Decode a NIST P-521 public key from an `EC2` COSE_Key
+    fn from_cose_key(bytes: &[u8]) -> Result<Self, encoding::Error> {
+        PublicKey::from_bytes(CoseKey::from_bytes(bytes)?.public_key_for(Algorithm::NistP521)?)
+            .ok_or_else(|| encoding::error::ErrorKind::Decode.into())
+    }
+}
+
+#[cfg(feature = "cose")]
+impl ToCoseKey for PublicKey {
+    /// Encode a NIST P-521 public key as an `EC2` COSE_Key.
+    ///
+    /// Compressed public keys are encoded using the sign bit of `y`.
+    fn to_cose_key(&self) -> Result<Vec<u8>, encoding::Error> {
+        Ok(CoseKey::new(Algorithm::NistP521, self.as_ref())?.to_bytes())
+    }
+}
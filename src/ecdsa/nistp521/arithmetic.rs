@@ -0,0 +1,567 @@
+//! Arithmetic on the NIST P-521 elliptic curve (the RustCrypto project
+//! doesn't have a `p521` crate yet).
+//!
+//! Field elements and scalars are stored in Montgomery form as nine little
+//! endian 64-bit limbs. Points use projective coordinates and the complete
+//! addition formula for `a = -3` curves from Renes, Costello and Batina,
+//! "Complete addition formulas for prime order elliptic curves" (2016),
+//! so the same code path handles doubling and the identity.
+//!
+//! Everything which may operate on secret values (scalar arithmetic and
+//! scalar multiplication) is constant time. Decoding and inversion exponents
+//! only depend on public values.
+
+use crate::ecdsa::elliptic_curve::subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use zeroize::Zeroize;
+
+/// Number of 64-bit limbs in a field element or scalar
+const LIMBS: usize = 9;
+
+/// Size of a serialized field element or scalar in bytes
+pub(super) const ELEMENT_SIZE: usize = 66;
+
+/// Multi-limb integer (little endian)
+type Limbs = [u64; LIMBS];
+
+/// The integer one
+const ONE: Limbs = [1, 0, 0, 0, 0, 0, 0, 0, 0];
+
+/// Prime modulus and the constants needed for Montgomery arithmetic with it
+struct Modulus {
+    /// Modulus
+    value: Limbs,
+
+    /// `R^2 mod m`, where `R = 2^576` (used to convert into Montgomery form)
+    r2: Limbs,
+
+    /// `-m^-1 mod 2^64`
+    inv: u64,
+}
+
+/// Field modulus: `p = 2^521 - 1`
+const FIELD_MODULUS: Modulus = Modulus {
+    value: [
+        0xffff_ffff_ffff_ffff,
+        0xffff_ffff_ffff_ffff,
+        0xffff_ffff_ffff_ffff,
+        0xffff_ffff_ffff_ffff,
+        0xffff_ffff_ffff_ffff,
+        0xffff_ffff_ffff_ffff,
+        0xffff_ffff_ffff_ffff,
+        0xffff_ffff_ffff_ffff,
+        0x0000_0000_0000_01ff,
+    ],
+    r2: [
+        0x0000_0000_0000_0000,
+        0x0000_4000_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0000,
+    ],
+    inv: 0x0000_0000_0000_0001,
+};
+
+/// Order of the base point: `n`
+const ORDER: Modulus = Modulus {
+    value: [
+        0xbb6f_b71e_9138_6409,
+        0x3bb5_c9b8_899c_47ae,
+        0x7fcc_0148_f709_a5d0,
+        0x5186_8783_bf2f_966b,
+        0xffff_ffff_ffff_fffa,
+        0xffff_ffff_ffff_ffff,
+        0xffff_ffff_ffff_ffff,
+        0xffff_ffff_ffff_ffff,
+        0x0000_0000_0000_01ff,
+    ],
+    r2: [
+        0x137c_d04d_cf15_dd04,
+        0xf707_badc_e554_7ea3,
+        0x12a7_8d38_7945_73ff,
+        0xd372_1ef5_57f7_5e06,
+        0xdd6e_23d8_2e49_c7db,
+        0xcff3_d142_b775_6e3e,
+        0x5bcc_6d61_a8e5_67bc,
+        0x2d8e_03d1_492d_0d45,
+        0x0000_0000_0000_003d,
+    ],
+    inv: 0x1d2f_5ccd_79a9_95c7,
+};
+
+/// Curve equation coefficient `b` (serialized as big endian bytes)
+const EQUATION_B: &[u8; ELEMENT_SIZE] = b"\x00\x51\x95\x3e\xb9\x61\x8e\x1c\x9a\x1f\x92\x9a\x21\
+                                         \xa0\xb6\x85\x40\xee\xa2\xda\x72\x5b\x99\xb3\x15\xf3\
+                                         \xb8\xb4\x89\x91\x8e\xf1\x09\xe1\x56\x19\x39\x51\xec\
+                                         \x7e\x93\x7b\x16\x52\xc0\xbd\x3b\xb1\xbf\x07\x35\x73\
+                                         \xdf\x88\x3d\x2c\x34\xf1\xef\x45\x1f\xd4\x6b\x50\x3f\
+                                         \x00";
+
+/// `x`-coordinate of the base point (serialized as big endian bytes)
+const GENERATOR_X: &[u8; ELEMENT_SIZE] = b"\x00\xc6\x85\x8e\x06\xb7\x04\x04\xe9\xcd\x9e\x3e\xcb\
+                                          \x66\x23\x95\xb4\x42\x9c\x64\x81\x39\x05\x3f\xb5\x21\
+                                          \xf8\x28\xaf\x60\x6b\x4d\x3d\xba\xa1\x4b\x5e\x77\xef\
+                                          \xe7\x59\x28\xfe\x1d\xc1\x27\xa2\xff\xa8\xde\x33\x48\
+                                          \xb3\xc1\x85\x6a\x42\x9b\xf9\x7e\x7e\x31\xc2\xe5\xbd\
+                                          \x66";
+
+/// `y`-coordinate of the base point (serialized as big endian bytes)
+const GENERATOR_Y: &[u8; ELEMENT_SIZE] = b"\x01\x18\x39\x29\x6a\x78\x9a\x3b\xc0\x04\x5c\x8a\x5f\
+                                          \xb4\x2c\x7d\x1b\xd9\x98\xf5\x44\x49\x57\x9b\x44\x68\
+                                          \x17\xaf\xbd\x17\x27\x3e\x66\x2c\x97\xee\x72\x99\x5e\
+                                          \xf4\x26\x40\xc5\x50\xb9\x01\x3f\xad\x07\x61\x35\x3c\
+                                          \x70\x86\xa2\x72\xc2\x40\x88\xbe\x94\x76\x9f\xd1\x66\
+                                          \x50";
+
+/// Element of the P-521 base field (in Montgomery form)
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub(super) struct FieldElement(Limbs);
+
+impl FieldElement {
+    /// The additive identity
+    fn zero() -> Self {
+        FieldElement([0; LIMBS])
+    }
+
+    /// The multiplicative identity
+    fn one() -> Self {
+        FieldElement(to_montgomery(&ONE, &FIELD_MODULUS))
+    }
+
+    /// Decode a field element from big endian bytes, returning `None` if it
+    /// isn't less than the modulus
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        decode(bytes, &FIELD_MODULUS).map(FieldElement)
+    }
+
+    /// Serialize this field element as big endian bytes
+    pub fn to_bytes(self) -> [u8; ELEMENT_SIZE] {
+        encode(&from_montgomery(&self.0, &FIELD_MODULUS))
+    }
+
+    /// Is the (canonical) value of this field element odd?
+    pub fn is_odd(self) -> bool {
+        from_montgomery(&self.0, &FIELD_MODULUS)[0] & 1 == 1
+    }
+
+    /// Compute `x^3 - 3x + b`, i.e. the value of `y^2` for a point on the
+    /// curve with this `x`-coordinate
+    pub fn curve_equation_rhs(self) -> Self {
+        let b = Self::from_bytes(EQUATION_B).expect("invalid curve equation coefficient");
+        let three_x = self.add(self).add(self);
+        self.mul(self).mul(self).sub(three_x).add(b)
+    }
+
+    /// Compute a square root of this field element, returning `None` if it
+    /// isn't a square
+    pub fn sqrt(self) -> Option<Self> {
+        // As `p = 3 (mod 4)`, `a^((p + 1) / 4) = a^(2^519)` is a square root
+        // of `a` whenever one exists
+        let mut root = self;
+
+        for _ in 0..519 {
+            root = root.mul(root);
+        }
+
+        if root.mul(root) == self {
+            Some(root)
+        } else {
+            None
+        }
+    }
+
+    /// Compute `p - self`
+    pub fn neg(self) -> Self {
+        Self::zero().sub(self)
+    }
+
+    /// Add two field elements
+    pub fn add(self, other: Self) -> Self {
+        FieldElement(add_mod(&self.0, &other.0, &FIELD_MODULUS))
+    }
+
+    /// Subtract two field elements
+    pub fn sub(self, other: Self) -> Self {
+        FieldElement(sub_mod(&self.0, &other.0, &FIELD_MODULUS))
+    }
+
+    /// Multiply two field elements
+    pub fn mul(self, other: Self) -> Self {
+        FieldElement(montgomery_mul(&self.0, &other.0, &FIELD_MODULUS))
+    }
+
+    /// Compute the multiplicative inverse of this field element (zero if
+    /// it's zero)
+    fn invert(self) -> Self {
+        FieldElement(invert(&self.0, &FIELD_MODULUS))
+    }
+}
+
+impl ConditionallySelectable for FieldElement {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        FieldElement(select(&a.0, &b.0, choice))
+    }
+}
+
+/// Element of the scalar field, i.e. integers modulo the order of the base
+/// point (in Montgomery form)
+#[derive(Copy, Clone, Debug, Default)]
+pub(super) struct Scalar(Limbs);
+
+impl Scalar {
+    /// Decode a scalar from big endian bytes, returning `None` if it isn't
+    /// less than the curve order
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        decode(bytes, &ORDER).map(Scalar)
+    }
+
+    /// Reduce the `x`-coordinate of a point modulo the curve order
+    pub fn from_field_element(element: FieldElement) -> Self {
+        // `x < p < 2n`, so at most one subtraction is needed
+        let x = from_montgomery(&element.0, &FIELD_MODULUS);
+        let (reduced, borrow) = sub_limbs(&x, &ORDER.value);
+        let x = select(&reduced, &x, Choice::from(borrow as u8));
+        Scalar(to_montgomery(&x, &ORDER))
+    }
+
+    /// Serialize this scalar as big endian bytes
+    pub fn to_bytes(self) -> [u8; ELEMENT_SIZE] {
+        encode(&from_montgomery(&self.0, &ORDER))
+    }
+
+    /// Is this scalar zero?
+    pub fn is_zero(&self) -> Choice {
+        self.0.ct_eq(&[0; LIMBS])
+    }
+
+    /// Add two scalars
+    pub fn add(&self, other: &Self) -> Self {
+        Scalar(add_mod(&self.0, &other.0, &ORDER))
+    }
+
+    /// Multiply two scalars
+    pub fn mul(&self, other: &Self) -> Self {
+        Scalar(montgomery_mul(&self.0, &other.0, &ORDER))
+    }
+
+    /// Compute the multiplicative inverse of this scalar (zero if it's zero)
+    pub fn invert(&self) -> Self {
+        Scalar(invert(&self.0, &ORDER))
+    }
+}
+
+impl ConstantTimeEq for Scalar {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl Zeroize for Scalar {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// Point on the P-521 curve in projective coordinates
+#[derive(Copy, Clone, Debug)]
+pub(super) struct ProjectivePoint {
+    x: FieldElement,
+    y: FieldElement,
+    z: FieldElement,
+}
+
+impl ProjectivePoint {
+    /// The point at infinity
+    pub fn identity() -> Self {
+        Self {
+            x: FieldElement::zero(),
+            y: FieldElement::one(),
+            z: FieldElement::zero(),
+        }
+    }
+
+    /// The base point
+    pub fn generator() -> Self {
+        Self::from_affine(
+            FieldElement::from_bytes(GENERATOR_X).unwrap(),
+            FieldElement::from_bytes(GENERATOR_Y).unwrap(),
+        )
+        .expect("invalid base point")
+    }
+
+    /// Create a point from affine coordinates, returning `None` if it isn't
+    /// on the curve
+    pub fn from_affine(x: FieldElement, y: FieldElement) -> Option<Self> {
+        if y.mul(y) == x.curve_equation_rhs() {
+            Some(Self {
+                x,
+                y,
+                z: FieldElement::one(),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Get the affine coordinates of this point, returning `None` if it's
+    /// the point at infinity
+    pub fn to_affine(self) -> Option<(FieldElement, FieldElement)> {
+        if self.z == FieldElement::zero() {
+            return None;
+        }
+
+        let z_inv = self.z.invert();
+        Some((self.x.mul(z_inv), self.y.mul(z_inv)))
+    }
+
+    /// Add two points (Renes-Costello-Batina Algorithm 4)
+    pub fn add(&self, other: &Self) -> Self {
+        let b = FieldElement::from_bytes(EQUATION_B).expect("invalid curve equation coefficient");
+        let (x1, y1, z1) = (self.x, self.y, self.z);
+        let (x2, y2, z2) = (other.x, other.y, other.z);
+
+        let mut t0 = x1.mul(x2);
+        let mut t1 = y1.mul(y2);
+        let mut t2 = z1.mul(z2);
+        let mut t3 = x1.add(y1);
+        let mut t4 = x2.add(y2);
+        t3 = t3.mul(t4);
+        t4 = t0.add(t1);
+        t3 = t3.sub(t4);
+        t4 = y1.add(z1);
+        let mut x3 = y2.add(z2);
+        t4 = t4.mul(x3);
+        x3 = t1.add(t2);
+        t4 = t4.sub(x3);
+        x3 = x1.add(z1);
+        let mut y3 = x2.add(z2);
+        x3 = x3.mul(y3);
+        y3 = t0.add(t2);
+        y3 = x3.sub(y3);
+        let mut z3 = b.mul(t2);
+        x3 = y3.sub(z3);
+        z3 = x3.add(x3);
+        x3 = x3.add(z3);
+        z3 = t1.sub(x3);
+        x3 = t1.add(x3);
+        y3 = b.mul(y3);
+        t1 = t2.add(t2);
+        t2 = t1.add(t2);
+        y3 = y3.sub(t2);
+        y3 = y3.sub(t0);
+        t1 = y3.add(y3);
+        y3 = t1.add(y3);
+        t1 = t0.add(t0);
+        t0 = t1.add(t0);
+        t0 = t0.sub(t2);
+        t1 = t4.mul(y3);
+        t2 = t0.mul(y3);
+        y3 = x3.mul(z3);
+        y3 = y3.add(t2);
+        x3 = t3.mul(x3);
+        x3 = x3.sub(t1);
+        z3 = t4.mul(z3);
+        t1 = t3.mul(t0);
+        z3 = z3.add(t1);
+
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// Multiply this point by the given scalar (in constant time)
+    pub fn mul(&self, scalar: &Scalar) -> Self {
+        let mut k = from_montgomery(&scalar.0, &ORDER);
+        let mut result = Self::identity();
+
+        for i in (0..521).rev() {
+            result = result.add(&result);
+            let sum = result.add(self);
+            let bit = Choice::from(((k[i / 64] >> (i % 64)) & 1) as u8);
+            result = Self::conditional_select(&result, &sum, bit);
+        }
+
+        k.zeroize();
+        result
+    }
+}
+
+impl ConditionallySelectable for ProjectivePoint {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            x: FieldElement::conditional_select(&a.x, &b.x, choice),
+            y: FieldElement::conditional_select(&a.y, &b.y, choice),
+            z: FieldElement::conditional_select(&a.z, &b.z, choice),
+        }
+    }
+}
+
+/// Decode a big endian integer and convert it into Montgomery form,
+/// returning `None` if it isn't less than the modulus
+fn decode(bytes: &[u8], modulus: &Modulus) -> Option<Limbs> {
+    if bytes.len() != ELEMENT_SIZE {
+        return None;
+    }
+
+    let mut limbs = [0u64; LIMBS];
+
+    for (i, &byte) in bytes.iter().rev().enumerate() {
+        limbs[i / 8] |= u64::from(byte) << (8 * (i % 8));
+    }
+
+    let (_, borrow) = sub_limbs(&limbs, &modulus.value);
+    let result = if borrow == 1 {
+        Some(to_montgomery(&limbs, modulus))
+    } else {
+        None
+    };
+
+    limbs.zeroize();
+    result
+}
+
+/// Serialize an integer less than `2^528` as big endian bytes
+fn encode(limbs: &Limbs) -> [u8; ELEMENT_SIZE] {
+    let mut bytes = [0u8; ELEMENT_SIZE];
+
+    for (i, byte) in bytes.iter_mut().rev().enumerate() {
+        *byte = (limbs[i / 8] >> (8 * (i % 8))) as u8;
+    }
+
+    bytes
+}
+
+/// Select `b` if `choice` is set, otherwise `a` (in constant time)
+fn select(a: &Limbs, b: &Limbs, choice: Choice) -> Limbs {
+    let mut result = [0u64; LIMBS];
+
+    for i in 0..LIMBS {
+        result[i] = u64::conditional_select(&a[i], &b[i], choice);
+    }
+
+    result
+}
+
+/// Add two multi-limb integers, returning the sum and the carry
+fn add_limbs(a: &Limbs, b: &Limbs) -> (Limbs, u64) {
+    let mut sum = [0u64; LIMBS];
+    let mut carry = 0u128;
+
+    for i in 0..LIMBS {
+        let tmp = u128::from(a[i]) + u128::from(b[i]) + carry;
+        sum[i] = tmp as u64;
+        carry = tmp >> 64;
+    }
+
+    (sum, carry as u64)
+}
+
+/// Subtract two multi-limb integers, returning the difference and the
+/// borrow
+fn sub_limbs(a: &Limbs, b: &Limbs) -> (Limbs, u64) {
+    let mut difference = [0u64; LIMBS];
+    let mut borrow = 0u64;
+
+    for i in 0..LIMBS {
+        let tmp = u128::from(a[i])
+            .wrapping_sub(u128::from(b[i]))
+            .wrapping_sub(u128::from(borrow));
+        difference[i] = tmp as u64;
+        borrow = (tmp >> 127) as u64;
+    }
+
+    (difference, borrow)
+}
+
+/// Compute `a + b mod m`
+fn add_mod(a: &Limbs, b: &Limbs, modulus: &Modulus) -> Limbs {
+    // Both moduli are less than `2^575`, so the sum can't overflow
+    let (sum, _) = add_limbs(a, b);
+    let (reduced, borrow) = sub_limbs(&sum, &modulus.value);
+    select(&reduced, &sum, Choice::from(borrow as u8))
+}
+
+/// Compute `a - b mod m`
+fn sub_mod(a: &Limbs, b: &Limbs, modulus: &Modulus) -> Limbs {
+    let (difference, borrow) = sub_limbs(a, b);
+    let (wrapped, _) = add_limbs(&difference, &modulus.value);
+    select(&difference, &wrapped, Choice::from(borrow as u8))
+}
+
+/// Montgomery multiplication: compute `a * b * R^-1 mod m` (CIOS method)
+fn montgomery_mul(a: &Limbs, b: &Limbs, modulus: &Modulus) -> Limbs {
+    let m = &modulus.value;
+    let mut t = [0u64; LIMBS + 2];
+
+    for &b_i in b.iter() {
+        let mut carry = 0u128;
+
+        for j in 0..LIMBS {
+            let tmp = u128::from(t[j]) + u128::from(a[j]) * u128::from(b_i) + carry;
+            t[j] = tmp as u64;
+            carry = tmp >> 64;
+        }
+
+        let tmp = u128::from(t[LIMBS]) + carry;
+        t[LIMBS] = tmp as u64;
+        t[LIMBS + 1] = (tmp >> 64) as u64;
+
+        let q = t[0].wrapping_mul(modulus.inv);
+        let mut carry = (u128::from(t[0]) + u128::from(q) * u128::from(m[0])) >> 64;
+
+        for j in 1..LIMBS {
+            let tmp = u128::from(t[j]) + u128::from(q) * u128::from(m[j]) + carry;
+            t[j - 1] = tmp as u64;
+            carry = tmp >> 64;
+        }
+
+        let tmp = u128::from(t[LIMBS]) + carry;
+        t[LIMBS - 1] = tmp as u64;
+        t[LIMBS] = t[LIMBS + 1] + (tmp >> 64) as u64;
+    }
+
+    let mut result = [0u64; LIMBS];
+    result.copy_from_slice(&t[..LIMBS]);
+
+    // The result is less than `2m`, so at most one subtraction is needed
+    let (reduced, borrow) = sub_limbs(&result, m);
+    let use_reduced = Choice::from(((t[LIMBS] | (borrow ^ 1)) & 1) as u8);
+    let result = select(&result, &reduced, use_reduced);
+
+    t.zeroize();
+    result
+}
+
+/// Convert an integer less than `m` into Montgomery form
+fn to_montgomery(a: &Limbs, modulus: &Modulus) -> Limbs {
+    montgomery_mul(a, &modulus.r2, modulus)
+}
+
+/// Convert an integer out of Montgomery form
+fn from_montgomery(a: &Limbs, modulus: &Modulus) -> Limbs {
+    montgomery_mul(a, &ONE, modulus)
+}
+
+/// Compute `a^-1 mod m` as `a^(m - 2)` (Fermat's little theorem). The
+/// exponent is public, so branching on its bits is fine.
+fn invert(a: &Limbs, modulus: &Modulus) -> Limbs {
+    let (exponent, _) = sub_limbs(&modulus.value, &[2, 0, 0, 0, 0, 0, 0, 0, 0]);
+    let mut result = to_montgomery(&ONE, modulus);
+
+    for limb in exponent.iter().rev() {
+        for i in (0..64).rev() {
+            result = montgomery_mul(&result, &result, modulus);
+
+            if (limb >> i) & 1 == 1 {
+                result = montgomery_mul(&result, a, modulus);
+            }
+        }
+    }
+
+    result
+}
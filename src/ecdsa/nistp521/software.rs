@@ -0,0 +1,246 @@
+//! Pure-Rust software implementation of ECDSA/P-521
+//!
+//! Signatures are deterministic: the ephemeral scalar `k` is derived from the
+//! secret key and message digest as described in RFC 6979, so no random
+//! number generator is needed.
+
+use super::{
+    arithmetic::{FieldElement, ProjectivePoint, Scalar, ELEMENT_SIZE},
+    Asn1Signature, FixedSignature, NistP521, PublicKey, SecretKey,
+};
+#[cfg(feature = "alloc")]
+use crate::batch::{self, BatchError, BatchItem, BatchVerifier};
+#[cfg(feature = "pkcs8")]
+use crate::encoding::{self, pkcs8::PrivateKeyInfo, Algorithm, FromPkcs8};
+use crate::{
+    ecdsa::{generic_array::GenericArray, rfc6979, PointConversion, PointEncoding},
+    public_key::PublicKeyed,
+};
+use sha2::{Digest, Sha512};
+use signature::{DigestSigner, DigestVerifier, Error, Signature};
+use zeroize::Zeroize;
+
+/// Number of excess low-order bits in a serialized scalar (528 bits) over
+/// the bit length of the curve order (521 bits)
+const EXCESS_BITS: u32 = 7;
+
+/// NIST P-521 ECDSA signer
+pub struct Signer {
+    /// Secret scalar
+    secret_scalar: Scalar,
+
+    /// Public key (as an uncompressed point)
+    public_key: PublicKey,
+}
+
+impl Signer {
+    /// Create a new signer from the given secret key, returning an error if
+    /// it's zero or not less than the curve order
+    pub fn new(secret_key: &SecretKey) -> Result<Self, Error> {
+        let secret_scalar = Scalar::from_bytes(secret_key.as_bytes())
+            .filter(|scalar| !bool::from(scalar.is_zero()))
+            .ok_or_else(Error::new)?;
+
+        Ok(Self {
+            secret_scalar,
+            public_key: secret_key.public_key()?,
+        })
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+impl FromPkcs8 for Signer {
+    /// Create a new NIST P-521 signer from a PKCS#8 private key, checking it
+    /// against the public key if the document contains one
+    fn from_pkcs8<K: AsRef<[u8]>>(secret_key: K) -> Result<Self, encoding::Error> {
+        let private_key_info = PrivateKeyInfo::from_der(secret_key.as_ref())?;
+
+        if private_key_info.algorithm != Algorithm::NistP521 {
+            return Err(encoding::error::ErrorKind::Decode.into());
+        }
+
+        let signer = SecretKey::from_bytes(private_key_info.private_key)
+            .ok()
+            .and_then(|secret_key| Signer::new(&secret_key).ok())
+            .ok_or(encoding::error::ErrorKind::Decode)?;
+
+        if let Some(public_key) = private_key_info.public_key {
+            let public_key = PublicKey::from_bytes(public_key)
+                .and_then(|public_key| public_key.to_uncompressed().ok());
+
+            if public_key != Some(signer.public_key) {
+                return Err(encoding::error::ErrorKind::Decode.into());
+            }
+        }
+
+        Ok(signer)
+    }
+}
+
+impl PublicKeyed<PublicKey> for Signer {
+    fn public_key(&self) -> Result<PublicKey, Error> {
+        Ok(self.public_key)
+    }
+}
+
+impl DigestSigner<Sha512, FixedSignature> for Signer {
+    fn try_sign_digest(&self, digest: Sha512) -> Result<FixedSignature, Error> {
+        let e = hash_to_scalar(&digest.finalize());
+        let mut x = GenericArray::clone_from_slice(&self.secret_scalar.to_bytes());
+        let h1 = GenericArray::clone_from_slice(&e.to_bytes());
+
+        let mut k = rfc6979::generate_k::<NistP521, Sha512, _, _>(&x, &h1, |bytes| {
+            let mut candidate = [0u8; ELEMENT_SIZE];
+
+            // bits2int: drop the excess low-order bits
+            for i in 0..ELEMENT_SIZE {
+                let carry = if i > 0 {
+                    bytes[i - 1] << (8 - EXCESS_BITS)
+                } else {
+                    0
+                };
+                candidate[i] = (bytes[i] >> EXCESS_BITS) | carry;
+            }
+
+            let k = Scalar::from_bytes(&candidate).filter(|k| !bool::from(k.is_zero()));
+            candidate.zeroize();
+            k
+        });
+
+        x.as_mut_slice().zeroize();
+
+        // r = x-coordinate of kG (mod n)
+        let r = ProjectivePoint::generator()
+            .mul(&k)
+            .to_affine()
+            .map(|(x, _)| Scalar::from_field_element(x))
+            .filter(|r| !bool::from(r.is_zero()));
+
+        // s = k^-1 * (e + r * x) (mod n)
+        let result = r
+            .map(|r| {
+                let s = k.invert().mul(&e.add(&r.mul(&self.secret_scalar)));
+
+                (r, s)
+            })
+            .filter(|(_, s)| !bool::from(s.is_zero()))
+            .map(|(r, s)| {
+                FixedSignature::from_scalars(
+                    GenericArray::from_slice(&r.to_bytes()),
+                    GenericArray::from_slice(&s.to_bytes()),
+                )
+            })
+            .ok_or_else(Error::new);
+
+        k.zeroize();
+        result
+    }
+}
+
+impl DigestSigner<Sha512, Asn1Signature> for Signer {
+    fn try_sign_digest(&self, digest: Sha512) -> Result<Asn1Signature, Error> {
+        DigestSigner::<Sha512, FixedSignature>::try_sign_digest(self, digest)
+            .map(|signature| signature.to_asn1())
+    }
+}
+
+impl signature::Signer<FixedSignature> for Signer {
+    fn try_sign(&self, msg: &[u8]) -> Result<FixedSignature, Error> {
+        self.try_sign_digest(Sha512::new().chain(msg))
+    }
+}
+
+impl signature::Signer<Asn1Signature> for Signer {
+    fn try_sign(&self, msg: &[u8]) -> Result<Asn1Signature, Error> {
+        self.try_sign_digest(Sha512::new().chain(msg))
+    }
+}
+
+impl Drop for Signer {
+    fn drop(&mut self) {
+        self.secret_scalar.zeroize();
+    }
+}
+
+/// NIST P-521 ECDSA verifier
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Verifier(PublicKey);
+
+impl<'a> From<&'a PublicKey> for Verifier {
+    fn from(public_key: &'a PublicKey) -> Self {
+        Verifier(*public_key)
+    }
+}
+
+impl DigestVerifier<Sha512, FixedSignature> for Verifier {
+    fn verify_digest(&self, digest: Sha512, signature: &FixedSignature) -> Result<(), Error> {
+        let public_key = self.0.to_encoding(PointEncoding::Uncompressed)?;
+        let (x, y) = public_key.as_bytes()[1..].split_at(ELEMENT_SIZE);
+        let public_point = ProjectivePoint::from_affine(
+            FieldElement::from_bytes(x).ok_or_else(Error::new)?,
+            FieldElement::from_bytes(y).ok_or_else(Error::new)?,
+        )
+        .ok_or_else(Error::new)?;
+
+        let (r, s) = signature.as_bytes().split_at(ELEMENT_SIZE);
+        let r = Scalar::from_bytes(r)
+            .filter(|r| !bool::from(r.is_zero()))
+            .ok_or_else(Error::new)?;
+        let s = Scalar::from_bytes(s)
+            .filter(|s| !bool::from(s.is_zero()))
+            .ok_or_else(Error::new)?;
+
+        // R = (e * s^-1)G + (r * s^-1)Q
+        let e = hash_to_scalar(&digest.finalize());
+        let s_inv = s.invert();
+        let point = ProjectivePoint::generator()
+            .mul(&e.mul(&s_inv))
+            .add(&public_point.mul(&r.mul(&s_inv)));
+
+        match point.to_affine() {
+            Some((x, _)) if Scalar::from_field_element(x).to_bytes() == r.to_bytes() => Ok(()),
+            _ => Err(Error::new()),
+        }
+    }
+}
+
+impl DigestVerifier<Sha512, Asn1Signature> for Verifier {
+    fn verify_digest(&self, digest: Sha512, signature: &Asn1Signature) -> Result<(), Error> {
+        self.verify_digest(digest, &FixedSignature::from_asn1(signature.as_ref())?)
+    }
+}
+
+impl signature::Verifier<FixedSignature> for Verifier {
+    fn verify(&self, msg: &[u8], signature: &FixedSignature) -> Result<(), Error> {
+        self.verify_digest(Sha512::new().chain(msg), signature)
+    }
+}
+
+impl signature::Verifier<Asn1Signature> for Verifier {
+    fn verify(&self, msg: &[u8], signature: &Asn1Signature) -> Result<(), Error> {
+        self.verify_digest(Sha512::new().chain(msg), signature)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl BatchVerifier<PublicKey, Asn1Signature> for Verifier {
+    fn verify_batch(items: &[BatchItem<'_, PublicKey, Asn1Signature>]) -> Result<(), BatchError> {
+        batch::verify_sequentially::<_, _, Self>(items)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl BatchVerifier<PublicKey, FixedSignature> for Verifier {
+    fn verify_batch(items: &[BatchItem<'_, PublicKey, FixedSignature>]) -> Result<(), BatchError> {
+        batch::verify_sequentially::<_, _, Self>(items)
+    }
+}
+
+/// Convert a SHA-512 digest to a scalar. The digest is shorter than the
+/// curve order, so `bits2int` doesn't truncate it and it's always less
+/// than the curve order.
+fn hash_to_scalar(digest: &[u8]) -> Scalar {
+    let mut bytes = [0u8; ELEMENT_SIZE];
+    bytes[(ELEMENT_SIZE - digest.len())..].copy_from_slice(digest);
+    Scalar::from_bytes(&bytes).expect("SHA-512 digest exceeds curve order")
+}
@@ -0,0 +1,38 @@
+//! ECDSA test vectors for the NIST P-521 elliptic curve
+//!
+//! Deterministic (RFC 6979) signatures with SHA-512, from RFC 6979
+//! Appendix A.2.7:
+//!
+//! <https://tools.ietf.org/html/rfc6979#appendix-A.2.7>
+
+use crate::ecdsa::TestVector;
+
+/// ECDSA P-521 test vectors for deterministic signatures (RFC 6979), with
+/// SHA-512 as the message digest and HMAC-DRBG hash function
+pub const SHA512_RFC6979_TEST_VECTORS: &[TestVector] = &[
+    // RFC 6979 Appendix A.2.7, with SHA-512 and message "sample"
+    TestVector {
+        sk: b"\x00\xfa\xd0\x6d\xaa\x62\xba\x3b\x25\xd2\xfb\x40\x13\x3d\xa7\x57\x20\x5d\xe6\x7f\x5b\xb0\x01\x8f\xee\x8c\x86\xe1\xb6\x8c\x7e\x75\xca\xa8\x96\xeb\x32\xf1\xf4\x7c\x70\x85\x58\x36\xa6\xd1\x6f\xcc\x14\x66\xf6\xd8\xfb\xec\x67\xdb\x89\xec\x0c\x08\xb0\xe9\x96\xb8\x35\x38",
+        pk: b"\x01\x89\x45\x50\xd0\x78\x59\x32\xe0\x0e\xaa\x23\xb6\x94\xf2\x13\xf8\xc3\x12\x1f\x86\xdc\x97\xa0\x4e\x5a\x71\x67\xdb\x4e\x5b\xcd\x37\x11\x23\xd4\x6e\x45\xdb\x6b\x5d\x53\x70\xa7\xf2\x0f\xb6\x33\x15\x5d\x38\xff\xa1\x6d\x2b\xd7\x61\xdc\xac\x47\x4b\x9a\x2f\x50\x23\xa4\x00\x49\x31\x01\xc9\x62\xcd\x4d\x2f\xdd\xf7\x82\x28\x5e\x64\x58\x41\x39\xc2\xf9\x1b\x47\xf8\x7f\xf8\x23\x54\xd6\x63\x0f\x74\x6a\x28\xa0\xdb\x25\x74\x1b\x5b\x34\xa8\x28\x00\x8b\x22\xac\xc2\x3f\x92\x4f\xaa\xfb\xd4\xd3\x3f\x81\xea\x66\x95\x6d\xfe\xaa\x2b\xfd\xfc\xf5",
+        nonce: Some(b"\x01\xda\xe2\xea\x07\x1f\x81\x10\xdc\x26\x88\x2d\x4d\x5e\xae\x06\x21\xa3\x25\x6f\xc8\x84\x7f\xb9\x02\x2e\x2b\x7d\x28\xe6\xf1\x01\x98\xb1\x57\x4f\xdd\x03\xa9\x05\x3c\x08\xa1\x85\x4a\x16\x8a\xa5\xa5\x74\x70\xec\x97\xdd\x5c\xe0\x90\x12\x4e\xf5\x2a\x2f\x7e\xcb\xff\xd3"),
+        msg: b"\x73\x61\x6d\x70\x6c\x65",
+        sig: b"\x00\xc3\x28\xfa\xfc\xbd\x79\xdd\x77\x85\x03\x70\xc4\x63\x25\xd9\x87\xcb\x52\x55\x69\xfb\x63\xc5\xd3\xbc\x53\x95\x0e\x6d\x4c\x5f\x17\x4e\x25\xa1\xee\x90\x17\xb5\xd4\x50\x60\x6a\xdd\x15\x2b\x53\x49\x31\xd7\xd4\xe8\x45\x5c\xc9\x1f\x9b\x15\xbf\x05\xec\x36\xe3\x77\xfa\x00\x61\x7c\xce\x7c\xf5\x06\x48\x06\xc4\x67\xf6\x78\xd3\xb4\x08\x0d\x6f\x1c\xc5\x0a\xf2\x6c\xa2\x09\x41\x73\x08\x28\x1b\x68\xaf\x28\x26\x23\xea\xa6\x3e\x5b\x5c\x07\x23\xd8\xb8\xc3\x7f\xf0\x77\x7b\x1a\x20\xf8\xcc\xb1\xdc\xcc\x43\x99\x7f\x1e\xe0\xe4\x4d\xa4\xa6\x7a",
+    },
+    // RFC 6979 Appendix A.2.7, with SHA-512 and message "test"
+    TestVector {
+        sk: b"\x00\xfa\xd0\x6d\xaa\x62\xba\x3b\x25\xd2\xfb\x40\x13\x3d\xa7\x57\x20\x5d\xe6\x7f\x5b\xb0\x01\x8f\xee\x8c\x86\xe1\xb6\x8c\x7e\x75\xca\xa8\x96\xeb\x32\xf1\xf4\x7c\x70\x85\x58\x36\xa6\xd1\x6f\xcc\x14\x66\xf6\xd8\xfb\xec\x67\xdb\x89\xec\x0c\x08\xb0\xe9\x96\xb8\x35\x38",
+        pk: b"\x01\x89\x45\x50\xd0\x78\x59\x32\xe0\x0e\xaa\x23\xb6\x94\xf2\x13\xf8\xc3\x12\x1f\x86\xdc\x97\xa0\x4e\x5a\x71\x67\xdb\x4e\x5b\xcd\x37\x11\x23\xd4\x6e\x45\xdb\x6b\x5d\x53\x70\xa7\xf2\x0f\xb6\x33\x15\x5d\x38\xff\xa1\x6d\x2b\xd7\x61\xdc\xac\x47\x4b\x9a\x2f\x50\x23\xa4\x00\x49\x31\x01\xc9\x62\xcd\x4d\x2f\xdd\xf7\x82\x28\x5e\x64\x58\x41\x39\xc2\xf9\x1b\x47\xf8\x7f\xf8\x23\x54\xd6\x63\x0f\x74\x6a\x28\xa0\xdb\x25\x74\x1b\x5b\x34\xa8\x28\x00\x8b\x22\xac\xc2\x3f\x92\x4f\xaa\xfb\xd4\xd3\x3f\x81\xea\x66\x95\x6d\xfe\xaa\x2b\xfd\xfc\xf5",
+        nonce: Some(b"\x01\x62\x00\x81\x30\x20\xec\x98\x68\x63\xbe\xdf\xc1\xb1\x21\xf6\x05\xc1\x21\x56\x45\x01\x8a\xea\x1a\x7b\x21\x5a\x56\x4d\xe9\xeb\x1b\x38\xa6\x7a\xa1\x12\x8b\x80\xce\x39\x1c\x4f\xb7\x11\x87\x65\x4a\xaa\x34\x31\x02\x7b\xfc\x7f\x39\x57\x66\xca\x98\x8c\x96\x4d\xc5\x6d"),
+        msg: b"\x74\x65\x73\x74",
+        sig: b"\x01\x3e\x99\x02\x0a\xbf\x5c\xee\x75\x25\xd1\x6b\x69\xb2\x29\x65\x2a\xb6\xbd\xf2\xaf\xfc\xae\xf3\x87\x73\xb4\xb7\xd0\x87\x25\xf1\x0c\xdb\x93\x48\x2f\xdc\xc5\x4e\xdc\xee\x91\xec\xa4\x16\x6b\x2a\x7c\x62\x65\xef\x0c\xe2\xbd\x70\x51\xb7\xce\xf9\x45\xba\xbd\x47\xee\x6d\x01\xfb\xd0\x01\x3c\x67\x4a\xa7\x9c\xb3\x98\x49\x52\x79\x16\xce\x30\x1c\x66\xea\x7c\xe8\xb8\x06\x82\x78\x6a\xd6\x0f\x98\xf7\xe7\x8a\x19\xca\x69\xef\xf5\xc5\x74\x00\xe3\xb3\xa0\xad\x66\xce\x09\x78\x21\x4d\x13\xba\xf4\xe9\xac\x60\x75\x2f\x7b\x15\x5e\x2d\xe4\xdc\xe3",
+    },
+    // secret key n - 1, with SHA-512 and message "sample" (cross-checked
+    // against OpenSSL's deterministic ECDSA)
+    TestVector {
+        sk: b"\x01\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xfa\x51\x86\x87\x83\xbf\x2f\x96\x6b\x7f\xcc\x01\x48\xf7\x09\xa5\xd0\x3b\xb5\xc9\xb8\x89\x9c\x47\xae\xbb\x6f\xb7\x1e\x91\x38\x64\x08",
+        pk: b"\x00\xc6\x85\x8e\x06\xb7\x04\x04\xe9\xcd\x9e\x3e\xcb\x66\x23\x95\xb4\x42\x9c\x64\x81\x39\x05\x3f\xb5\x21\xf8\x28\xaf\x60\x6b\x4d\x3d\xba\xa1\x4b\x5e\x77\xef\xe7\x59\x28\xfe\x1d\xc1\x27\xa2\xff\xa8\xde\x33\x48\xb3\xc1\x85\x6a\x42\x9b\xf9\x7e\x7e\x31\xc2\xe5\xbd\x66\x00\xe7\xc6\xd6\x95\x87\x65\xc4\x3f\xfb\xa3\x75\xa0\x4b\xd3\x82\xe4\x26\x67\x0a\xbb\xb6\xa8\x64\xbb\x97\xe8\x50\x42\xe8\xd8\xc1\x99\xd3\x68\x11\x8d\x66\xa1\x0b\xd9\xbf\x3a\xaf\x46\xfe\xc0\x52\xf8\x9e\xca\xc3\x8f\x79\x5d\x8d\x3d\xbf\x77\x41\x6b\x89\x60\x2e\x99\xaf",
+        nonce: Some(b"\x01\xa5\x8b\x35\x67\xe4\x27\xf9\x00\x14\x62\x7b\xf1\xcc\x35\xdd\xf1\x5b\x0f\xee\x65\xe4\x55\x5d\xa6\x46\xa3\xf8\x78\xdc\x6a\xcd\x6c\x5f\x83\xde\xd4\x6a\x84\xd0\xe5\x96\x10\x83\x43\x5d\x8b\xc0\x1c\xad\x78\xcf\xc2\x41\x0a\x26\xd1\xe8\x1b\xff\x6e\x78\x07\xc8\x1d\xe2"),
+        msg: b"\x73\x61\x6d\x70\x6c\x65",
+        sig: b"\x00\xd2\x54\xf8\x78\x1c\xc9\x2f\xac\x1a\x35\x76\x8a\x8d\xac\x62\x30\xa9\x74\x0d\xf6\xe5\xd0\xa8\x57\x42\x9b\x2c\x54\xc9\xfd\x47\xe9\x33\x03\xd8\x84\x8d\xbf\x95\x73\xf3\x5e\x16\xfd\x05\x14\x95\x87\x5b\xef\x55\xa4\x15\x25\xa4\x51\xce\xc9\x21\x7b\x3e\xbd\x66\x81\x59\x00\xe6\x7c\x28\xb0\xa8\xf2\xde\xd3\xf6\xc8\xc3\x74\x86\x2c\x33\x34\xcb\xdb\xc6\x78\xd7\x80\x22\x8d\xee\x13\xf2\x5c\x10\xfc\x84\xff\xf1\x27\x12\xda\x8d\x3c\x11\xe0\x40\x31\x85\xcd\x5a\xcc\x99\x5e\x6e\x01\x84\x19\x3a\x18\x0f\xe2\xe8\x58\xfe\x8d\xae\x65\x46\xa0\xa2",
+    },
+];
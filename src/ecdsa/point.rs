@@ -0,0 +1,60 @@
+//! Conversions between the compressed and uncompressed SEC1 encodings of
+//! ECDSA public keys
+//!
+//! Providers disagree about which encoding they return public keys in (e.g.
+//! *ring* returns uncompressed points whereas libsecp256k1 returns compressed
+//! ones), so callers which need a particular encoding should ask for it
+//! explicitly using [`PublicKeyedWithEncoding`].
+
+use crate::public_key::{PublicKey, PublicKeyed};
+use signature::Error;
+
+/// SEC1 encodings of elliptic curve points (SEC 1 Section 2.3.3)
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum PointEncoding {
+    /// `0x02` or `0x03` tag (indicating the parity of `y`) followed by `x`
+    Compressed,
+
+    /// `0x04` tag followed by `x` and `y`
+    Uncompressed,
+}
+
+/// Public keys which can be converted between the compressed and
+/// uncompressed SEC1 encodings.
+///
+/// Every conversion checks that the point is on the curve, returning an
+/// error if it isn't.
+pub trait PointConversion: Sized {
+    /// Convert this public key to the given encoding
+    fn to_encoding(&self, encoding: PointEncoding) -> Result<Self, Error>;
+
+    /// Convert this public key to the compressed encoding
+    fn to_compressed(&self) -> Result<Self, Error> {
+        self.to_encoding(PointEncoding::Compressed)
+    }
+
+    /// Convert this public key to the uncompressed encoding
+    fn to_uncompressed(&self) -> Result<Self, Error> {
+        self.to_encoding(PointEncoding::Uncompressed)
+    }
+}
+
+/// Signers which can return their public key in a specific encoding
+/// (implemented for every [`PublicKeyed`] type)
+pub trait PublicKeyedWithEncoding<K>: PublicKeyed<K>
+where
+    K: PublicKey + PointConversion,
+{
+    /// Public key which can verify signatures created by this signer, in
+    /// the given encoding
+    fn public_key_with_encoding(&self, encoding: PointEncoding) -> Result<K, Error> {
+        self.public_key()?.to_encoding(encoding)
+    }
+}
+
+impl<T, K> PublicKeyedWithEncoding<K> for T
+where
+    T: PublicKeyed<K>,
+    K: PublicKey + PointConversion,
+{
+}
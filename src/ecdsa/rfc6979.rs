@@ -0,0 +1,127 @@
+//! Deterministic ECDSA nonce generation as described in RFC 6979 Section 3.2
+//!
+//! Deriving `k` from the secret key and message digest with HMAC-DRBG means
+//! signing doesn't need a random number generator, which makes the built-in
+//! ECDSA signers usable on `no_std` targets without an entropy source.
+//!
+//! <https://tools.ietf.org/html/rfc6979#section-3.2>
+
+use super::{
+    elliptic_curve::ElementBytes,
+    generic_array::{sequence::GenericSequence, ArrayLength, GenericArray},
+    Curve,
+};
+use hmac::{Hmac, Mac, NewMac};
+use sha2::digest::{BlockInput, FixedOutput, Reset, Update};
+use zeroize::Zeroize;
+
+/// Generate the ephemeral scalar `k` for the given serialized secret scalar
+/// `x` and the message digest `h1` (already reduced modulo the curve order).
+///
+/// Each candidate is passed to `to_scalar`, which must return `None` if it's
+/// zero or not less than the curve order.
+///
+/// Candidates are the size of a serialized scalar. When the bit length of the
+/// curve order isn't a multiple of 8 (i.e. NIST P-521), `to_scalar` is
+/// responsible for `bits2int`, which shifts off the excess low-order bits.
+pub(crate) fn generate_k<C, D, K, F>(x: &ElementBytes<C>, h1: &ElementBytes<C>, to_scalar: F) -> K
+where
+    C: Curve,
+    D: Update + BlockInput + FixedOutput + Reset + Default + Clone,
+    D::BlockSize: ArrayLength<u8>,
+    D::OutputSize: ArrayLength<u8>,
+    F: Fn(&ElementBytes<C>) -> Option<K>,
+{
+    let mut hmac_drbg = HmacDrbg::<D>::new(x, h1);
+    let mut t = ElementBytes::<C>::default();
+
+    loop {
+        hmac_drbg.fill_bytes(&mut t);
+
+        if let Some(k) = to_scalar(&t) {
+            t.as_mut_slice().zeroize();
+            return k;
+        }
+
+        hmac_drbg.reseed();
+    }
+}
+
+/// HMAC-DRBG instantiated as described in RFC 6979 Section 3.2 (steps b-f)
+struct HmacDrbg<D>
+where
+    D: Update + BlockInput + FixedOutput + Reset + Default + Clone,
+    D::BlockSize: ArrayLength<u8>,
+    D::OutputSize: ArrayLength<u8>,
+{
+    /// HMAC keyed with the current value of `K`
+    k: Hmac<D>,
+
+    /// Current value of `V`
+    v: GenericArray<u8, D::OutputSize>,
+}
+
+impl<D> HmacDrbg<D>
+where
+    D: Update + BlockInput + FixedOutput + Reset + Default + Clone,
+    D::BlockSize: ArrayLength<u8>,
+    D::OutputSize: ArrayLength<u8>,
+{
+    /// Initialize the DRBG from the secret scalar and message digest
+    fn new(x: &[u8], h1: &[u8]) -> Self {
+        let mut drbg = Self {
+            k: Hmac::new_varkey(&GenericArray::<u8, D::OutputSize>::default())
+                .expect("HMAC accepts keys of any size"),
+            v: GenericArray::generate(|_| 0x01),
+        };
+
+        for &separator in &[0x00, 0x01] {
+            drbg.k.update(&drbg.v);
+            drbg.k.update(&[separator]);
+            drbg.k.update(x);
+            drbg.k.update(h1);
+            drbg.rekey();
+        }
+
+        drbg
+    }
+
+    /// Fill `out` with the leftmost bytes of `V || HMAC_K(V) || ...`
+    /// (step h.2)
+    fn fill_bytes(&mut self, out: &mut [u8]) {
+        for chunk in out.chunks_mut(self.v.len()) {
+            self.k.update(&self.v);
+            self.v = self.k.finalize_reset().into_bytes();
+            chunk.copy_from_slice(&self.v[..chunk.len()]);
+        }
+    }
+
+    /// Update `K` and `V` after a rejected candidate (step h.3)
+    fn reseed(&mut self) {
+        self.k.update(&self.v);
+        self.k.update(&[0x00]);
+        self.rekey();
+    }
+
+    /// Replace `K` with the output of the HMAC computation in progress, then
+    /// compute `V = HMAC_K(V)`
+    fn rekey(&mut self) {
+        let mut k = self.k.finalize_reset().into_bytes();
+        self.k = Hmac::new_varkey(&k).expect("HMAC accepts keys of any size");
+        k.as_mut_slice().zeroize();
+
+        self.k.update(&self.v);
+        self.v = self.k.finalize_reset().into_bytes();
+    }
+}
+
+impl<D> Drop for HmacDrbg<D>
+where
+    D: Update + BlockInput + FixedOutput + Reset + Default + Clone,
+    D::BlockSize: ArrayLength<u8>,
+    D::OutputSize: ArrayLength<u8>,
+{
+    fn drop(&mut self) {
+        self.v.as_mut_slice().zeroize();
+    }
+}
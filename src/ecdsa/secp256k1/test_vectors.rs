@@ -16,3 +16,123 @@ pub const SHA256_FIXED_SIZE_TEST_VECTORS: &[TestVector] = &[
         sig: b"\xdc\xd0\x37\x67\x2e\xde\x6b\x10\xf6\xab\xf6\xb6\xbb\x01\x85\x37\xbb\xbe\xa4\x86\x05\x83\x84\x76\xad\x75\x81\xb3\x82\x2d\xdc\xc8\x41\xfe\x40\x30\xd8\x58\xf2\x1a\xf5\xd5\xc1\x0f\xe4\x82\xe5\x66\xfb\xbb\x34\x60\x42\xa1\x8a\x70\xa0\xc2\xbb\x62\x77\xba\xc5\x74",
     },
 ];
+
+/// ECDSA secp256k1 test vectors for deterministic signatures (RFC 6979), with
+/// SHA-256 as the message digest and HMAC-DRBG hash function, and `s`
+/// normalized to the lower half of the curve order (BIP 62).
+///
+/// These are widely used to test Bitcoin wallet implementations, and were
+/// cross-checked against an independent implementation of RFC 6979.
+#[rustfmt::skip]
+pub const SHA256_RFC6979_TEST_VECTORS: &[TestVector] = &[
+    // secret key 1, message "Satoshi Nakamoto"
+    TestVector {
+        sk: b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01",
+        pk: b"\x02\x79\xbe\x66\x7e\xf9\xdc\xbb\xac\x55\xa0\x62\x95\xce\x87\x0b\x07\x02\x9b\xfc\xdb\x2d\xce\x28\xd9\x59\xf2\x81\x5b\x16\xf8\x17\x98",
+        nonce: Some(b"\x8f\x8a\x27\x6c\x19\xf4\x14\x96\x56\xb2\x80\x62\x1e\x35\x8c\xce\x24\xf5\xf5\x25\x42\x77\x26\x91\xee\x69\x06\x3b\x74\xf1\x5d\x15"),
+        msg: b"\x53\x61\x74\x6f\x73\x68\x69\x20\x4e\x61\x6b\x61\x6d\x6f\x74\x6f",
+        sig: b"\x93\x4b\x1e\xa1\x0a\x4b\x3c\x17\x57\xe2\xb0\xc0\x17\xd0\xb6\x14\x3c\xe3\xc9\xa7\xe6\xa4\xa4\x98\x60\xd7\xa6\xab\x21\x0e\xe3\xd8\x24\x42\xce\x9d\x2b\x91\x60\x64\x10\x80\x14\x78\x3e\x92\x3e\xc3\x6b\x49\x74\x3e\x2f\xfa\x1c\x44\x96\xf0\x1a\x51\x2a\xaf\xd9\xe5",
+    },
+    // secret key n - 1, message "Satoshi Nakamoto"
+    TestVector {
+        sk: b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xfe\xba\xae\xdc\xe6\xaf\x48\xa0\x3b\xbf\xd2\x5e\x8c\xd0\x36\x41\x40",
+        pk: b"\x03\x79\xbe\x66\x7e\xf9\xdc\xbb\xac\x55\xa0\x62\x95\xce\x87\x0b\x07\x02\x9b\xfc\xdb\x2d\xce\x28\xd9\x59\xf2\x81\x5b\x16\xf8\x17\x98",
+        nonce: Some(b"\x33\xa1\x9b\x60\xe2\x5f\xb6\xf4\x43\x5a\xf5\x3a\x3d\x42\xd4\x93\x64\x48\x27\x36\x7e\x64\x53\x92\x85\x54\xf4\x3e\x49\xaa\x6f\x90"),
+        msg: b"\x53\x61\x74\x6f\x73\x68\x69\x20\x4e\x61\x6b\x61\x6d\x6f\x74\x6f",
+        sig: b"\xfd\x56\x7d\x12\x1d\xb6\x6e\x38\x29\x91\x53\x4a\xda\x77\xa6\xbd\x31\x06\xf0\xa1\x09\x8c\x23\x1e\x47\x99\x34\x47\xcd\x6a\xf2\xd0\x6b\x39\xcd\x0e\xb1\xbc\x86\x03\xe1\x59\xef\x5c\x20\xa5\xc8\xad\x68\x5a\x45\xb0\x6c\xe9\xbe\xbe\xd3\xf1\x53\xd1\x0d\x93\xbe\xd5",
+    },
+    // message "Alan Turing"
+    TestVector {
+        sk: b"\xf8\xb8\xaf\x8c\xe3\xc7\xcc\xa5\xe3\x00\xd3\x39\x39\x54\x0c\x10\xd4\x5c\xe0\x01\xb8\xf2\x52\xbf\xbc\x57\xba\x03\x42\x90\x41\x81",
+        pk: b"\x02\x92\xdf\x7b\x24\x5b\x81\xaa\x63\x7a\xb4\xe8\x67\xc8\xd5\x11\x00\x8f\x79\x16\x1a\x97\xd6\x4f\x2a\xc7\x09\x60\x03\x52\xf7\xac\xbc",
+        nonce: Some(b"\x52\x5a\x82\xb7\x0e\x67\x87\x43\x98\x06\x75\x43\xfd\x84\xc8\x3d\x30\xc1\x75\xfd\xc4\x5f\xde\xee\x08\x2f\xe1\x3b\x1d\x7c\xfd\xf1"),
+        msg: b"\x41\x6c\x61\x6e\x20\x54\x75\x72\x69\x6e\x67",
+        sig: b"\x70\x63\xae\x83\xe7\xf6\x2b\xbb\x17\x17\x98\x13\x1b\x4a\x05\x64\xb9\x56\x93\x00\x92\xb3\x3b\x07\xb3\x95\x61\x5d\x9e\xc7\xe1\x5c\x58\xdf\xcc\x1e\x00\xa3\x5e\x15\x72\xf3\x66\xff\xe3\x4b\xa0\xfc\x47\xdb\x1e\x71\x89\x75\x9b\x9f\xb2\x33\xc5\xb0\x5a\xb3\x88\xea",
+    },
+];
+
+/// ECDSA secp256k1 test vectors for deterministic signatures with
+/// Keccak-256 (as used by Ethereum) as the message digest, with nonces
+/// derived using RFC 6979 with HMAC-SHA256 (as libsecp256k1 does) and `s`
+/// normalized to the lower half of the curve order.
+///
+/// The last vector is the example transaction from EIP-155, and the others
+/// were generated with an independent implementation.
+#[rustfmt::skip]
+pub const KECCAK256_RFC6979_TEST_VECTORS: &[TestVector] = &[
+    // secret key 1, message "Satoshi Nakamoto"
+    TestVector {
+        sk: b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01",
+        pk: b"\x02\x79\xbe\x66\x7e\xf9\xdc\xbb\xac\x55\xa0\x62\x95\xce\x87\x0b\x07\x02\x9b\xfc\xdb\x2d\xce\x28\xd9\x59\xf2\x81\x5b\x16\xf8\x17\x98",
+        nonce: Some(b"\x0a\x28\xb0\x06\xaf\xc5\x0b\xeb\x23\x96\xd4\x25\xe9\x1a\xc9\xa9\xf1\xbc\x0c\xd0\xbb\x24\x7f\x3e\x6a\x4e\x9c\x09\x8c\x58\x19\x09"),
+        msg: b"\x53\x61\x74\x6f\x73\x68\x69\x20\x4e\x61\x6b\x61\x6d\x6f\x74\x6f",
+        sig: b"\x13\xf3\xc6\x9f\xad\x2f\x18\x20\x8f\x7f\xc3\x79\xde\x40\x52\xe9\x82\xd2\xcc\x76\xaa\xec\x6b\xf9\x62\x33\x8d\x6e\xbc\xd6\xfe\xd6\x65\xaf\x5e\x41\x7e\xe9\x53\xb0\x71\xc0\x41\x15\x00\x44\xa7\x9a\x20\x1f\xc0\x2e\x9f\x07\x5f\x22\x60\xde\x15\x99\x38\x9a\x7f\x58",
+    },
+    // secret key n - 1, message "Satoshi Nakamoto"
+    TestVector {
+        sk: b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xfe\xba\xae\xdc\xe6\xaf\x48\xa0\x3b\xbf\xd2\x5e\x8c\xd0\x36\x41\x40",
+        pk: b"\x03\x79\xbe\x66\x7e\xf9\xdc\xbb\xac\x55\xa0\x62\x95\xce\x87\x0b\x07\x02\x9b\xfc\xdb\x2d\xce\x28\xd9\x59\xf2\x81\x5b\x16\xf8\x17\x98",
+        nonce: Some(b"\x3a\xb4\xe4\x09\xfc\xf9\x9e\x1d\xf2\x67\x58\x85\xe4\x00\x38\x3b\x18\xba\xe8\xdf\x33\x29\x64\x6a\xe6\xa7\x5c\x37\x62\x8a\xc6\xc9"),
+        msg: b"\x53\x61\x74\x6f\x73\x68\x69\x20\x4e\x61\x6b\x61\x6d\x6f\x74\x6f",
+        sig: b"\x4c\xeb\xae\x02\x9e\xf7\xbf\x0f\xae\xba\xfc\x58\xdd\x78\xe5\x2d\xb0\x7f\x0e\x98\xb7\x06\x2f\x2c\xfd\xd0\x65\xce\x5b\xba\x02\xde\x11\x50\x70\xfd\x44\xef\x35\xcd\xe3\x1c\x4d\x4c\x74\x09\xae\xb3\x80\x6f\x0d\x73\x1e\xf5\xf6\x90\x70\x50\xdd\xa5\x3a\x5e\x2d\xa4",
+    },
+    // message "Alan Turing"
+    TestVector {
+        sk: b"\xf8\xb8\xaf\x8c\xe3\xc7\xcc\xa5\xe3\x00\xd3\x39\x39\x54\x0c\x10\xd4\x5c\xe0\x01\xb8\xf2\x52\xbf\xbc\x57\xba\x03\x42\x90\x41\x81",
+        pk: b"\x02\x92\xdf\x7b\x24\x5b\x81\xaa\x63\x7a\xb4\xe8\x67\xc8\xd5\x11\x00\x8f\x79\x16\x1a\x97\xd6\x4f\x2a\xc7\x09\x60\x03\x52\xf7\xac\xbc",
+        nonce: Some(b"\x57\xd7\xc1\xdd\x42\x67\xb0\x1a\x3a\xc5\x8d\x7e\x04\x94\x5f\xef\xbf\xda\xa3\x69\xee\xf2\x77\x3a\xb7\xd9\xc4\xee\xfe\x5d\x0f\x04"),
+        msg: b"\x41\x6c\x61\x6e\x20\x54\x75\x72\x69\x6e\x67",
+        sig: b"\x3b\xd1\x5a\xaa\x5f\x46\xc4\xb6\xc9\x1e\x56\x6d\xa1\x66\x92\x31\xb0\x70\x35\x87\x45\x17\xe9\x0a\x7c\xaa\x84\xee\x6e\xe1\x26\x38\x01\x49\x29\x5a\x33\x8f\x52\x5c\xc6\xcf\xf5\xe7\x9f\xfa\xf1\x40\x5f\x64\x4e\x4b\x51\x1d\x96\x9f\x98\xf0\x9a\x66\x8e\xf2\xcf\x63",
+    },
+    // EIP-155 example transaction (RLP-encoded) with chain ID 1
+    TestVector {
+        sk: b"\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46",
+        pk: b"\x02\x4b\xc2\xa3\x12\x65\x15\x3f\x07\xe7\x0e\x0b\xab\x08\x72\x4e\x6b\x85\xe2\x17\xf8\xcd\x62\x8c\xeb\x62\x97\x42\x47\xbb\x49\x33\x82",
+        nonce: Some(b"\x49\x44\xe2\x70\x76\xbf\x36\xf6\x5c\xc6\x00\x50\x1d\x77\x7c\xbd\xb9\x3a\x74\x0b\x2d\x1e\x12\x29\x84\x14\xc5\xba\xd5\xcd\x3e\x56"),
+        msg: b"\xec\x09\x85\x04\xa8\x17\xc8\x00\x82\x52\x08\x94\x35\x35\x35\x35\x35\x35\x35\x35\x35\x35\x35\x35\x35\x35\x35\x35\x35\x35\x35\x35\x88\x0d\xe0\xb6\xb3\xa7\x64\x00\x00\x80\x01\x80\x80",
+        sig: b"\x28\xef\x61\x34\x0b\xd9\x39\xbc\x21\x95\xfe\x53\x75\x67\x86\x60\x03\xe1\xa1\x5d\x3c\x71\xff\x63\xe1\x59\x06\x20\xaa\x63\x62\x76\x67\xcb\xe9\xd8\x99\x7f\x76\x1a\xec\xb7\x03\x30\x4b\x38\x00\xcc\xf5\x55\xc9\xf3\xdc\x64\x21\x4b\x29\x7f\xb1\x96\x6a\x3b\x6d\x83",
+    },
+];
+
+/// ECDSA secp256k1 test vectors for deterministic signatures with SHA3-256
+/// as the message digest, with nonces derived using RFC 6979 with
+/// HMAC-SHA256 (as libsecp256k1 does) and `s` normalized to the lower half of
+/// the curve order.
+///
+/// Generated with an independent implementation, using the same keys and
+/// messages as the Keccak-256 test vectors.
+#[rustfmt::skip]
+pub const SHA3_256_RFC6979_TEST_VECTORS: &[TestVector] = &[
+    // secret key 1, message "Satoshi Nakamoto"
+    TestVector {
+        sk: b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01",
+        pk: b"\x02\x79\xbe\x66\x7e\xf9\xdc\xbb\xac\x55\xa0\x62\x95\xce\x87\x0b\x07\x02\x9b\xfc\xdb\x2d\xce\x28\xd9\x59\xf2\x81\x5b\x16\xf8\x17\x98",
+        nonce: Some(b"\xd1\x8a\xcc\xfc\xf4\x6a\x46\xdb\x1e\xc3\x0d\xdd\x6d\xe1\x2e\x10\x48\x84\xfd\x99\xdd\x74\x0b\x3b\x8d\x84\x7c\x0b\x33\xb5\xc6\x93"),
+        msg: b"\x53\x61\x74\x6f\x73\x68\x69\x20\x4e\x61\x6b\x61\x6d\x6f\x74\x6f",
+        sig: b"\x59\xe5\xfa\xdf\x0c\x28\xee\xa5\x09\xc7\xc5\xe6\x9e\x18\x6c\x73\x39\x02\x4d\x65\x4e\x04\x2c\x7c\x74\x9b\x94\xde\x8b\x50\x89\xbf\x0a\x1f\x49\xca\x3b\x91\xbe\x37\x47\xfc\xbc\xcf\xb5\x6f\x6f\xb8\x37\x4e\x18\x8c\x29\x5e\xbe\xda\x69\x5f\x89\x67\x1e\x52\x23\x65",
+    },
+    // secret key n - 1, message "Satoshi Nakamoto"
+    TestVector {
+        sk: b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xfe\xba\xae\xdc\xe6\xaf\x48\xa0\x3b\xbf\xd2\x5e\x8c\xd0\x36\x41\x40",
+        pk: b"\x03\x79\xbe\x66\x7e\xf9\xdc\xbb\xac\x55\xa0\x62\x95\xce\x87\x0b\x07\x02\x9b\xfc\xdb\x2d\xce\x28\xd9\x59\xf2\x81\x5b\x16\xf8\x17\x98",
+        nonce: Some(b"\xa1\x36\x78\x19\x04\x7d\x3e\xcb\x79\x33\x31\xfc\xcb\xb1\x5c\xdc\xb1\x50\x1d\xff\xbe\x97\x61\x7e\xf6\x8d\xed\x3f\x77\x08\x9e\x05"),
+        msg: b"\x53\x61\x74\x6f\x73\x68\x69\x20\x4e\x61\x6b\x61\x6d\x6f\x74\x6f",
+        sig: b"\xb9\xc2\xd0\xdf\x82\x81\x9f\x25\x57\xa5\x9d\x84\x73\x44\x3b\x7d\xf4\x25\xbf\x7c\xb9\xa4\xad\x1d\xea\x40\xe0\xc7\x13\x2a\x8b\x13\x32\x47\x82\x8f\x94\x77\xe7\xd6\x54\x0a\x47\x5a\x0b\x74\x08\x8e\x4d\xb5\x6e\x66\x82\xf3\xf5\x5b\x11\xc7\xe3\x2b\x35\x5a\x80\x02",
+    },
+    // message "Alan Turing"
+    TestVector {
+        sk: b"\xf8\xb8\xaf\x8c\xe3\xc7\xcc\xa5\xe3\x00\xd3\x39\x39\x54\x0c\x10\xd4\x5c\xe0\x01\xb8\xf2\x52\xbf\xbc\x57\xba\x03\x42\x90\x41\x81",
+        pk: b"\x02\x92\xdf\x7b\x24\x5b\x81\xaa\x63\x7a\xb4\xe8\x67\xc8\xd5\x11\x00\x8f\x79\x16\x1a\x97\xd6\x4f\x2a\xc7\x09\x60\x03\x52\xf7\xac\xbc",
+        nonce: Some(b"\x38\xe4\xef\x47\xce\x60\xe2\xbc\xb2\x36\x40\x2e\xc8\x98\x29\xda\x66\x20\x76\xd1\x61\x65\x8e\xf2\x00\xfa\x57\x59\x3f\xc1\x9e\xf0"),
+        msg: b"\x41\x6c\x61\x6e\x20\x54\x75\x72\x69\x6e\x67",
+        sig: b"\x1c\xc9\x5a\x29\xe7\x28\x07\x68\xe4\x55\xf9\x61\xd8\x53\xea\xdb\x62\x8b\x6a\xd9\x23\xc4\x5c\xe4\x21\x78\x24\x5e\x6b\x67\x9a\xce\x08\x1c\x17\xea\x0e\x92\x4e\x9b\x3e\xc8\xa1\x7c\x34\xe4\xbb\x68\x3f\x84\xec\xa8\x6c\x53\xd3\x30\xc6\xe6\xc1\xcf\x39\xd1\xb7\x6b",
+    },
+    // EIP-155 example transaction (RLP-encoded), hashed with SHA3-256
+    TestVector {
+        sk: b"\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46\x46",
+        pk: b"\x02\x4b\xc2\xa3\x12\x65\x15\x3f\x07\xe7\x0e\x0b\xab\x08\x72\x4e\x6b\x85\xe2\x17\xf8\xcd\x62\x8c\xeb\x62\x97\x42\x47\xbb\x49\x33\x82",
+        nonce: Some(b"\x40\xd6\x83\xbb\xf2\x0f\x49\x95\x18\x66\x41\xb1\xd6\x70\x20\x00\xba\x96\x69\xb4\x0b\x82\x66\xde\xef\x82\x4c\xd6\xd3\x9f\xe1\x68"),
+        msg: b"\xec\x09\x85\x04\xa8\x17\xc8\x00\x82\x52\x08\x94\x35\x35\x35\x35\x35\x35\x35\x35\x35\x35\x35\x35\x35\x35\x35\x35\x35\x35\x35\x35\x88\x0d\xe0\xb6\xb3\xa7\x64\x00\x00\x80\x01\x80\x80",
+        sig: b"\x33\x35\x9e\x4b\xf2\x5b\x90\xc1\x63\x12\x52\x2d\xc8\xd0\x35\x78\xbf\x70\x6f\x99\xde\x63\xdc\x1b\x2c\x18\x76\x66\x3a\x22\xa9\x20\x64\x12\x77\xa5\xce\x05\xe4\x10\xc2\xea\x7e\x59\x5c\x85\x7d\xfb\x07\xd4\x32\x9d\xc3\x62\x8c\xb8\x6d\x82\x94\x98\x9d\x5e\x16\xb2",
+    },
+];
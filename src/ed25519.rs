@@ -55,8 +55,14 @@
 //!     }
 //! ```
 
+mod context;
+mod policy;
 mod public_key;
 mod seed;
+#[cfg(feature = "ed25519-software")]
+mod software;
+#[cfg(feature = "ed25519-software")]
+mod x25519;
 
 #[cfg(feature = "test-vectors")]
 #[macro_use]
@@ -66,9 +72,19 @@ mod test_macros;
 #[cfg(feature = "test-vectors")]
 mod test_vectors;
 
+#[cfg(feature = "ed25519-software")]
+pub use self::software::{Signer, Verifier};
 #[cfg(feature = "test-vectors")]
 pub use self::test_vectors::TEST_VECTORS;
+#[cfg(feature = "test-vectors")]
+pub use self::test_vectors::{
+    ED25519CTX_TEST_VECTORS, ED25519PH_TEST_VECTORS, ED25519_EDGE_CASE_VECTORS,
+};
+#[cfg(feature = "ed25519-software")]
+pub use self::x25519::X25519_KEY_SIZE;
 pub use self::{
+    context::{ContextSigner, ContextVerifier, MAX_CONTEXT_SIZE},
+    policy::VerificationPolicy,
     public_key::{PublicKey, PUBLIC_KEY_SIZE},
     seed::{Seed, SEED_SIZE},
 };
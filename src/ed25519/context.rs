@@ -0,0 +1,37 @@
+//! Ed25519ctx: Ed25519 signatures bound to a context string
+//!
+//! Described in RFC 8032 Section 5.1. The context is hashed into the
+//! signature (along with a domain separator), so a signature produced for
+//! one context (or by pure Ed25519) will never verify under another.
+
+use super::Signature;
+use signature::Error;
+
+/// Maximum size of an Ed25519ctx context string
+pub const MAX_CONTEXT_SIZE: usize = 255;
+
+/// Sign messages using Ed25519ctx
+pub trait ContextSigner {
+    /// Sign the given message using the given context string, which must be
+    /// between 1 and `MAX_CONTEXT_SIZE` bytes
+    fn try_sign_with_context(&self, context: &[u8], msg: &[u8]) -> Result<Signature, Error>;
+
+    /// Sign the given message using the given context string, panicking if
+    /// the context is invalid or signing fails
+    fn sign_with_context(&self, context: &[u8], msg: &[u8]) -> Signature {
+        self.try_sign_with_context(context, msg)
+            .expect("signature operation failed")
+    }
+}
+
+/// Verify Ed25519ctx signatures
+pub trait ContextVerifier {
+    /// Verify the given Ed25519ctx signature of the given message under the
+    /// given context string
+    fn verify_with_context(
+        &self,
+        context: &[u8],
+        msg: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error>;
+}
@@ -0,0 +1,177 @@
+//! Ed25519 verification policies
+//!
+//! RFC 8032 leaves implementations some latitude in how they handle edge
+//! cases such as small-order points, non-canonical point encodings, and the
+//! choice between the cofactored and cofactorless verification equations.
+//! Different providers make different choices, which is a consensus hazard
+//! for systems where several implementations must agree on the validity of
+//! every signature. Selecting a policy explicitly avoids this.
+
+use super::{PublicKey, Signature};
+use signature::Error;
+
+/// Order of the Ed25519 base point (`ℓ`), serialized as little endian bytes
+const ORDER: [u8; 32] = [
+    0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+];
+
+/// Encodings (with the sign bit cleared) of the `y`-coordinates of every
+/// point of small order, including the non-canonical encodings `p` and
+/// `p + 1` (the same list libsodium uses)
+const SMALL_ORDER_ENCODINGS: [[u8; 32]; 7] = [
+    // 0 (order 4)
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00,
+    ],
+    // 1 (order 1)
+    [
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00,
+    ],
+    // 2707385501144840649318225287225658788936804267575313519463743609750303402022 (order 8)
+    [
+        0x26, 0xe8, 0x95, 0x8f, 0xc2, 0xb2, 0x27, 0xb0, 0x45, 0xc3, 0xf4, 0x89, 0xf2, 0xef, 0x98,
+        0xf0, 0xd5, 0xdf, 0xac, 0x05, 0xd3, 0xc6, 0x33, 0x39, 0xb1, 0x38, 0x02, 0x88, 0x6d, 0x53,
+        0xfc, 0x05,
+    ],
+    // 55188659117513257062467267217118295137698188065244968500265048394206261417927 (order 8)
+    [
+        0xc7, 0x17, 0x6a, 0x70, 0x3d, 0x4d, 0xd8, 0x4f, 0xba, 0x3c, 0x0b, 0x76, 0x0d, 0x10, 0x67,
+        0x0f, 0x2a, 0x20, 0x53, 0xfa, 0x2c, 0x39, 0xcc, 0xc6, 0x4e, 0xc7, 0xfd, 0x77, 0x92, 0xac,
+        0x03, 0x7a,
+    ],
+    // p - 1 (order 2)
+    [
+        0xec, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0x7f,
+    ],
+    // p (non-canonical encoding of 0, order 4)
+    [
+        0xed, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0x7f,
+    ],
+    // p + 1 (non-canonical encoding of 1, order 1)
+    [
+        0xee, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0x7f,
+    ],
+];
+
+/// Rules for which Ed25519 signatures are considered valid
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum VerificationPolicy {
+    /// RFC 8032 Section 5.1.7, applied strictly:
+    ///
+    /// - `A` and `R` MUST be canonical point encodings
+    /// - `S` MUST be less than `ℓ`
+    /// - small-order points are allowed
+    /// - the cofactored equation `[8][S]B = [8]R + [8][k]A` is checked
+    Rfc8032,
+
+    /// ZIP-215 (as used by Zcash), which is designed so that single and
+    /// batch verification always agree:
+    ///
+    /// - `A` and `R` MAY be non-canonical point encodings
+    /// - `S` MUST be less than `ℓ`
+    /// - small-order points are allowed
+    /// - the cofactored equation `[8][S]B = [8]R + [8][k]A` is checked
+    Zip215,
+
+    /// Compatible with libsodium's `crypto_sign_verify_detached` (as used by
+    /// `signatory-sodiumoxide`):
+    ///
+    /// - `A` MUST have a canonical `y`-coordinate
+    /// - `S` MUST be less than `ℓ`
+    /// - `A` and `R` MUST NOT be small-order points
+    /// - the cofactorless equation `[S]B = R + [k]A` is checked, with `R`
+    ///   compared by its encoding
+    Libsodium,
+}
+
+impl VerificationPolicy {
+    /// Does this policy use the cofactored verification equation?
+    pub fn is_cofactored(self) -> bool {
+        match self {
+            VerificationPolicy::Rfc8032 | VerificationPolicy::Zip215 => true,
+            VerificationPolicy::Libsodium => false,
+        }
+    }
+
+    /// Check the parts of this policy which apply to the encodings of the
+    /// public key and signature (i.e. everything besides the verification
+    /// equation itself).
+    ///
+    /// Providers can use this to bring a backend's edge case handling in line
+    /// with a policy.
+    pub fn check_encodings(
+        self,
+        public_key: &PublicKey,
+        signature: &Signature,
+    ) -> Result<(), Error> {
+        let a = public_key.as_bytes();
+        let (r, s) = signature.as_ref().split_at(32);
+
+        let valid = is_canonical_scalar(s)
+            && match self {
+                VerificationPolicy::Rfc8032 => is_canonical_point(a) && is_canonical_point(r),
+                VerificationPolicy::Zip215 => true,
+                VerificationPolicy::Libsodium => {
+                    has_canonical_y(a) && !is_small_order(a) && !is_small_order(r)
+                }
+            };
+
+        if valid {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+/// Is the given little endian scalar less than `ℓ`?
+fn is_canonical_scalar(bytes: &[u8]) -> bool {
+    for (byte, order_byte) in bytes.iter().zip(ORDER.iter()).rev() {
+        if byte != order_byte {
+            return byte < order_byte;
+        }
+    }
+
+    false
+}
+
+/// Is the `y`-coordinate of the given point encoding less than `p`?
+fn has_canonical_y(bytes: &[u8]) -> bool {
+    // The only values which aren't are `p` through `2^255 - 1`, i.e. all ones
+    // in every bit but the sign bit and the low byte `>= 0xed`
+    bytes[0] < 0xed || bytes[1..31].iter().any(|&b| b != 0xff) || bytes[31] & 0x7f != 0x7f
+}
+
+/// Is the given point encoding canonical according to RFC 8032 Section 5.1.3?
+pub(crate) fn is_canonical_point(bytes: &[u8]) -> bool {
+    // Points with `x = 0` (i.e. `y = 1` and `y = p - 1`) can't have the sign
+    // bit set
+    let x_is_zero =
+        y_matches(bytes, &SMALL_ORDER_ENCODINGS[1]) || y_matches(bytes, &SMALL_ORDER_ENCODINGS[4]);
+
+    has_canonical_y(bytes) && !(x_is_zero && bytes[31] & 0x80 != 0)
+}
+
+/// Is the given point encoding that of a point of small order?
+fn is_small_order(bytes: &[u8]) -> bool {
+    SMALL_ORDER_ENCODINGS
+        .iter()
+        .any(|encoding| y_matches(bytes, encoding))
+}
+
+/// Does the `y`-coordinate of the given point encoding (i.e. ignoring the
+/// sign bit) match the given encoding?
+fn y_matches(bytes: &[u8], encoding: &[u8; 32]) -> bool {
+    bytes[..31] == encoding[..31] && bytes[31] & 0x7f == encoding[31]
+}
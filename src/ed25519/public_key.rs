@@ -2,12 +2,33 @@
 
 use core::fmt::{self, Debug};
 
+#[cfg(any(
+    feature = "cose",
+    feature = "jwk",
+    feature = "openssh",
+    feature = "spki"
+))]
+use crate::encoding::Algorithm;
 #[cfg(feature = "encoding")]
 use crate::encoding::Decode;
 #[cfg(all(feature = "alloc", feature = "encoding"))]
 use crate::encoding::Encode;
-#[cfg(all(feature = "alloc", feature = "encoding"))]
+#[cfg(all(feature = "alloc", feature = "spki"))]
+use crate::encoding::ToPublicKeyDer;
+#[cfg(feature = "cose")]
+use crate::encoding::{cose::CoseKey, FromCoseKey, ToCoseKey};
+#[cfg(feature = "openssh")]
+use crate::encoding::{openssh, FromOpenSshPublicKey, ToOpenSshPublicKey};
+#[cfg(feature = "spki")]
+use crate::encoding::{spki::SubjectPublicKeyInfo, FromPublicKeyDer};
+#[cfg(feature = "jwk")]
+use crate::encoding::{FromJwk, Jwk, ToJwk};
+#[cfg(feature = "openssh")]
+use alloc::string::String;
+#[cfg(all(feature = "alloc", any(feature = "encoding", feature = "spki")))]
 use alloc::vec::Vec;
+#[cfg(feature = "ed25519-software")]
+use curve25519_dalek::edwards::CompressedEdwardsY;
 #[cfg(feature = "encoding")]
 use subtle_encoding::Encoding;
 
@@ -38,6 +59,42 @@ impl PublicKey {
         }
     }
 
+    /// Create an Ed25519 public key from a 32-byte array, ensuring it's valid
+    /// (see [`PublicKey::is_valid`])
+    #[cfg(feature = "ed25519-software")]
+    pub fn new_validated(bytes: [u8; PUBLIC_KEY_SIZE]) -> Option<Self> {
+        Some(Self::new(bytes)).filter(Self::is_valid)
+    }
+
+    /// Create an Ed25519 public key from its serialized (compressed Edwards-y)
+    /// form, ensuring it's valid (see [`PublicKey::is_valid`])
+    #[cfg(feature = "ed25519-software")]
+    pub fn from_bytes_validated<B>(bytes: B) -> Option<Self>
+    where
+        B: AsRef<[u8]>,
+    {
+        Self::from_bytes(bytes).filter(Self::is_valid)
+    }
+
+    /// Is this public key a canonically encoded point in the prime-order
+    /// subgroup? This rejects:
+    ///
+    /// - encodings which don't decompress to a point on the curve
+    /// - non-canonical encodings (e.g. `y >= p`)
+    /// - small-order points (including the identity)
+    /// - mixed-order points (i.e. ones with a torsion component)
+    #[cfg(feature = "ed25519-software")]
+    pub fn is_valid(&self) -> bool {
+        if !super::policy::is_canonical_point(&self.0) {
+            return false;
+        }
+
+        match CompressedEdwardsY(self.0).decompress() {
+            Some(point) => !point.is_small_order() && point.is_torsion_free(),
+            None => false,
+        }
+    }
+
     /// Obtain public key as a byte array reference
     #[inline]
     pub fn as_bytes(&self) -> &[u8; PUBLIC_KEY_SIZE] {
@@ -91,4 +148,80 @@ impl Encode for PublicKey {
     }
 }
 
+#[cfg(feature = "spki")]
+impl FromPublicKeyDer for PublicKey {
+    /// Decode an Ed25519 public key from a DER-encoded **SubjectPublicKeyInfo**
+    fn from_public_key_der(bytes: &[u8]) -> Result<Self, crate::encoding::Error> {
+        let spki = SubjectPublicKeyInfo::from_der_with_algorithm(bytes, Algorithm::Ed25519)?;
+        Self::from_bytes(spki.subject_public_key)
+            .ok_or_else(|| crate::encoding::error::ErrorKind::Decode.into())
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "spki"))]
+impl ToPublicKeyDer for PublicKey {
+    /// Encode an Ed25519 public key as a DER-encoded **SubjectPublicKeyInfo**
+    fn to_public_key_der(&self) -> Vec<u8> {
+        SubjectPublicKeyInfo {
+            algorithm: Algorithm::Ed25519,
+            subject_public_key: self.as_bytes(),
+        }
+        .to_der()
+    }
+}
+
+#[cfg(feature = "openssh")]
+impl FromOpenSshPublicKey for PublicKey {
+    /// Decode an Ed25519 public key from an OpenSSH `ssh-ed25519` public key line
+    fn from_openssh_public_key<S: AsRef<str>>(line: S) -> Result<Self, crate::encoding::Error> {
+        let public_key =
+            openssh::PublicKey::from_openssh_with_algorithm(line.as_ref(), Algorithm::Ed25519)?;
+
+        Self::from_bytes(public_key.public_key())
+            .ok_or_else(|| crate::encoding::error::ErrorKind::Decode.into())
+    }
+}
+
+#[cfg(feature = "openssh")]
+impl ToOpenSshPublicKey for PublicKey {
+    /// Encode an Ed25519 public key as an OpenSSH `ssh-ed25519` public key line
+    fn to_openssh_public_key(&self, comment: &str) -> String {
+        openssh::encode_public_key(Algorithm::Ed25519, self.as_bytes(), comment)
+    }
+}
+
+#[cfg(feature = "jwk")]
+impl FromJwk for PublicKey {
+    /// Decode an Ed25519 public key from an `OKP` JWK
+    fn from_jwk(jwk: &Jwk) -> Result<Self, crate::encoding::Error> {
+        Self::from_bytes(jwk.public_key_for(Algorithm::Ed25519)?)
+            .ok_or_else(|| crate::encoding::error::ErrorKind::Decode.into())
+    }
+}
+
+#[cfg(feature = "jwk")]
+impl ToJwk for PublicKey {
+    /// Encode an Ed25519 public key as an `OKP` JWK
+    fn to_jwk(&self) -> Result<Jwk, crate::encoding::Error> {
+        Ok(Jwk::new(Algorithm::Ed25519, self.as_bytes())?.with_thumbprint_kid())
+    }
+}
+
+#[cfg(feature = "cose")]
+impl FromCoseKey for PublicKey {
+    /// Decode an Ed25519 public key from an `OKP` COSE_Key
+    fn from_cose_key(bytes: &[u8]) -> Result<Self, crate::encoding::Error> {
+        Self::from_bytes(CoseKey::from_bytes(bytes)?.public_key_for(Algorithm::Ed25519)?)
+            .ok_or_else(|| crate::encoding::error::ErrorKind::Decode.into())
+    }
+}
+
+#[cfg(feature = "cose")]
+impl ToCoseKey for PublicKey {
+    /// Encode an Ed25519 public key as an `OKP` COSE_Key
+    fn to_cose_key(&self) -> Result<Vec<u8>, crate::encoding::Error> {
+        Ok(CoseKey::new(Algorithm::Ed25519, self.as_bytes())?.to_bytes())
+    }
+}
+
 impl crate::public_key::PublicKey for PublicKey {}
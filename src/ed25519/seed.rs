@@ -1,10 +1,21 @@
 //! Ed25519 seeds: 32-bit uniformly random secret value used to derive scalars
 //! and nonce prefixes
 
+#[cfg(any(
+    all(feature = "jwk", feature = "ed25519-software"),
+    feature = "openssh"
+))]
+use crate::encoding::Algorithm;
 #[cfg(feature = "encoding")]
 use crate::encoding::Decode;
 #[cfg(all(feature = "alloc", feature = "encoding"))]
 use crate::encoding::Encode;
+#[cfg(feature = "openssh")]
+use crate::encoding::{openssh, FromOpenSshPrivateKey};
+#[cfg(all(feature = "jwk", feature = "ed25519-software"))]
+use crate::encoding::{FromJwk, Jwk, ToJwk};
+#[cfg(feature = "ed25519-software")]
+use crate::public_key::PublicKeyed;
 #[cfg(all(feature = "encoding", feature = "alloc"))]
 use alloc::vec::Vec;
 #[cfg(feature = "getrandom")]
@@ -53,22 +64,59 @@ impl Seed {
         }
     }
 
-    /// Create an Ed25519 seed from a keypair: i.e. a seed and its assocaited
+    /// Create an Ed25519 seed from a keypair: i.e. a seed and its associated
     /// public key (i.e. compressed Edwards-y coordinate)
+    ///
+    /// The public key half of the keypair is ignored: use
+    /// [`Seed::from_keypair_validated`] to ensure it matches the seed.
     pub fn from_keypair(keypair: &[u8]) -> Option<Self> {
         if keypair.len() == KEYPAIR_SIZE {
-            // TODO: ensure public key part of keypair is correct
             Self::from_bytes(&keypair[..SEED_SIZE])
         } else {
             None
         }
     }
 
-    /// Decode a `Seed` from an encoded (hex or Base64) Ed25519 keypair
+    /// Create an Ed25519 seed from a keypair, returning `None` unless the
+    /// public key half of the keypair is the one derived from the seed
+    #[cfg(feature = "ed25519-software")]
+    pub fn from_keypair_validated(keypair: &[u8]) -> Option<Self> {
+        let seed = Self::from_keypair(keypair)?;
+
+        if seed.public_key().ok()?.as_ref() == &keypair[SEED_SIZE..] {
+            Some(seed)
+        } else {
+            None
+        }
+    }
+
+    /// Decode a `Seed` from an encoded (hex or Base64) Ed25519 keypair.
+    ///
+    /// As with [`Seed::from_keypair`], the public key half is ignored.
     #[cfg(feature = "encoding")]
     pub fn decode_keypair<E: Encoding>(
         encoded_keypair: &[u8],
         encoding: &E,
+    ) -> Result<Self, signature::Error> {
+        Self::decode_keypair_with(encoded_keypair, encoding, Self::from_keypair)
+    }
+
+    /// Decode a `Seed` from an encoded (hex or Base64) Ed25519 keypair,
+    /// returning an error unless its public key matches the seed
+    #[cfg(all(feature = "encoding", feature = "ed25519-software"))]
+    pub fn decode_keypair_validated<E: Encoding>(
+        encoded_keypair: &[u8],
+        encoding: &E,
+    ) -> Result<Self, signature::Error> {
+        Self::decode_keypair_with(encoded_keypair, encoding, Self::from_keypair_validated)
+    }
+
+    /// Decode an encoded keypair and pass it to the given constructor
+    #[cfg(feature = "encoding")]
+    fn decode_keypair_with<E: Encoding>(
+        encoded_keypair: &[u8],
+        encoding: &E,
+        from_keypair: fn(&[u8]) -> Option<Self>,
     ) -> Result<Self, signature::Error> {
         let mut decoded_keypair = [0u8; SEED_SIZE * 2];
         let decoded_len = encoding
@@ -76,7 +124,7 @@ impl Seed {
             .map_err(|_| signature::Error::new())?;
 
         if decoded_len == SEED_SIZE * 2 {
-            Self::from_keypair(&decoded_keypair).ok_or_else(signature::Error::new)
+            from_keypair(&decoded_keypair).ok_or_else(signature::Error::new)
         } else {
             Err(signature::Error::new())
         }
@@ -115,6 +163,59 @@ impl Encode for Seed {
     }
 }
 
+#[cfg(feature = "openssh")]
+impl FromOpenSshPrivateKey for Seed {
+    /// Obtain an Ed25519 seed from an OpenSSH `ssh-ed25519` private key
+    fn from_openssh_private_key(
+        private_key: &openssh::PrivateKey,
+    ) -> Result<Self, crate::encoding::Error> {
+        if private_key.algorithm != Algorithm::Ed25519 {
+            Err(crate::encoding::error::ErrorKind::Decode)?
+        }
+
+        Self::from_bytes(&*private_key.private_key)
+            .ok_or_else(|| crate::encoding::error::ErrorKind::Decode.into())
+    }
+}
+
+#[cfg(all(feature = "jwk", feature = "ed25519-software"))]
+impl FromJwk for Seed {
+    /// Obtain an Ed25519 seed from the `d` member of an `OKP` JWK, returning
+    /// an error if it doesn't match the JWK's public key (i.e. `x`)
+    fn from_jwk(jwk: &Jwk) -> Result<Self, crate::encoding::Error> {
+        let seed = Self::from_bytes(jwk.private_key_for(Algorithm::Ed25519)?)
+            .ok_or(crate::encoding::error::ErrorKind::Decode)?;
+
+        match seed.public_key() {
+            Ok(ref public_key) if public_key.as_ref() == jwk.public_key() => Ok(seed),
+            _ => Err(crate::encoding::error::ErrorKind::Decode.into()),
+        }
+    }
+}
+
+#[cfg(all(feature = "jwk", feature = "ed25519-software"))]
+impl ToJwk for Seed {
+    /// Serialize this seed as an `OKP` JWK containing both the seed (`d`) and
+    /// the derived public key (`x`)
+    fn to_jwk(&self) -> Result<Jwk, crate::encoding::Error> {
+        let public_key = self
+            .public_key()
+            .map_err(|_| crate::encoding::error::ErrorKind::Encode)?;
+
+        Ok(Jwk::new(Algorithm::Ed25519, public_key.as_ref())?
+            .with_private_key(self.as_secret_slice())?
+            .with_thumbprint_kid())
+    }
+}
+
+#[cfg(feature = "ed25519-software")]
+impl PublicKeyed<super::PublicKey> for Seed {
+    /// Derive the Ed25519 public key for this seed
+    fn public_key(&self) -> Result<super::PublicKey, signature::Error> {
+        super::Signer::from(self).public_key()
+    }
+}
+
 impl Drop for Seed {
     fn drop(&mut self) {
         self.0.zeroize();
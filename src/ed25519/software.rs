@@ -0,0 +1,390 @@
+//! Pure-Rust software implementation of Ed25519 built on `curve25519-dalek`
+//!
+//! Supports all three variants described in RFC 8032 Section 5.1:
+//!
+//! - Ed25519 (PureEdDSA): `signature::Signer` / `signature::Verifier`
+//! - Ed25519ctx: `ContextSigner` / `ContextVerifier`
+//! - Ed25519ph: `DigestSigner<Sha512, _>` / `DigestVerifier<Sha512, _>`,
+//!   which allows signing messages incrementally without buffering them
+//!
+//! Signatures are verified according to a configurable [`VerificationPolicy`].
+//!
+//! With the `ed25519-batch` feature, [`Verifier`] also implements
+//! [`BatchVerifier`][crate::batch::BatchVerifier] using a single
+//! multiscalar multiplication for the whole batch.
+
+use super::{
+    ContextSigner, ContextVerifier, PublicKey, Seed, Signature, VerificationPolicy,
+    MAX_CONTEXT_SIZE,
+};
+#[cfg(feature = "ed25519-batch")]
+use crate::batch::{self, BatchError, BatchItem, BatchVerifier};
+use crate::public_key::PublicKeyed;
+#[cfg(feature = "ed25519-batch")]
+use alloc::vec::Vec;
+#[cfg(feature = "ed25519-batch")]
+use curve25519_dalek::{constants::ED25519_BASEPOINT_POINT, traits::VartimeMultiscalarMul};
+use curve25519_dalek::{
+    constants::ED25519_BASEPOINT_TABLE,
+    edwards::{CompressedEdwardsY, EdwardsPoint},
+    scalar::Scalar,
+    traits::IsIdentity,
+};
+use sha2::{Digest, Sha512};
+use signature::{DigestSigner, DigestVerifier, Error};
+use zeroize::Zeroize;
+
+/// Domain separator used by `dom2` for Ed25519ctx and Ed25519ph
+const DOM2_PREFIX: &[u8] = b"SigEd25519 no Ed25519 collisions";
+
+/// Ed25519 variants (RFC 8032 Section 5.1), which determine the `dom2`
+/// prefix of each hash
+#[derive(Copy, Clone, Debug)]
+enum Variant<'a> {
+    /// Ed25519 (PureEdDSA): no prefix
+    Pure,
+
+    /// Ed25519ctx with the given context string
+    Context(&'a [u8]),
+
+    /// Ed25519ph with an empty context string
+    Prehash,
+}
+
+impl<'a> Variant<'a> {
+    /// Create a SHA-512 hasher initialized with this variant's prefix
+    fn hasher(self) -> Result<Sha512, Error> {
+        let mut hasher = Sha512::new();
+
+        match self {
+            Variant::Pure => (),
+            Variant::Context(context) => {
+                if context.is_empty() || context.len() > MAX_CONTEXT_SIZE {
+                    return Err(Error::new());
+                }
+
+                hasher.update(DOM2_PREFIX);
+                hasher.update([0, context.len() as u8]);
+                hasher.update(context);
+            }
+            Variant::Prehash => {
+                hasher.update(DOM2_PREFIX);
+                hasher.update([1u8, 0]);
+            }
+        }
+
+        Ok(hasher)
+    }
+}
+
+/// Ed25519 signer
+pub struct Signer {
+    /// Secret scalar derived from the seed
+    secret_scalar: Scalar,
+
+    /// Prefix hashed with the message to derive nonces
+    nonce_prefix: [u8; 32],
+
+    /// Public key corresponding to the secret scalar
+    public_key: PublicKey,
+}
+
+impl<'a> From<&'a Seed> for Signer {
+    /// Expand the given seed into a secret scalar and nonce prefix
+    fn from(seed: &'a Seed) -> Self {
+        let mut expanded = Sha512::digest(seed.as_secret_slice());
+
+        let mut scalar_bytes = [0u8; 32];
+        scalar_bytes.copy_from_slice(&expanded[..32]);
+        scalar_bytes[0] &= 248;
+        scalar_bytes[31] &= 127;
+        scalar_bytes[31] |= 64;
+
+        let mut nonce_prefix = [0u8; 32];
+        nonce_prefix.copy_from_slice(&expanded[32..]);
+
+        let secret_scalar = Scalar::from_bits(scalar_bytes);
+        let public_key = PublicKey::new((&secret_scalar * &ED25519_BASEPOINT_TABLE).compress().0);
+
+        expanded.as_mut_slice().zeroize();
+        scalar_bytes.zeroize();
+
+        Self {
+            secret_scalar,
+            nonce_prefix,
+            public_key,
+        }
+    }
+}
+
+impl Signer {
+    /// Sign the given message (or prehash) using the given variant
+    fn sign_variant(&self, variant: Variant<'_>, msg: &[u8]) -> Result<Signature, Error> {
+        let mut nonce_hasher = variant.hasher()?;
+        nonce_hasher.update(self.nonce_prefix);
+        nonce_hasher.update(msg);
+        let mut nonce = Scalar::from_hash(nonce_hasher);
+
+        let r = (&nonce * &ED25519_BASEPOINT_TABLE).compress();
+        let k = challenge(variant, &r, &self.public_key, msg)?;
+        let s = k * self.secret_scalar + nonce;
+        nonce.zeroize();
+
+        let mut signature = [0u8; 64];
+        signature[..32].copy_from_slice(r.as_bytes());
+        signature[32..].copy_from_slice(s.as_bytes());
+        Ok(Signature::new(signature))
+    }
+}
+
+impl PublicKeyed<PublicKey> for Signer {
+    fn public_key(&self) -> Result<PublicKey, Error> {
+        Ok(self.public_key)
+    }
+}
+
+impl signature::Signer<Signature> for Signer {
+    fn try_sign(&self, msg: &[u8]) -> Result<Signature, Error> {
+        self.sign_variant(Variant::Pure, msg)
+    }
+}
+
+impl ContextSigner for Signer {
+    fn try_sign_with_context(&self, context: &[u8], msg: &[u8]) -> Result<Signature, Error> {
+        self.sign_variant(Variant::Context(context), msg)
+    }
+}
+
+impl DigestSigner<Sha512, Signature> for Signer {
+    fn try_sign_digest(&self, digest: Sha512) -> Result<Signature, Error> {
+        self.sign_variant(Variant::Prehash, &digest.finalize())
+    }
+}
+
+impl Drop for Signer {
+    fn drop(&mut self) {
+        self.secret_scalar.zeroize();
+        self.nonce_prefix.zeroize();
+    }
+}
+
+/// Ed25519 verifier
+///
+/// Signatures are checked according to a [`VerificationPolicy`], which
+/// defaults to [`VerificationPolicy::Rfc8032`] when constructed via `From`.
+#[derive(Clone, Debug)]
+pub struct Verifier {
+    /// Public key being verified against
+    public_key: PublicKey,
+
+    /// Negated public key point, or `None` if the public key isn't a valid
+    /// point (in which case all signatures are rejected)
+    minus_a: Option<EdwardsPoint>,
+
+    /// Rules for which signatures are valid
+    policy: VerificationPolicy,
+}
+
+impl<'a> From<&'a PublicKey> for Verifier {
+    fn from(public_key: &'a PublicKey) -> Self {
+        Self::new(public_key, VerificationPolicy::Rfc8032)
+    }
+}
+
+impl Verifier {
+    /// Create a new verifier for the given public key which checks signatures
+    /// according to the given policy
+    pub fn new(public_key: &PublicKey, policy: VerificationPolicy) -> Self {
+        Self {
+            public_key: *public_key,
+            minus_a: CompressedEdwardsY(public_key.0)
+                .decompress()
+                .map(|point| -point),
+            policy,
+        }
+    }
+
+    /// Get the policy this verifier checks signatures against
+    pub fn policy(&self) -> VerificationPolicy {
+        self.policy
+    }
+
+    /// Verify the given signature of the given message (or prehash) using the
+    /// given variant
+    fn verify_variant(
+        &self,
+        variant: Variant<'_>,
+        msg: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error> {
+        self.policy.check_encodings(&self.public_key, signature)?;
+        let minus_a = self.minus_a.as_ref().ok_or_else(Error::new)?;
+
+        let bytes = signature.as_ref();
+        let r = CompressedEdwardsY::from_slice(&bytes[..32]);
+
+        let mut s_bytes = [0u8; 32];
+        s_bytes.copy_from_slice(&bytes[32..]);
+        let s = Scalar::from_canonical_bytes(s_bytes).ok_or_else(Error::new)?;
+
+        let k = challenge(variant, &r, &self.public_key, msg)?;
+
+        // Compute `[s]B - [k]A`, which should equal `R`
+        let expected_r = EdwardsPoint::vartime_double_scalar_mul_basepoint(&k, minus_a, &s);
+
+        let valid = if self.policy.is_cofactored() {
+            r.decompress()
+                .map(|r_point| (expected_r - r_point).mul_by_cofactor().is_identity())
+                .unwrap_or(false)
+        } else {
+            expected_r.compress() == r
+        };
+
+        if valid {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+impl signature::Verifier<Signature> for Verifier {
+    fn verify(&self, msg: &[u8], signature: &Signature) -> Result<(), Error> {
+        self.verify_variant(Variant::Pure, msg, signature)
+    }
+}
+
+impl ContextVerifier for Verifier {
+    fn verify_with_context(
+        &self,
+        context: &[u8],
+        msg: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error> {
+        self.verify_variant(Variant::Context(context), msg, signature)
+    }
+}
+
+impl DigestVerifier<Sha512, Signature> for Verifier {
+    fn verify_digest(&self, digest: Sha512, signature: &Signature) -> Result<(), Error> {
+        self.verify_variant(Variant::Prehash, &digest.finalize(), signature)
+    }
+}
+
+#[cfg(feature = "ed25519-batch")]
+impl BatchVerifier<PublicKey, Signature> for Verifier {
+    /// Verify the batch according to [`VerificationPolicy::Rfc8032`] using
+    /// the cofactored batch equation, falling back to verifying each
+    /// signature individually to identify failures.
+    ///
+    /// Since the policy's verification equation is also cofactored, a batch
+    /// passes if and only if every signature in it would pass individually
+    /// (with overwhelming probability).
+    fn verify_batch(items: &[BatchItem<'_, PublicKey, Signature>]) -> Result<(), BatchError> {
+        if items.len() > 1 && batch_equation_holds(items) {
+            Ok(())
+        } else {
+            batch::verify_sequentially::<_, _, Self>(items)
+        }
+    }
+}
+
+/// Check the cofactored batch equation
+/// `[8](∑ z_i⋅R_i + ∑ (z_i⋅k_i)⋅A_i - (∑ z_i⋅s_i)⋅B) == 0`
+///
+/// The `z_i` coefficients are derived by hashing every item in the batch, so
+/// they can't be predicted by whoever produced the signatures.
+#[cfg(feature = "ed25519-batch")]
+fn batch_equation_holds(items: &[BatchItem<'_, PublicKey, Signature>]) -> bool {
+    let mut decoded = Vec::with_capacity(items.len());
+    let mut transcript = Sha512::new();
+
+    for item in items {
+        if VerificationPolicy::Rfc8032
+            .check_encodings(item.public_key, item.signature)
+            .is_err()
+        {
+            return false;
+        }
+
+        let bytes = item.signature.as_ref();
+        let r = CompressedEdwardsY::from_slice(&bytes[..32]);
+
+        let r_point = match r.decompress() {
+            Some(point) => point,
+            None => return false,
+        };
+
+        let a_point = match CompressedEdwardsY(item.public_key.0).decompress() {
+            Some(point) => point,
+            None => return false,
+        };
+
+        let mut s_bytes = [0u8; 32];
+        s_bytes.copy_from_slice(&bytes[32..]);
+
+        let s = match Scalar::from_canonical_bytes(s_bytes) {
+            Some(s) => s,
+            None => return false,
+        };
+
+        let k = match challenge(Variant::Pure, &r, item.public_key, item.msg) {
+            Ok(k) => k,
+            Err(_) => return false,
+        };
+
+        transcript.update(r.as_bytes());
+        transcript.update(item.public_key.as_bytes());
+        transcript.update(s.as_bytes());
+        transcript.update(k.as_bytes());
+        decoded.push((r_point, a_point, s, k));
+    }
+
+    let mut scalars = Vec::with_capacity(decoded.len() * 2 + 1);
+    let mut points = Vec::with_capacity(scalars.capacity());
+    let mut b_coefficient = Scalar::zero();
+
+    for (i, (r_point, a_point, s, k)) in decoded.into_iter().enumerate() {
+        let z = batch_coefficient(&transcript, i);
+        b_coefficient -= z * s;
+        scalars.push(z);
+        points.push(r_point);
+        scalars.push(z * k);
+        points.push(a_point);
+    }
+
+    scalars.push(b_coefficient);
+    points.push(ED25519_BASEPOINT_POINT);
+
+    EdwardsPoint::vartime_multiscalar_mul(scalars, points)
+        .mul_by_cofactor()
+        .is_identity()
+}
+
+/// Derive the 128-bit coefficient for the `i`th item in a batch from the
+/// batch transcript
+#[cfg(feature = "ed25519-batch")]
+fn batch_coefficient(transcript: &Sha512, i: usize) -> Scalar {
+    let digest = transcript
+        .clone()
+        .chain((i as u64).to_le_bytes())
+        .finalize();
+
+    let mut z_bytes = [0u8; 32];
+    z_bytes[..16].copy_from_slice(&digest[..16]);
+    Scalar::from_bits(z_bytes)
+}
+
+/// Compute the challenge scalar `k = H(dom2 || R || A || M)`
+fn challenge(
+    variant: Variant<'_>,
+    r: &CompressedEdwardsY,
+    public_key: &PublicKey,
+    msg: &[u8],
+) -> Result<Scalar, Error> {
+    let mut hasher = variant.hasher()?;
+    hasher.update(r.as_bytes());
+    hasher.update(public_key.as_bytes());
+    hasher.update(msg);
+    Ok(Scalar::from_hash(hasher))
+}
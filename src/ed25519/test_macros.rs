@@ -1,10 +1,14 @@
 //! Macro for generating shared tests for all software Ed25519 implementations
 
 /// Generate tests for Ed25519
+///
+/// Optionally takes the name of the `ed25519::VerificationPolicy` the
+/// verifier implements, in which case it's checked against every edge case
+/// vector (otherwise only the ones all policies agree on are checked).
 #[macro_export]
 macro_rules! ed25519_tests {
     ($signer:ident, $verifier:ident) => {
-        use $crate::ed25519::{self, SIGNATURE_SIZE, TEST_VECTORS};
+        use $crate::ed25519::{self, ED25519_EDGE_CASE_VECTORS, SIGNATURE_SIZE, TEST_VECTORS};
         use $crate::signature::{Signer as _, Verifier as _};
 
         #[test]
@@ -52,5 +56,250 @@ macro_rules! ed25519_tests {
                 );
             }
         }
+
+        #[test]
+        fn verify_unambiguous_edge_case_vectors() {
+            use $crate::signature::Signature;
+            for vector in ED25519_EDGE_CASE_VECTORS {
+                if !vector.is_unambiguous() {
+                    continue;
+                }
+
+                let pk = ed25519::PublicKey::from_bytes(vector.pk).unwrap();
+                let verifier = $verifier::from(&pk);
+                let sig = ed25519::Signature::from_bytes(vector.sig).unwrap();
+                assert_eq!(
+                    verifier.verify(vector.msg, &sig).is_ok(),
+                    vector.rfc8032,
+                    "unexpected result for edge case: {}",
+                    vector.description
+                );
+            }
+        }
+    };
+    ($signer:ident, $verifier:ident, $policy:ident) => {
+        $crate::ed25519_tests!($signer, $verifier);
+
+        #[test]
+        fn verify_edge_case_vectors() {
+            use $crate::signature::Signature;
+            let policy = ed25519::VerificationPolicy::$policy;
+
+            for vector in ED25519_EDGE_CASE_VECTORS {
+                let pk = ed25519::PublicKey::from_bytes(vector.pk).unwrap();
+                let verifier = $verifier::from(&pk);
+                let sig = ed25519::Signature::from_bytes(vector.sig).unwrap();
+                assert_eq!(
+                    verifier.verify(vector.msg, &sig).is_ok(),
+                    vector.expected(policy),
+                    "unexpected result for edge case under {:?}: {}",
+                    policy,
+                    vector.description
+                );
+            }
+        }
+    };
+    ($signer:ident, $verifier:ident, $policy:ident, ed25519ctx, ed25519ph) => {
+        $crate::ed25519_tests!($signer, $verifier, $policy);
+
+        use $crate::ed25519::{
+            ContextSigner as _, ContextVerifier as _, ED25519CTX_TEST_VECTORS,
+            ED25519PH_TEST_VECTORS, MAX_CONTEXT_SIZE,
+        };
+        use $crate::sha2::{Digest as _, Sha512};
+        use $crate::signature::{DigestSigner as _, DigestVerifier as _};
+
+        #[test]
+        fn sign_rfc8032_ed25519ctx_test_vectors() {
+            for ctx_vector in ED25519CTX_TEST_VECTORS {
+                let vector = &ctx_vector.vector;
+                let seed = ed25519::Seed::from_bytes(vector.sk).unwrap();
+                let signer = $signer::from(&seed);
+                assert_eq!(
+                    signer
+                        .sign_with_context(ctx_vector.ctx, vector.msg)
+                        .as_ref(),
+                    vector.sig
+                );
+            }
+        }
+
+        #[test]
+        fn verify_rfc8032_ed25519ctx_test_vectors() {
+            use $crate::signature::Signature;
+            for ctx_vector in ED25519CTX_TEST_VECTORS {
+                let vector = &ctx_vector.vector;
+                let pk = ed25519::PublicKey::from_bytes(vector.pk).unwrap();
+                let verifier = $verifier::from(&pk);
+                let sig = ed25519::Signature::from_bytes(vector.sig).unwrap();
+                assert!(
+                    verifier
+                        .verify_with_context(ctx_vector.ctx, vector.msg, &sig)
+                        .is_ok(),
+                    "expected signature to verify"
+                );
+            }
+        }
+
+        #[test]
+        fn rejects_ed25519ctx_signature_with_wrong_context() {
+            use $crate::signature::Signature;
+            for ctx_vector in ED25519CTX_TEST_VECTORS {
+                let vector = &ctx_vector.vector;
+                let pk = ed25519::PublicKey::from_bytes(vector.pk).unwrap();
+                let verifier = $verifier::from(&pk);
+                let sig = ed25519::Signature::from_bytes(vector.sig).unwrap();
+
+                assert!(
+                    verifier
+                        .verify_with_context(b"wrong", vector.msg, &sig)
+                        .is_err(),
+                    "expected signature with wrong context to fail"
+                );
+
+                assert!(
+                    verifier.verify(vector.msg, &sig).is_err(),
+                    "expected Ed25519ctx signature to fail as pure Ed25519"
+                );
+            }
+        }
+
+        #[test]
+        fn rejects_invalid_ed25519ctx_contexts() {
+            let vector = &ED25519CTX_TEST_VECTORS[0].vector;
+            let seed = ed25519::Seed::from_bytes(vector.sk).unwrap();
+            let signer = $signer::from(&seed);
+            let oversized_context = [0u8; MAX_CONTEXT_SIZE + 1];
+
+            assert!(signer.try_sign_with_context(b"", vector.msg).is_err());
+            assert!(signer
+                .try_sign_with_context(&oversized_context, vector.msg)
+                .is_err());
+            assert!(signer
+                .try_sign_with_context(&oversized_context[..MAX_CONTEXT_SIZE], vector.msg)
+                .is_ok());
+        }
+
+        #[test]
+        fn sign_rfc8032_ed25519ph_test_vectors() {
+            for vector in ED25519PH_TEST_VECTORS {
+                let seed = ed25519::Seed::from_bytes(vector.sk).unwrap();
+                let signer = $signer::from(&seed);
+                let sig = signer.sign_digest(Sha512::new().chain(vector.msg));
+                assert_eq!(sig.as_ref(), vector.sig);
+            }
+        }
+
+        #[test]
+        fn verify_rfc8032_ed25519ph_test_vectors() {
+            use $crate::signature::Signature;
+            for vector in ED25519PH_TEST_VECTORS {
+                let pk = ed25519::PublicKey::from_bytes(vector.pk).unwrap();
+                let verifier = $verifier::from(&pk);
+                let sig = ed25519::Signature::from_bytes(vector.sig).unwrap();
+                assert!(
+                    verifier
+                        .verify_digest(Sha512::new().chain(vector.msg), &sig)
+                        .is_ok(),
+                    "expected signature to verify"
+                );
+            }
+        }
+
+        #[test]
+        fn rejects_tweaked_rfc8032_ed25519ph_test_vectors() {
+            use $crate::signature::Signature;
+            for vector in ED25519PH_TEST_VECTORS {
+                let pk = ed25519::PublicKey::from_bytes(vector.pk).unwrap();
+                let verifier = $verifier::from(&pk);
+
+                let mut tweaked_sig = [0u8; SIGNATURE_SIZE];
+                tweaked_sig.copy_from_slice(vector.sig);
+                tweaked_sig[0] ^= 0x42;
+
+                let result = verifier.verify_digest(
+                    Sha512::new().chain(vector.msg),
+                    &ed25519::Signature::from_bytes(&tweaked_sig[..]).unwrap(),
+                );
+
+                assert!(
+                    result.is_err(),
+                    "expected signature verification failure but it succeeded"
+                );
+
+                let sig = ed25519::Signature::from_bytes(vector.sig).unwrap();
+                assert!(
+                    verifier.verify(vector.msg, &sig).is_err(),
+                    "expected Ed25519ph signature to fail as pure Ed25519"
+                );
+            }
+        }
+    };
+}
+
+/// Generate tests for Ed25519 batch verification
+#[macro_export]
+macro_rules! ed25519_batch_tests {
+    ($verifier:ident) => {
+        #[test]
+        fn batch_verify_rfc8032_test_vectors() {
+            use $crate::batch::{BatchItem, BatchVerifier};
+            use $crate::ed25519::{PublicKey, Signature, TEST_VECTORS};
+            use $crate::signature::Signature as _;
+
+            let keys_and_sigs = TEST_VECTORS
+                .iter()
+                .map(|vector| {
+                    (
+                        PublicKey::from_bytes(vector.pk).unwrap(),
+                        Signature::from_bytes(vector.sig).unwrap(),
+                    )
+                })
+                .collect::<::std::vec::Vec<_>>();
+
+            let items = TEST_VECTORS
+                .iter()
+                .zip(&keys_and_sigs)
+                .map(|(vector, (pk, sig))| BatchItem::new(pk, vector.msg, sig))
+                .collect::<::std::vec::Vec<_>>();
+
+            assert_eq!($verifier::verify_batch(&items), Ok(()));
+            assert_eq!($verifier::verify_batch(&[]), Ok(()));
+        }
+
+        #[test]
+        fn batch_verify_reports_failed_entries() {
+            use $crate::batch::{BatchItem, BatchVerifier};
+            use $crate::ed25519::{PublicKey, Signature, TEST_VECTORS};
+            use $crate::signature::Signature as _;
+
+            let keys_and_sigs = TEST_VECTORS
+                .iter()
+                .map(|vector| {
+                    (
+                        PublicKey::from_bytes(vector.pk).unwrap(),
+                        Signature::from_bytes(vector.sig).unwrap(),
+                    )
+                })
+                .collect::<::std::vec::Vec<_>>();
+
+            let items = TEST_VECTORS
+                .iter()
+                .zip(&keys_and_sigs)
+                .enumerate()
+                .map(|(i, (vector, (pk, sig)))| {
+                    let msg: &[u8] = if i == 1 || i == 3 {
+                        b"tampered message"
+                    } else {
+                        vector.msg
+                    };
+
+                    BatchItem::new(pk, msg, sig)
+                })
+                .collect::<::std::vec::Vec<_>>();
+
+            let err = $verifier::verify_batch(&items).unwrap_err();
+            assert_eq!(err.failed(), &[1, 3]);
+        }
     };
 }
@@ -1,4 +1,4 @@
-use crate::test_vector::{TestVector, TestVectorAlgorithm};
+use crate::test_vector::{ContextTestVector, EdgeCaseTestVector, TestVector, TestVectorAlgorithm};
 
 /// Ed25519 test vectors (from RFC 8032, converted to Rust bytestring literals)
 #[rustfmt::skip]
@@ -49,3 +49,186 @@ pub const TEST_VECTORS: &[TestVector] = &[
         pass: true
     },
 ];
+
+/// Ed25519ctx test vectors (from RFC 8032 Section 7.2)
+#[rustfmt::skip]
+pub const ED25519CTX_TEST_VECTORS: &[ContextTestVector] = &[
+    ContextTestVector {
+        ctx: b"\x66\x6F\x6F",
+        vector: TestVector {
+            alg: TestVectorAlgorithm::Ed25519ctx,
+            sk: b"\x03\x05\x33\x4E\x38\x1A\xF7\x8F\x14\x1C\xB6\x66\xF6\x19\x9F\x57\xBC\x34\x95\x33\x5A\x25\x6A\x95\xBD\x2A\x55\xBF\x54\x66\x63\xF6",
+            pk: b"\xDF\xC9\x42\x5E\x4F\x96\x8F\x7F\x0C\x29\xF0\x25\x9C\xF5\xF9\xAE\xD6\x85\x1C\x2B\xB4\xAD\x8B\xFB\x86\x0C\xFE\xE0\xAB\x24\x82\x92",
+            nonce: None,
+            msg: b"\xF7\x26\x93\x6D\x19\xC8\x00\x49\x4E\x3F\xDA\xFF\x20\xB2\x76\xA8",
+            sig: b"\x55\xA4\xCC\x2F\x70\xA5\x4E\x04\x28\x8C\x5F\x4C\xD1\xE4\x5A\x7B\xB5\x20\xB3\x62\x92\x91\x18\x76\xCA\xDA\x73\x23\x19\x8D\xD8\x7A\x8B\x36\x95\x0B\x95\x13\x00\x22\x90\x7A\x7F\xB7\xC4\xE9\xB2\xD5\xF6\xCC\xA6\x85\xA5\x87\xB4\xB2\x1F\x4B\x88\x8E\x4E\x7E\xDB\x0D",
+            pass: true
+        },
+    },
+    ContextTestVector {
+        ctx: b"\x62\x61\x72",
+        vector: TestVector {
+            alg: TestVectorAlgorithm::Ed25519ctx,
+            sk: b"\x03\x05\x33\x4E\x38\x1A\xF7\x8F\x14\x1C\xB6\x66\xF6\x19\x9F\x57\xBC\x34\x95\x33\x5A\x25\x6A\x95\xBD\x2A\x55\xBF\x54\x66\x63\xF6",
+            pk: b"\xDF\xC9\x42\x5E\x4F\x96\x8F\x7F\x0C\x29\xF0\x25\x9C\xF5\xF9\xAE\xD6\x85\x1C\x2B\xB4\xAD\x8B\xFB\x86\x0C\xFE\xE0\xAB\x24\x82\x92",
+            nonce: None,
+            msg: b"\xF7\x26\x93\x6D\x19\xC8\x00\x49\x4E\x3F\xDA\xFF\x20\xB2\x76\xA8",
+            sig: b"\xFC\x60\xD5\x87\x2F\xC4\x6B\x3A\xA6\x9F\x8B\x5B\x43\x51\xD5\x80\x8F\x92\xBC\xC0\x44\x60\x6D\xB0\x97\xAB\xAB\x6D\xBC\xB1\xAE\xE3\x21\x6C\x48\xE8\xB3\xB6\x64\x31\xB5\xB1\x86\xD1\xD2\x8F\x8E\xE1\x5A\x5C\xA2\xDF\x66\x68\x34\x62\x91\xC2\x04\x3D\x4E\xB3\xE9\x0D",
+            pass: true
+        },
+    },
+    ContextTestVector {
+        ctx: b"\x66\x6F\x6F",
+        vector: TestVector {
+            alg: TestVectorAlgorithm::Ed25519ctx,
+            sk: b"\x03\x05\x33\x4E\x38\x1A\xF7\x8F\x14\x1C\xB6\x66\xF6\x19\x9F\x57\xBC\x34\x95\x33\x5A\x25\x6A\x95\xBD\x2A\x55\xBF\x54\x66\x63\xF6",
+            pk: b"\xDF\xC9\x42\x5E\x4F\x96\x8F\x7F\x0C\x29\xF0\x25\x9C\xF5\xF9\xAE\xD6\x85\x1C\x2B\xB4\xAD\x8B\xFB\x86\x0C\xFE\xE0\xAB\x24\x82\x92",
+            nonce: None,
+            msg: b"\x50\x8E\x9E\x68\x82\xB9\x79\xFE\xA9\x00\xF6\x2A\xDC\xEA\xCA\x35",
+            sig: b"\x8B\x70\xC1\xCC\x83\x10\xE1\xDE\x20\xAC\x53\xCE\x28\xAE\x6E\x72\x07\xF3\x3C\x32\x95\xE0\x3B\xB5\xC0\x73\x2A\x1D\x20\xDC\x64\x90\x89\x22\xA8\xB0\x52\xCF\x99\xB7\xC4\xFE\x10\x7A\x5A\xBB\x5B\x2C\x40\x85\xAE\x75\x89\x0D\x02\xDF\x26\x26\x9D\x89\x45\xF8\x4B\x0B",
+            pass: true
+        },
+    },
+    ContextTestVector {
+        ctx: b"\x66\x6F\x6F",
+        vector: TestVector {
+            alg: TestVectorAlgorithm::Ed25519ctx,
+            sk: b"\xAB\x9C\x28\x53\xCE\x29\x7D\xDA\xB8\x5C\x99\x3B\x3A\xE1\x4B\xCA\xD3\x9B\x2C\x68\x2B\xEA\xBC\x27\xD6\xD4\xEB\x20\x71\x1D\x65\x60",
+            pk: b"\x0F\x1D\x12\x74\x94\x3B\x91\x41\x58\x89\x15\x2E\x89\x3D\x80\xE9\x32\x75\xA1\xFC\x0B\x65\xFD\x71\xB4\xB0\xDD\xA1\x0A\xD7\xD7\x72",
+            nonce: None,
+            msg: b"\xF7\x26\x93\x6D\x19\xC8\x00\x49\x4E\x3F\xDA\xFF\x20\xB2\x76\xA8",
+            sig: b"\x21\x65\x5B\x5F\x1A\xA9\x65\x99\x6B\x3F\x97\xB3\xC8\x49\xEA\xFB\xA9\x22\xA0\xA6\x29\x92\xF7\x3B\x3D\x1B\x73\x10\x6A\x84\xAD\x85\xE9\xB8\x6A\x7B\x60\x05\xEA\x86\x83\x37\xFF\x2D\x20\xA7\xF5\xFB\xD4\xCD\x10\xB0\xBE\x49\xA6\x8D\xA2\xB2\xE0\xDC\x0A\xD8\x96\x0F",
+            pass: true
+        },
+    },
+];
+
+/// Ed25519ph test vectors (from RFC 8032 Section 7.3)
+///
+/// The `msg` field contains the message prior to being hashed with SHA-512
+#[rustfmt::skip]
+pub const ED25519PH_TEST_VECTORS: &[TestVector] = &[
+    TestVector {
+        alg: TestVectorAlgorithm::Ed25519ph,
+        sk: b"\x83\x3F\xE6\x24\x09\x23\x7B\x9D\x62\xEC\x77\x58\x75\x20\x91\x1E\x9A\x75\x9C\xEC\x1D\x19\x75\x5B\x7D\xA9\x01\xB9\x6D\xCA\x3D\x42",
+        pk: b"\xEC\x17\x2B\x93\xAD\x5E\x56\x3B\xF4\x93\x2C\x70\xE1\x24\x50\x34\xC3\x54\x67\xEF\x2E\xFD\x4D\x64\xEB\xF8\x19\x68\x34\x67\xE2\xBF",
+        nonce: None,
+        msg: b"\x61\x62\x63",
+        sig: b"\x98\xA7\x02\x22\xF0\xB8\x12\x1A\xA9\xD3\x0F\x81\x3D\x68\x3F\x80\x9E\x46\x2B\x46\x9C\x7F\xF8\x76\x39\x49\x9B\xB9\x4E\x6D\xAE\x41\x31\xF8\x50\x42\x46\x3C\x2A\x35\x5A\x20\x03\xD0\x62\xAD\xF5\xAA\xA1\x0B\x8C\x61\xE6\x36\x06\x2A\xAA\xD1\x1C\x2A\x26\x08\x34\x06",
+        pass: true
+    },
+];
+
+/// Ed25519 verification edge cases, with the expected outcome under each
+/// `VerificationPolicy`
+#[rustfmt::skip]
+pub const ED25519_EDGE_CASE_VECTORS: &[EdgeCaseTestVector] = &[
+    EdgeCaseTestVector {
+        description: "valid signature",
+        pk: b"\x03\xA1\x07\xBF\xF3\xCE\x10\xBE\x1D\x70\xDD\x18\xE7\x4B\xC0\x99\x67\xE4\xD6\x30\x9B\xA5\x0D\x5F\x1D\xDC\x86\x64\x12\x55\x31\xB8",
+        msg: b"\x65\x64\x67\x65\x20\x63\x61\x73\x65\x3A\x20\x76\x61\x6C\x69\x64",
+        sig: b"\xA4\xAC\xE8\x7C\x19\xF1\xD1\x24\xC2\x6A\x05\x57\x6F\x0F\x76\xAA\xD6\xC5\x61\x8C\xC1\x06\xCF\xE5\xBF\x00\xC5\x4A\x1D\xBD\x16\x90\xBD\x06\x4F\x96\xCB\x7D\x11\xAC\xF0\x0C\x41\xF8\x70\xA8\x44\xDD\xA5\xC9\x08\xC0\x0D\xCA\x97\xBC\xEE\x95\x25\x2A\x02\x23\x03\x01",
+        rfc8032: true,
+        zip215: true,
+        libsodium: true,
+    },
+    EdgeCaseTestVector {
+        description: "S >= L (S + L of a valid signature)",
+        pk: b"\x03\xA1\x07\xBF\xF3\xCE\x10\xBE\x1D\x70\xDD\x18\xE7\x4B\xC0\x99\x67\xE4\xD6\x30\x9B\xA5\x0D\x5F\x1D\xDC\x86\x64\x12\x55\x31\xB8",
+        msg: b"\x65\x64\x67\x65\x20\x63\x61\x73\x65\x3A\x20\x76\x61\x6C\x69\x64",
+        sig: b"\xA4\xAC\xE8\x7C\x19\xF1\xD1\x24\xC2\x6A\x05\x57\x6F\x0F\x76\xAA\xD6\xC5\x61\x8C\xC1\x06\xCF\xE5\xBF\x00\xC5\x4A\x1D\xBD\x16\x90\xAA\xDA\x44\xF3\xE5\xE0\x23\x04\xC7\xA9\x38\x9B\x4F\xA2\x23\xF2\xA5\xC9\x08\xC0\x0D\xCA\x97\xBC\xEE\x95\x25\x2A\x02\x23\x03\x11",
+        rfc8032: false,
+        zip215: false,
+        libsodium: false,
+    },
+    EdgeCaseTestVector {
+        description: "small-order A and R with S = 0",
+        pk: b"\xC7\x17\x6A\x70\x3D\x4D\xD8\x4F\xBA\x3C\x0B\x76\x0D\x10\x67\x0F\x2A\x20\x53\xFA\x2C\x39\xCC\xC6\x4E\xC7\xFD\x77\x92\xAC\x03\x7A",
+        msg: b"\x73\x6D\x61\x6C\x6C\x2D\x6F\x72\x64\x65\x72\x20\x41\x20\x61\x6E\x64\x20\x52\x20\x30",
+        sig: b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x80\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+        rfc8032: true,
+        zip215: true,
+        libsodium: false,
+    },
+    EdgeCaseTestVector {
+        description: "mixed-order A (cofactored equation only)",
+        pk: b"\xB5\x02\xFF\x3D\x92\xE3\x1D\x81\x90\xB4\xAA\x4E\xA0\x41\x40\x05\x16\x7F\xAD\x08\x9C\x4D\xE9\xDA\xC8\xA2\xFC\x85\x0F\xED\x4F\x58",
+        msg: b"\x6D\x69\x78\x65\x64\x2D\x6F\x72\x64\x65\x72\x20\x41\x20\x30",
+        sig: b"\xEF\x4F\x62\xF8\x47\x97\x33\xAD\x87\x9C\xFA\xCE\xD3\xC8\x9A\x9C\x39\xDD\x4F\xC7\x95\xEF\x2E\xFA\x1C\x3E\xAF\xE4\xD7\x29\xA0\x81\x5B\xE0\x89\x5A\x41\x34\xDF\x05\xCF\xA2\x79\xA5\x68\xED\xCE\x29\xB6\x57\xFB\x7E\xC5\xE9\x9D\xC2\xDF\x32\xFD\x19\xD0\x32\xB1\x00",
+        rfc8032: true,
+        zip215: true,
+        libsodium: false,
+    },
+    EdgeCaseTestVector {
+        description: "mixed-order A (cofactorless equation holds)",
+        pk: b"\xB5\x02\xFF\x3D\x92\xE3\x1D\x81\x90\xB4\xAA\x4E\xA0\x41\x40\x05\x16\x7F\xAD\x08\x9C\x4D\xE9\xDA\xC8\xA2\xFC\x85\x0F\xED\x4F\x58",
+        msg: b"\x6D\x69\x78\x65\x64\x2D\x6F\x72\x64\x65\x72\x20\x41\x20\x31",
+        sig: b"\xEF\x4F\x62\xF8\x47\x97\x33\xAD\x87\x9C\xFA\xCE\xD3\xC8\x9A\x9C\x39\xDD\x4F\xC7\x95\xEF\x2E\xFA\x1C\x3E\xAF\xE4\xD7\x29\xA0\x81\xEC\x3A\xD0\xD4\x0F\xFD\xF7\x54\x49\x31\x43\x03\x49\xD7\xE6\x1E\x3A\xE6\xFC\x00\x7F\x8E\x0A\xD9\xAE\xF5\x72\x31\x6D\x0F\xA8\x0C",
+        rfc8032: true,
+        zip215: true,
+        libsodium: true,
+    },
+    EdgeCaseTestVector {
+        description: "small-order R (identity)",
+        pk: b"\x03\xA1\x07\xBF\xF3\xCE\x10\xBE\x1D\x70\xDD\x18\xE7\x4B\xC0\x99\x67\xE4\xD6\x30\x9B\xA5\x0D\x5F\x1D\xDC\x86\x64\x12\x55\x31\xB8",
+        msg: b"\x69\x64\x65\x6E\x74\x69\x74\x79\x20\x52",
+        sig: b"\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x3B\xC8\x1F\x02\x20\x77\x0A\x3F\x2C\x47\xB8\x00\xB2\x60\xFC\xD8\x6C\xEE\xAF\x22\xD6\x69\x60\x4E\xE4\x6F\xB4\x62\xE2\xAB\xDA\x07",
+        rfc8032: true,
+        zip215: true,
+        libsodium: false,
+    },
+    EdgeCaseTestVector {
+        description: "small-order R (order 8)",
+        pk: b"\x03\xA1\x07\xBF\xF3\xCE\x10\xBE\x1D\x70\xDD\x18\xE7\x4B\xC0\x99\x67\xE4\xD6\x30\x9B\xA5\x0D\x5F\x1D\xDC\x86\x64\x12\x55\x31\xB8",
+        msg: b"\x6F\x72\x64\x65\x72\x2D\x38\x20\x52",
+        sig: b"\xC7\x17\x6A\x70\x3D\x4D\xD8\x4F\xBA\x3C\x0B\x76\x0D\x10\x67\x0F\x2A\x20\x53\xFA\x2C\x39\xCC\xC6\x4E\xC7\xFD\x77\x92\xAC\x03\x7A\x23\x67\xC0\xC4\x2B\xC7\xF6\x8D\x0F\x3E\x1D\x14\x7B\x9F\x93\xCC\xC3\x74\x5D\x78\x8F\x14\x8B\x66\x73\xEB\x8C\xB1\x51\xA2\x3B\x05",
+        rfc8032: true,
+        zip215: true,
+        libsodium: false,
+    },
+    EdgeCaseTestVector {
+        description: "non-canonical R (y = p + 1)",
+        pk: b"\x03\xA1\x07\xBF\xF3\xCE\x10\xBE\x1D\x70\xDD\x18\xE7\x4B\xC0\x99\x67\xE4\xD6\x30\x9B\xA5\x0D\x5F\x1D\xDC\x86\x64\x12\x55\x31\xB8",
+        msg: b"\x6E\x6F\x6E\x2D\x63\x61\x6E\x6F\x6E\x69\x63\x61\x6C\x20\x52",
+        sig: b"\xEE\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x7F\x68\xED\xBD\x2D\x98\x89\x97\x74\xCA\x6B\x4F\x46\x9D\x63\x35\x41\x66\x9B\xFF\x08\xBC\x0C\xE0\x49\xD9\x4F\x59\x3E\x78\x3A\xA2\x01",
+        rfc8032: false,
+        zip215: true,
+        libsodium: false,
+    },
+    EdgeCaseTestVector {
+        description: "non-canonical R (identity with sign bit set)",
+        pk: b"\x03\xA1\x07\xBF\xF3\xCE\x10\xBE\x1D\x70\xDD\x18\xE7\x4B\xC0\x99\x67\xE4\xD6\x30\x9B\xA5\x0D\x5F\x1D\xDC\x86\x64\x12\x55\x31\xB8",
+        msg: b"\x6E\x65\x67\x61\x74\x69\x76\x65\x20\x7A\x65\x72\x6F\x20\x52",
+        sig: b"\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x80\xB3\x27\x34\x65\xA8\x0B\x35\x2D\x40\x74\x9C\x03\x6D\xCB\x09\xF5\x1F\x34\xE0\xE1\x7D\x6A\xBA\x6E\xF0\x8C\x7C\xC3\x10\xF0\xE4\x0A",
+        rfc8032: false,
+        zip215: true,
+        libsodium: false,
+    },
+    EdgeCaseTestVector {
+        description: "non-canonical A (y = p + 1)",
+        pk: b"\xEE\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x7F",
+        msg: b"\x6E\x6F\x6E\x2D\x63\x61\x6E\x6F\x6E\x69\x63\x61\x6C\x20\x41",
+        sig: b"\xEF\x4F\x62\xF8\x47\x97\x33\xAD\x87\x9C\xFA\xCE\xD3\xC8\x9A\x9C\x39\xDD\x4F\xC7\x95\xEF\x2E\xFA\x1C\x3E\xAF\xE4\xD7\x29\xA0\x81\x39\x30\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+        rfc8032: false,
+        zip215: true,
+        libsodium: false,
+    },
+    EdgeCaseTestVector {
+        description: "small-order A (identity)",
+        pk: b"\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+        msg: b"\x69\x64\x65\x6E\x74\x69\x74\x79\x20\x41",
+        sig: b"\xEF\x4F\x62\xF8\x47\x97\x33\xAD\x87\x9C\xFA\xCE\xD3\xC8\x9A\x9C\x39\xDD\x4F\xC7\x95\xEF\x2E\xFA\x1C\x3E\xAF\xE4\xD7\x29\xA0\x81\x39\x30\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+        rfc8032: true,
+        zip215: true,
+        libsodium: false,
+    },
+    EdgeCaseTestVector {
+        description: "A is not a valid point encoding",
+        pk: b"\x02\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+        msg: b"\x69\x6E\x76\x61\x6C\x69\x64\x20\x41",
+        sig: b"\xEF\x4F\x62\xF8\x47\x97\x33\xAD\x87\x9C\xFA\xCE\xD3\xC8\x9A\x9C\x39\xDD\x4F\xC7\x95\xEF\x2E\xFA\x1C\x3E\xAF\xE4\xD7\x29\xA0\x81\x39\x30\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+        rfc8032: false,
+        zip215: false,
+        libsodium: false,
+    },
+];
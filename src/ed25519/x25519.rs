@@ -0,0 +1,74 @@
+//! X25519 key agreement (RFC 7748) using Ed25519 keys
+//!
+//! Ed25519 keys are converted to their X25519 equivalents using the
+//! birational map from the twisted Edwards form of Curve25519 to its
+//! Montgomery form (`u = (1 + y) / (1 - y)`), as with libsodium's
+//! `crypto_sign_ed25519_pk_to_curve25519` and
+//! `crypto_sign_ed25519_sk_to_curve25519`.
+
+use super::{PublicKey, Seed};
+use crate::ecdh::DiffieHellman;
+use curve25519_dalek::{edwards::CompressedEdwardsY, montgomery::MontgomeryPoint, scalar::Scalar};
+use sha2::{Digest, Sha512};
+use signature::Error;
+use zeroize::{Zeroize, Zeroizing};
+
+/// Size of an X25519 secret key, public key or shared secret in bytes
+pub const X25519_KEY_SIZE: usize = 32;
+
+impl Seed {
+    /// Convert this seed to the equivalent X25519 secret key, i.e. the
+    /// clamped first half of its SHA-512 hash (which is also the Ed25519
+    /// secret scalar)
+    pub fn to_x25519_secret_key(&self) -> Zeroizing<[u8; X25519_KEY_SIZE]> {
+        let mut expanded = Sha512::digest(self.as_secret_slice());
+
+        let mut secret_key = Zeroizing::new([0u8; X25519_KEY_SIZE]);
+        secret_key.copy_from_slice(&expanded[..X25519_KEY_SIZE]);
+        secret_key[0] &= 248;
+        secret_key[31] &= 127;
+        secret_key[31] |= 64;
+
+        expanded.as_mut_slice().zeroize();
+        secret_key
+    }
+}
+
+impl PublicKey {
+    /// Convert this public key to the equivalent X25519 public key (i.e. the
+    /// Montgomery `u`-coordinate), returning an error if it isn't valid
+    /// (see [`PublicKey::is_valid`])
+    pub fn to_x25519_public_key(&self) -> Result<[u8; X25519_KEY_SIZE], Error> {
+        if !self.is_valid() {
+            return Err(Error::new());
+        }
+
+        CompressedEdwardsY(self.0)
+            .decompress()
+            .map(|point| point.to_montgomery().to_bytes())
+            .ok_or_else(Error::new)
+    }
+}
+
+impl DiffieHellman<PublicKey> for Seed {
+    type SharedSecret = Zeroizing<[u8; X25519_KEY_SIZE]>;
+
+    /// Compute the X25519 shared secret with the given Ed25519 public key,
+    /// after converting both keys to X25519. Returns an error if the public
+    /// key isn't valid or the shared secret is all zeroes.
+    fn diffie_hellman(&self, public_key: &PublicKey) -> Result<Self::SharedSecret, Error> {
+        let public_key = MontgomeryPoint(public_key.to_x25519_public_key()?);
+        let mut secret_scalar = Scalar::from_bits(*self.to_x25519_secret_key());
+
+        let mut shared_point = public_key * secret_scalar;
+        let shared_secret = Zeroizing::new(shared_point.to_bytes());
+        secret_scalar.zeroize();
+        shared_point.zeroize();
+
+        if shared_secret.iter().all(|&byte| byte == 0) {
+            return Err(Error::new());
+        }
+
+        Ok(shared_secret)
+    }
+}
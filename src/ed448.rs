@@ -0,0 +1,33 @@
+//! Ed448: Schnorr signatures using the Edwards form of Curve448
+//!
+//! Described in RFC 8032: <https://tools.ietf.org/html/rfc8032>
+//!
+//! Ed448 is the higher security (~224-bit) counterpart to Ed25519. Every
+//! signature is bound to a (possibly empty) context string, and the Ed448ph
+//! variant signs a SHAKE256 prehash of the message instead of the message
+//! itself.
+
+mod context;
+mod public_key;
+mod seed;
+mod signature;
+
+#[cfg(feature = "ed448-software")]
+mod arithmetic;
+#[cfg(feature = "ed448-software")]
+mod software;
+
+/// RFC 8032 Ed448 test vectors
+#[cfg(feature = "test-vectors")]
+mod test_vectors;
+
+#[cfg(feature = "ed448-software")]
+pub use self::software::{Signer, Verifier};
+#[cfg(feature = "test-vectors")]
+pub use self::test_vectors::{ED448PH_TEST_VECTORS, ED448_CONTEXT_TEST_VECTORS, TEST_VECTORS};
+pub use self::{
+    context::{ContextSigner, ContextVerifier, PrehashSigner, PrehashVerifier, MAX_CONTEXT_SIZE},
+    public_key::{PublicKey, PUBLIC_KEY_SIZE},
+    seed::{Seed, SEED_SIZE},
+    signature::{Signature, SIGNATURE_SIZE},
+};
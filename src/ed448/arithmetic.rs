@@ -0,0 +1,578 @@
+//! Arithmetic on the Edwards448 ("Goldilocks") curve used by Ed448
+//!
+//! Field elements and scalars are stored in Montgomery form as eight little
+//! endian 64-bit limbs (leaving headroom above the 448-bit modulus so sums
+//! never overflow). Points use projective coordinates and the complete
+//! addition formula from RFC 8032 Section 5.2.4, so the same code path
+//! handles doubling and the identity.
+//!
+//! Everything which may operate on secret values (scalar arithmetic and
+//! scalar multiplication) is constant time. Point decoding and inversion
+//! exponents only depend on public values.
+
+use subtle::{Choice, ConditionallySelectable};
+use zeroize::Zeroize;
+
+/// Number of 64-bit limbs in a field element or scalar
+const LIMBS: usize = 8;
+
+/// Size of a serialized field element in bytes
+const FIELD_ELEMENT_SIZE: usize = 56;
+
+/// Size of a serialized scalar or point in bytes
+pub(super) const ENCODED_SIZE: usize = 57;
+
+/// Multi-limb integer (little endian)
+type Limbs = [u64; LIMBS];
+
+/// The integer one
+const ONE: Limbs = [1, 0, 0, 0, 0, 0, 0, 0];
+
+/// Prime modulus and the constants needed for Montgomery arithmetic with it
+struct Modulus {
+    /// Modulus
+    value: Limbs,
+
+    /// `R^2 mod m`, where `R = 2^512` (used to convert into Montgomery form)
+    r2: Limbs,
+
+    /// `-m^-1 mod 2^64`
+    inv: u64,
+}
+
+/// Field modulus: `p = 2^448 - 2^224 - 1`
+const FIELD_MODULUS: Modulus = Modulus {
+    value: [
+        0xffff_ffff_ffff_ffff,
+        0xffff_ffff_ffff_ffff,
+        0xffff_ffff_ffff_ffff,
+        0xffff_fffe_ffff_ffff,
+        0xffff_ffff_ffff_ffff,
+        0xffff_ffff_ffff_ffff,
+        0xffff_ffff_ffff_ffff,
+        0x0000_0000_0000_0000,
+    ],
+    r2: [
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0002,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0003_0000_0000,
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0000,
+    ],
+    inv: 0x0000_0000_0000_0001,
+};
+
+/// Order of the base point: `L = 2^446 - 13818066809895115352007386748515426880336692474882178609894547503885`
+const ORDER: Modulus = Modulus {
+    value: [
+        0x2378_c292_ab58_44f3,
+        0x216c_c272_8dc5_8f55,
+        0xc44e_db49_aed6_3690,
+        0xffff_ffff_7cca_23e9,
+        0xffff_ffff_ffff_ffff,
+        0xffff_ffff_ffff_ffff,
+        0x3fff_ffff_ffff_ffff,
+        0x0000_0000_0000_0000,
+    ],
+    r2: [
+        0xbbec_ffc3_3c3a_378c,
+        0x3e0a_a68d_30db_b989,
+        0x8bd8_27de_127c_e5f2,
+        0xf10a_98ef_88e1_5bb4,
+        0x1075_50ba_94d0_e808,
+        0xae17_cf72_c986_0faa,
+        0x1a9c_c14b_a3c4_7c44,
+        0x0000_0000_0000_0000,
+    ],
+    inv: 0x03bd_440f_ae91_8bc5,
+};
+
+/// Bit length of the order of the base point
+const ORDER_BITS: usize = 446;
+
+/// Exponent used to compute square roots: `(p + 1) / 4`
+const SQRT_EXPONENT: Limbs = [
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0xffff_ffff_c000_0000,
+    0xffff_ffff_ffff_ffff,
+    0xffff_ffff_ffff_ffff,
+    0x3fff_ffff_ffff_ffff,
+    0x0000_0000_0000_0000,
+];
+
+/// Curve equation coefficient `d = -39081` (in Montgomery form)
+const EDWARDS_D: FieldElement = FieldElement([
+    0xffff_ffff_ffff_ffff,
+    0xffff_ffff_ffff_6756,
+    0xffff_ffff_ffff_ffff,
+    0xffff_fffe_ffff_ffff,
+    0xffff_6756_ffff_ffff,
+    0xffff_ffff_ffff_ffff,
+    0xffff_ffff_ffff_ffff,
+    0x0000_0000_0000_0000,
+]);
+
+/// `x`-coordinate of the base point (serialized as little endian bytes)
+const GENERATOR_X: &[u8; FIELD_ELEMENT_SIZE] = b"\x5e\xc0\x0c\xc7\x2b\xa8\x26\x26\x8e\x93\x00\x8b\
+                                                \xe1\x80\x3b\x43\x11\x65\xb6\x2a\xf7\x1a\xae\x12\
+                                                \x64\xa4\xd3\xa3\x24\xe3\x6d\xea\x67\x17\x0f\x47\
+                                                \x70\x65\x14\x9e\xda\x36\xbf\x22\xa6\x15\x1d\x22\
+                                                \xed\x0d\xed\x6b\xc6\x70\x19\x4f";
+
+/// `y`-coordinate of the base point (serialized as little endian bytes)
+const GENERATOR_Y: &[u8; FIELD_ELEMENT_SIZE] = b"\x14\xfa\x30\xf2\x5b\x79\x08\x98\xad\xc8\xd7\x4e\
+                                                \x2c\x13\xbd\xfd\xc4\x39\x7c\xe6\x1c\xff\xd3\x3a\
+                                                \xd7\xc2\xa0\x05\x1e\x9c\x78\x87\x40\x98\xa3\x6c\
+                                                \x73\x73\xea\x4b\x62\xc7\xc9\x56\x37\x20\x76\x88\
+                                                \x24\xbc\xb6\x6e\x71\x46\x3f\x69";
+
+/// Element of the Edwards448 base field (in Montgomery form)
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+struct FieldElement(Limbs);
+
+impl FieldElement {
+    /// The additive identity
+    fn zero() -> Self {
+        FieldElement([0; LIMBS])
+    }
+
+    /// The multiplicative identity
+    fn one() -> Self {
+        FieldElement(to_montgomery(&ONE, &FIELD_MODULUS))
+    }
+
+    /// Decode a field element from little endian bytes, returning `None` if
+    /// it isn't less than the modulus
+    fn from_bytes(bytes: &[u8; FIELD_ELEMENT_SIZE]) -> Option<Self> {
+        let limbs = decode(bytes);
+        let (_, borrow) = sub_limbs(&limbs, &FIELD_MODULUS.value);
+
+        if borrow == 1 {
+            Some(FieldElement(to_montgomery(&limbs, &FIELD_MODULUS)))
+        } else {
+            None
+        }
+    }
+
+    /// Serialize this field element as little endian bytes
+    fn to_bytes(self) -> [u8; FIELD_ELEMENT_SIZE] {
+        let mut bytes = [0u8; FIELD_ELEMENT_SIZE];
+        encode(&from_montgomery(&self.0, &FIELD_MODULUS), &mut bytes);
+        bytes
+    }
+
+    /// Is the (canonical) value of this field element odd?
+    fn is_odd(self) -> bool {
+        from_montgomery(&self.0, &FIELD_MODULUS)[0] & 1 == 1
+    }
+
+    /// Compute `p - self`
+    fn neg(self) -> Self {
+        Self::zero().sub(self)
+    }
+
+    /// Add two field elements
+    fn add(self, other: Self) -> Self {
+        FieldElement(add_mod(&self.0, &other.0, &FIELD_MODULUS))
+    }
+
+    /// Subtract two field elements
+    fn sub(self, other: Self) -> Self {
+        FieldElement(sub_mod(&self.0, &other.0, &FIELD_MODULUS))
+    }
+
+    /// Multiply two field elements
+    fn mul(self, other: Self) -> Self {
+        FieldElement(montgomery_mul(&self.0, &other.0, &FIELD_MODULUS))
+    }
+
+    /// Compute the multiplicative inverse of this field element (zero if
+    /// it's zero)
+    fn invert(self) -> Self {
+        let (exponent, _) = sub_limbs(&FIELD_MODULUS.value, &[2, 0, 0, 0, 0, 0, 0, 0]);
+        FieldElement(pow(&self.0, &exponent, &FIELD_MODULUS))
+    }
+
+    /// Compute a square root of this field element, returning `None` if it
+    /// isn't a square
+    fn sqrt(self) -> Option<Self> {
+        // As `p = 3 (mod 4)`, `a^((p + 1) / 4)` is a square root of `a`
+        // whenever one exists
+        let root = FieldElement(pow(&self.0, &SQRT_EXPONENT, &FIELD_MODULUS));
+
+        if root.mul(root) == self {
+            Some(root)
+        } else {
+            None
+        }
+    }
+}
+
+impl ConditionallySelectable for FieldElement {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        FieldElement(select(&a.0, &b.0, choice))
+    }
+}
+
+/// Element of the scalar field, i.e. integers modulo the order of the base
+/// point (in Montgomery form)
+#[derive(Copy, Clone, Debug, Default)]
+pub(super) struct Scalar(Limbs);
+
+impl Scalar {
+    /// Decode a scalar from little endian bytes, returning `None` if it
+    /// isn't less than the order of the base point
+    pub fn from_canonical_bytes(bytes: &[u8; ENCODED_SIZE]) -> Option<Self> {
+        let limbs = decode(bytes);
+        let (_, borrow) = sub_limbs(&limbs, &ORDER.value);
+
+        if borrow == 1 {
+            Some(Scalar(to_montgomery(&limbs, &ORDER)))
+        } else {
+            None
+        }
+    }
+
+    /// Reduce an integer of at most 64 little endian bytes (e.g. a clamped
+    /// secret scalar) modulo the order of the base point
+    pub fn from_bytes_mod_order(bytes: &[u8]) -> Self {
+        let mut limbs = decode(bytes);
+
+        // Inputs are less than `R`, so the product with `R^2 mod L` is less
+        // than `R * L` and the Montgomery reduction is complete
+        let scalar = Scalar(to_montgomery(&limbs, &ORDER));
+        limbs.zeroize();
+        scalar
+    }
+
+    /// Reduce a 114-byte little endian integer (i.e. a SHAKE256 digest)
+    /// modulo the order of the base point
+    pub fn from_bytes_mod_order_wide(bytes: &[u8; 2 * ENCODED_SIZE]) -> Self {
+        // `lo + hi * R` in Montgomery form is `mont(lo) + mont(hi) * R^2 / R`
+        let lo = Self::from_bytes_mod_order(&bytes[..64]);
+        let hi = Self::from_bytes_mod_order(&bytes[64..]);
+        Scalar(add_mod(
+            &lo.0,
+            &montgomery_mul(&hi.0, &ORDER.r2, &ORDER),
+            &ORDER,
+        ))
+    }
+
+    /// Serialize this scalar as little endian bytes
+    pub fn to_bytes(self) -> [u8; ENCODED_SIZE] {
+        let mut bytes = [0u8; ENCODED_SIZE];
+        encode(&from_montgomery(&self.0, &ORDER), &mut bytes);
+        bytes
+    }
+
+    /// Add two scalars
+    pub fn add(&self, other: &Self) -> Self {
+        Scalar(add_mod(&self.0, &other.0, &ORDER))
+    }
+
+    /// Multiply two scalars
+    pub fn mul(&self, other: &Self) -> Self {
+        Scalar(montgomery_mul(&self.0, &other.0, &ORDER))
+    }
+}
+
+impl Zeroize for Scalar {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// Point on the Edwards448 curve in projective coordinates
+#[derive(Copy, Clone, Debug)]
+pub(super) struct EdwardsPoint {
+    x: FieldElement,
+    y: FieldElement,
+    z: FieldElement,
+}
+
+impl EdwardsPoint {
+    /// The neutral element
+    pub fn identity() -> Self {
+        Self {
+            x: FieldElement::zero(),
+            y: FieldElement::one(),
+            z: FieldElement::one(),
+        }
+    }
+
+    /// The base point
+    pub fn generator() -> Self {
+        Self {
+            x: FieldElement::from_bytes(GENERATOR_X).expect("invalid base point"),
+            y: FieldElement::from_bytes(GENERATOR_Y).expect("invalid base point"),
+            z: FieldElement::one(),
+        }
+    }
+
+    /// Decode a point as described in RFC 8032 Section 5.2.3, returning
+    /// `None` if the encoding is non-canonical or isn't a point on the curve
+    pub fn decompress(bytes: &[u8; ENCODED_SIZE]) -> Option<Self> {
+        // The final byte only contains the sign of `x`
+        if bytes[FIELD_ELEMENT_SIZE] & 0x7f != 0 {
+            return None;
+        }
+
+        let mut y_bytes = [0u8; FIELD_ELEMENT_SIZE];
+        y_bytes.copy_from_slice(&bytes[..FIELD_ELEMENT_SIZE]);
+        let y = FieldElement::from_bytes(&y_bytes)?;
+        let x_sign = bytes[FIELD_ELEMENT_SIZE] >> 7 == 1;
+
+        // x^2 = (y^2 - 1) / (d * y^2 - 1)
+        let y2 = y.mul(y);
+        let u = y2.sub(FieldElement::one());
+        let v = EDWARDS_D.mul(y2).sub(FieldElement::one());
+        let x = u.mul(v.invert()).sqrt()?;
+
+        let x = if x == FieldElement::zero() {
+            if x_sign {
+                return None;
+            }
+
+            x
+        } else if x.is_odd() != x_sign {
+            x.neg()
+        } else {
+            x
+        };
+
+        Some(Self {
+            x,
+            y,
+            z: FieldElement::one(),
+        })
+    }
+
+    /// Encode this point as described in RFC 8032 Section 5.2.2
+    pub fn compress(&self) -> [u8; ENCODED_SIZE] {
+        let z_inv = self.z.invert();
+        let x = self.x.mul(z_inv);
+        let y = self.y.mul(z_inv);
+
+        let mut bytes = [0u8; ENCODED_SIZE];
+        bytes[..FIELD_ELEMENT_SIZE].copy_from_slice(&y.to_bytes());
+        bytes[FIELD_ELEMENT_SIZE] = (x.is_odd() as u8) << 7;
+        bytes
+    }
+
+    /// Add two points (RFC 8032 Section 5.2.4)
+    pub fn add(&self, other: &Self) -> Self {
+        let a = self.z.mul(other.z);
+        let b = a.mul(a);
+        let c = self.x.mul(other.x);
+        let d = self.y.mul(other.y);
+        let e = EDWARDS_D.mul(c).mul(d);
+        let f = b.sub(e);
+        let g = b.add(e);
+        let h = self.x.add(self.y).mul(other.x.add(other.y));
+
+        Self {
+            x: a.mul(f).mul(h.sub(c).sub(d)),
+            y: a.mul(g).mul(d.sub(c)),
+            z: f.mul(g),
+        }
+    }
+
+    /// Compute `-self`
+    pub fn neg(&self) -> Self {
+        Self {
+            x: self.x.neg(),
+            y: self.y,
+            z: self.z,
+        }
+    }
+
+    /// Multiply this point by the cofactor (4)
+    pub fn mul_by_cofactor(&self) -> Self {
+        let double = self.add(self);
+        double.add(&double)
+    }
+
+    /// Is this the neutral element?
+    pub fn is_identity(&self) -> bool {
+        self.x == FieldElement::zero() && self.y == self.z
+    }
+
+    /// Multiply this point by the given scalar (in constant time)
+    pub fn mul(&self, scalar: &Scalar) -> Self {
+        let mut k = from_montgomery(&scalar.0, &ORDER);
+        let mut result = Self::identity();
+
+        for i in (0..ORDER_BITS).rev() {
+            result = result.add(&result);
+            let sum = result.add(self);
+            let bit = Choice::from(((k[i / 64] >> (i % 64)) & 1) as u8);
+            result = Self::conditional_select(&result, &sum, bit);
+        }
+
+        k.zeroize();
+        result
+    }
+}
+
+impl ConditionallySelectable for EdwardsPoint {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            x: FieldElement::conditional_select(&a.x, &b.x, choice),
+            y: FieldElement::conditional_select(&a.y, &b.y, choice),
+            z: FieldElement::conditional_select(&a.z, &b.z, choice),
+        }
+    }
+}
+
+/// Decode a little endian integer of at most 64 bytes
+fn decode(bytes: &[u8]) -> Limbs {
+    debug_assert!(bytes.len() <= 8 * LIMBS);
+    let mut limbs = [0u64; LIMBS];
+
+    for (i, &byte) in bytes.iter().enumerate() {
+        limbs[i / 8] |= u64::from(byte) << (8 * (i % 8));
+    }
+
+    limbs
+}
+
+/// Serialize the low bytes of an integer as little endian bytes
+fn encode(limbs: &Limbs, bytes: &mut [u8]) {
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = (limbs[i / 8] >> (8 * (i % 8))) as u8;
+    }
+}
+
+/// Select `b` if `choice` is set, otherwise `a` (in constant time)
+fn select(a: &Limbs, b: &Limbs, choice: Choice) -> Limbs {
+    let mut result = [0u64; LIMBS];
+
+    for i in 0..LIMBS {
+        result[i] = u64::conditional_select(&a[i], &b[i], choice);
+    }
+
+    result
+}
+
+/// Add two multi-limb integers, returning the sum and the carry
+fn add_limbs(a: &Limbs, b: &Limbs) -> (Limbs, u64) {
+    let mut sum = [0u64; LIMBS];
+    let mut carry = 0u128;
+
+    for i in 0..LIMBS {
+        let tmp = u128::from(a[i]) + u128::from(b[i]) + carry;
+        sum[i] = tmp as u64;
+        carry = tmp >> 64;
+    }
+
+    (sum, carry as u64)
+}
+
+/// Subtract two multi-limb integers, returning the difference and the
+/// borrow
+fn sub_limbs(a: &Limbs, b: &Limbs) -> (Limbs, u64) {
+    let mut difference = [0u64; LIMBS];
+    let mut borrow = 0u64;
+
+    for i in 0..LIMBS {
+        let tmp = u128::from(a[i])
+            .wrapping_sub(u128::from(b[i]))
+            .wrapping_sub(u128::from(borrow));
+        difference[i] = tmp as u64;
+        borrow = (tmp >> 127) as u64;
+    }
+
+    (difference, borrow)
+}
+
+/// Compute `a + b mod m`
+fn add_mod(a: &Limbs, b: &Limbs, modulus: &Modulus) -> Limbs {
+    // Both moduli are less than `2^511`, so the sum can't overflow
+    let (sum, _) = add_limbs(a, b);
+    let (reduced, borrow) = sub_limbs(&sum, &modulus.value);
+    select(&reduced, &sum, Choice::from(borrow as u8))
+}
+
+/// Compute `a - b mod m`
+fn sub_mod(a: &Limbs, b: &Limbs, modulus: &Modulus) -> Limbs {
+    let (difference, borrow) = sub_limbs(a, b);
+    let (wrapped, _) = add_limbs(&difference, &modulus.value);
+    select(&difference, &wrapped, Choice::from(borrow as u8))
+}
+
+/// Montgomery multiplication: compute `a * b * R^-1 mod m` (CIOS method)
+fn montgomery_mul(a: &Limbs, b: &Limbs, modulus: &Modulus) -> Limbs {
+    let m = &modulus.value;
+    let mut t = [0u64; LIMBS + 2];
+
+    for &b_i in b.iter() {
+        let mut carry = 0u128;
+
+        for j in 0..LIMBS {
+            let tmp = u128::from(t[j]) + u128::from(a[j]) * u128::from(b_i) + carry;
+            t[j] = tmp as u64;
+            carry = tmp >> 64;
+        }
+
+        let tmp = u128::from(t[LIMBS]) + carry;
+        t[LIMBS] = tmp as u64;
+        t[LIMBS + 1] = (tmp >> 64) as u64;
+
+        let q = t[0].wrapping_mul(modulus.inv);
+        let mut carry = (u128::from(t[0]) + u128::from(q) * u128::from(m[0])) >> 64;
+
+        for j in 1..LIMBS {
+            let tmp = u128::from(t[j]) + u128::from(q) * u128::from(m[j]) + carry;
+            t[j - 1] = tmp as u64;
+            carry = tmp >> 64;
+        }
+
+        let tmp = u128::from(t[LIMBS]) + carry;
+        t[LIMBS - 1] = tmp as u64;
+        t[LIMBS] = t[LIMBS + 1] + (tmp >> 64) as u64;
+    }
+
+    let mut result = [0u64; LIMBS];
+    result.copy_from_slice(&t[..LIMBS]);
+
+    // The result is less than `2m`, so at most one subtraction is needed
+    let (reduced, borrow) = sub_limbs(&result, m);
+    let use_reduced = Choice::from(((t[LIMBS] | (borrow ^ 1)) & 1) as u8);
+    let result = select(&result, &reduced, use_reduced);
+
+    t.zeroize();
+    result
+}
+
+/// Convert an integer less than `R` into Montgomery form
+fn to_montgomery(a: &Limbs, modulus: &Modulus) -> Limbs {
+    montgomery_mul(a, &modulus.r2, modulus)
+}
+
+/// Convert an integer out of Montgomery form
+fn from_montgomery(a: &Limbs, modulus: &Modulus) -> Limbs {
+    montgomery_mul(a, &ONE, modulus)
+}
+
+/// Compute `a^exponent mod m`. The exponent is public, so branching on its
+/// bits is fine.
+fn pow(a: &Limbs, exponent: &Limbs, modulus: &Modulus) -> Limbs {
+    let mut result = to_montgomery(&ONE, modulus);
+
+    for limb in exponent.iter().rev() {
+        for i in (0..64).rev() {
+            result = montgomery_mul(&result, &result, modulus);
+
+            if (limb >> i) & 1 == 1 {
+                result = montgomery_mul(&result, a, modulus);
+            }
+        }
+    }
+
+    result
+}
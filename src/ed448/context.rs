@@ -0,0 +1,63 @@
+//! Ed448 context strings and Ed448ph
+//!
+//! Unlike Ed25519, every Ed448 signature is bound to a (possibly empty)
+//! context string as described in RFC 8032 Section 5.2. The `Signer` and
+//! `Verifier` traits use an empty context, and these traits allow one to be
+//! supplied.
+//!
+//! Ed448ph signs a 64-byte SHAKE256 prehash of the message rather than the
+//! message itself, which allows messages to be signed incrementally without
+//! buffering them.
+
+use super::Signature;
+use sha3::Shake256;
+use signature::Error;
+
+/// Maximum size of an Ed448 context string
+pub const MAX_CONTEXT_SIZE: usize = 255;
+
+/// Sign messages using Ed448 with a context string
+pub trait ContextSigner {
+    /// Sign the given message using the given context string, which must be
+    /// at most `MAX_CONTEXT_SIZE` bytes
+    fn try_sign_with_context(&self, context: &[u8], msg: &[u8]) -> Result<Signature, Error>;
+
+    /// Sign the given message using the given context string, panicking if
+    /// the context is invalid or signing fails
+    fn sign_with_context(&self, context: &[u8], msg: &[u8]) -> Signature {
+        self.try_sign_with_context(context, msg)
+            .expect("signature operation failed")
+    }
+}
+
+/// Verify Ed448 signatures made with a context string
+pub trait ContextVerifier {
+    /// Verify the given signature of the given message under the given
+    /// context string
+    fn verify_with_context(
+        &self,
+        context: &[u8],
+        msg: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error>;
+}
+
+/// Sign messages using Ed448ph
+pub trait PrehashSigner {
+    /// Sign the message which has been input into the given SHAKE256 hasher
+    /// using the given context string, which must be at most
+    /// `MAX_CONTEXT_SIZE` bytes
+    fn try_sign_prehashed(&self, context: &[u8], prehash: Shake256) -> Result<Signature, Error>;
+}
+
+/// Verify Ed448ph signatures
+pub trait PrehashVerifier {
+    /// Verify the given signature of the message which has been input into
+    /// the given SHAKE256 hasher under the given context string
+    fn verify_prehashed(
+        &self,
+        context: &[u8],
+        prehash: Shake256,
+        signature: &Signature,
+    ) -> Result<(), Error>;
+}
@@ -0,0 +1,153 @@
+//! Ed448 public keys
+
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
+};
+
+#[cfg(feature = "spki")]
+use crate::encoding::Algorithm;
+#[cfg(feature = "encoding")]
+use crate::encoding::Decode;
+#[cfg(all(feature = "alloc", feature = "encoding"))]
+use crate::encoding::Encode;
+#[cfg(all(feature = "alloc", feature = "spki"))]
+use crate::encoding::ToPublicKeyDer;
+#[cfg(feature = "spki")]
+use crate::encoding::{spki::SubjectPublicKeyInfo, FromPublicKeyDer};
+#[cfg(all(feature = "alloc", any(feature = "encoding", feature = "spki")))]
+use alloc::vec::Vec;
+#[cfg(feature = "encoding")]
+use subtle_encoding::Encoding;
+
+/// Size of an Ed448 public key in bytes (456-bits)
+pub const PUBLIC_KEY_SIZE: usize = 57;
+
+/// Ed448 public keys
+#[derive(Copy, Clone)]
+pub struct PublicKey(pub [u8; PUBLIC_KEY_SIZE]);
+
+impl PublicKey {
+    /// Create an Ed448 public key from a 57-byte array
+    pub fn new(bytes: [u8; PUBLIC_KEY_SIZE]) -> Self {
+        PublicKey(bytes)
+    }
+
+    /// Create an Ed448 public key from its serialized (compressed Edwards-y) form
+    pub fn from_bytes<B>(bytes: B) -> Option<Self>
+    where
+        B: AsRef<[u8]>,
+    {
+        if bytes.as_ref().len() == PUBLIC_KEY_SIZE {
+            let mut public_key = [0u8; PUBLIC_KEY_SIZE];
+            public_key.copy_from_slice(bytes.as_ref());
+            Some(PublicKey(public_key))
+        } else {
+            None
+        }
+    }
+
+    /// Obtain public key as a byte array reference
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; PUBLIC_KEY_SIZE] {
+        &self.0
+    }
+
+    /// Convert public key into owned byte array
+    #[inline]
+    pub fn into_bytes(self) -> [u8; PUBLIC_KEY_SIZE] {
+        self.0
+    }
+}
+
+impl AsRef<[u8]> for PublicKey {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl Debug for PublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ed448::PublicKey({:?})", self.as_ref())
+    }
+}
+
+// Arrays larger than 32 elements don't implement these traits on our MSRV
+impl Eq for PublicKey {}
+
+impl PartialEq for PublicKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl Hash for PublicKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_ref().hash(state)
+    }
+}
+
+impl PartialOrd for PublicKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PublicKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_ref().cmp(other.as_ref())
+    }
+}
+
+#[cfg(feature = "encoding")]
+impl Decode for PublicKey {
+    /// Decode an Ed448 public key from a byte slice with the given encoding
+    /// (e.g. hex, Base64)
+    fn decode<E: Encoding>(
+        encoded_key: &[u8],
+        encoding: &E,
+    ) -> Result<Self, crate::encoding::Error> {
+        let mut decoded_key = [0u8; PUBLIC_KEY_SIZE];
+        let decoded_len = encoding.decode_to_slice(encoded_key, &mut decoded_key)?;
+
+        if decoded_len == PUBLIC_KEY_SIZE {
+            Ok(Self::new(decoded_key))
+        } else {
+            Err(crate::encoding::error::ErrorKind::Decode)?
+        }
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "encoding"))]
+impl Encode for PublicKey {
+    /// Encode an Ed448 public key with the given encoding (e.g. hex, Base64)
+    fn encode<E: Encoding>(&self, encoding: &E) -> Vec<u8> {
+        encoding.encode(self.as_bytes())
+    }
+}
+
+#[cfg(feature = "spki")]
+impl FromPublicKeyDer for PublicKey {
+    /// Decode an Ed448 public key from a DER-encoded **SubjectPublicKeyInfo**
+    fn from_public_key_der(bytes: &[u8]) -> Result<Self, crate::encoding::Error> {
+        let spki = SubjectPublicKeyInfo::from_der_with_algorithm(bytes, Algorithm::Ed448)?;
+        Self::from_bytes(spki.subject_public_key)
+            .ok_or_else(|| crate::encoding::error::ErrorKind::Decode.into())
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "spki"))]
+impl ToPublicKeyDer for PublicKey {
+    /// Encode an Ed448 public key as a DER-encoded **SubjectPublicKeyInfo**
+    fn to_public_key_der(&self) -> Vec<u8> {
+        SubjectPublicKeyInfo {
+            algorithm: Algorithm::Ed448,
+            subject_public_key: self.as_bytes(),
+        }
+        .to_der()
+    }
+}
+
+impl crate::public_key::PublicKey for PublicKey {}
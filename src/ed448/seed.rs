@@ -0,0 +1,108 @@
+//! Ed448 seeds: 57-byte uniformly random secret value used to derive scalars
+//! and nonce prefixes
+
+#[cfg(feature = "encoding")]
+use crate::encoding::Decode;
+#[cfg(all(feature = "alloc", feature = "encoding"))]
+use crate::encoding::Encode;
+#[cfg(feature = "ed448-software")]
+use crate::public_key::PublicKeyed;
+#[cfg(all(feature = "encoding", feature = "alloc"))]
+use alloc::vec::Vec;
+#[cfg(feature = "getrandom")]
+use getrandom::getrandom;
+#[cfg(feature = "encoding")]
+use subtle_encoding::Encoding;
+use zeroize::Zeroize;
+
+/// Size of the "seed" value for an Ed448 private key
+pub const SEED_SIZE: usize = 57;
+
+/// Ed448 seeds: derivation secrets for Ed448 private scalars/nonce prefixes
+#[derive(Clone)]
+pub struct Seed(pub [u8; SEED_SIZE]);
+
+impl Seed {
+    /// Create an Ed448 seed from a 57-byte array
+    pub fn new(bytes: [u8; SEED_SIZE]) -> Self {
+        Seed(bytes)
+    }
+
+    /// Generate a new Ed448 seed using the operating system's
+    /// cryptographically secure random number generator
+    #[cfg(feature = "getrandom")]
+    pub fn generate() -> Self {
+        let mut bytes = [0u8; SEED_SIZE];
+        getrandom(&mut bytes[..]).expect("RNG failure!");
+        Self::new(bytes)
+    }
+
+    /// Create an Ed448 seed from a byte slice, returning `None` if the slice
+    /// is not the correct size (57-bytes)
+    pub fn from_bytes<B>(bytes: B) -> Option<Self>
+    where
+        B: AsRef<[u8]>,
+    {
+        if bytes.as_ref().len() == SEED_SIZE {
+            let mut seed = [0u8; SEED_SIZE];
+            seed.copy_from_slice(bytes.as_ref());
+            Some(Seed::new(seed))
+        } else {
+            None
+        }
+    }
+
+    /// Expose the secret values of the `Seed` as a byte slice
+    pub fn as_secret_slice(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+#[cfg(feature = "encoding")]
+impl Decode for Seed {
+    /// Decode an Ed448 seed from a byte slice with the given encoding
+    /// (e.g. hex, Base64)
+    fn decode<E: Encoding>(
+        encoded_seed: &[u8],
+        encoding: &E,
+    ) -> Result<Self, crate::encoding::Error> {
+        let mut decoded_seed = [0u8; SEED_SIZE];
+        let decoded_len = encoding.decode_to_slice(encoded_seed, &mut decoded_seed)?;
+        let result = if decoded_len == SEED_SIZE {
+            Ok(Self::new(decoded_seed))
+        } else {
+            Err(crate::encoding::error::ErrorKind::Decode.into())
+        };
+
+        decoded_seed.zeroize();
+        result
+    }
+}
+
+#[cfg(all(feature = "encoding", feature = "alloc"))]
+impl Encode for Seed {
+    /// Encode an Ed448 seed with the given encoding (e.g. hex, Base64)
+    fn encode<E: Encoding>(&self, encoding: &E) -> Vec<u8> {
+        encoding.encode(self.as_secret_slice())
+    }
+}
+
+#[cfg(feature = "ed448-software")]
+impl PublicKeyed<super::PublicKey> for Seed {
+    /// Derive the Ed448 public key for this seed
+    fn public_key(&self) -> Result<super::PublicKey, signature::Error> {
+        super::Signer::from(self).public_key()
+    }
+}
+
+impl Drop for Seed {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl From<[u8; SEED_SIZE]> for Seed {
+    fn from(bytes: [u8; SEED_SIZE]) -> Self {
+        Seed::new(bytes)
+    }
+}
@@ -0,0 +1,57 @@
+//! Ed448 signatures
+
+use core::fmt::{self, Debug};
+use signature::Error;
+
+/// Size of an Ed448 signature in bytes (the encoded `R` point followed by
+/// the scalar `S`)
+pub const SIGNATURE_SIZE: usize = 114;
+
+/// Ed448 signatures
+#[derive(Copy, Clone)]
+pub struct Signature([u8; SIGNATURE_SIZE]);
+
+impl Signature {
+    /// Create an Ed448 signature from a 114-byte array
+    pub fn new(bytes: [u8; SIGNATURE_SIZE]) -> Self {
+        Signature(bytes)
+    }
+
+    /// Convert signature into owned byte array
+    pub fn to_bytes(&self) -> [u8; SIGNATURE_SIZE] {
+        self.0
+    }
+}
+
+impl signature::Signature for Signature {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() == SIGNATURE_SIZE {
+            let mut signature = [0u8; SIGNATURE_SIZE];
+            signature.copy_from_slice(bytes);
+            Ok(Signature(signature))
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+impl AsRef<[u8]> for Signature {
+    fn as_ref(&self) -> &[u8] {
+        &self.0[..]
+    }
+}
+
+impl Debug for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ed448::Signature({:?})", self.as_ref())
+    }
+}
+
+// Arrays larger than 32 elements don't implement these traits on our MSRV
+impl Eq for Signature {}
+
+impl PartialEq for Signature {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
@@ -0,0 +1,311 @@
+//! Pure-Rust software implementation of Ed448
+//!
+//! Supports both variants described in RFC 8032 Section 5.2:
+//!
+//! - Ed448 (PureEdDSA): `signature::Signer` / `signature::Verifier` with an
+//!   empty context, or `ContextSigner` / `ContextVerifier`
+//! - Ed448ph: `PrehashSigner` / `PrehashVerifier`, which allows signing
+//!   messages incrementally without buffering them
+//!
+//! Signatures are verified using the cofactored equation
+//! `[4][S]B = [4]R + [4][k]A`.
+
+use super::{
+    arithmetic::{EdwardsPoint, Scalar, ENCODED_SIZE},
+    ContextSigner, ContextVerifier, PrehashSigner, PrehashVerifier, PublicKey, Seed, Signature,
+    MAX_CONTEXT_SIZE, SIGNATURE_SIZE,
+};
+#[cfg(feature = "alloc")]
+use crate::batch::{self, BatchError, BatchItem, BatchVerifier};
+#[cfg(feature = "pkcs8")]
+use crate::encoding::{self, pkcs8::PrivateKeyInfo, Algorithm, FromPkcs8};
+use crate::public_key::PublicKeyed;
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake256,
+};
+use signature::Error;
+use zeroize::Zeroize;
+
+/// Domain separator used by `dom4`
+const DOM4_PREFIX: &[u8] = b"SigEd448";
+
+/// Size of the SHAKE256 prehash signed by Ed448ph
+const PREHASH_SIZE: usize = 64;
+
+/// Ed448 variants (RFC 8032 Section 5.2), which determine the `dom4` prefix
+/// of each hash
+#[derive(Copy, Clone, Debug)]
+enum Variant<'a> {
+    /// Ed448 (PureEdDSA) with the given (possibly empty) context string
+    Pure(&'a [u8]),
+
+    /// Ed448ph with the given (possibly empty) context string
+    Prehash(&'a [u8]),
+}
+
+impl<'a> Variant<'a> {
+    /// Create a SHAKE256 hasher initialized with this variant's prefix
+    fn hasher(self) -> Result<Shake256, Error> {
+        let (flag, context) = match self {
+            Variant::Pure(context) => (0, context),
+            Variant::Prehash(context) => (1, context),
+        };
+
+        if context.len() > MAX_CONTEXT_SIZE {
+            return Err(Error::new());
+        }
+
+        let mut hasher = Shake256::default();
+        hasher.update(DOM4_PREFIX);
+        hasher.update([flag, context.len() as u8]);
+        hasher.update(context);
+        Ok(hasher)
+    }
+}
+
+/// Ed448 signer
+pub struct Signer {
+    /// Secret scalar derived from the seed
+    secret_scalar: Scalar,
+
+    /// Prefix hashed with the message to derive nonces
+    nonce_prefix: [u8; ENCODED_SIZE],
+
+    /// Public key corresponding to the secret scalar
+    public_key: PublicKey,
+}
+
+impl<'a> From<&'a Seed> for Signer {
+    /// Expand the given seed into a secret scalar and nonce prefix
+    fn from(seed: &'a Seed) -> Self {
+        let mut expanded = [0u8; 2 * ENCODED_SIZE];
+        let mut hasher = Shake256::default();
+        hasher.update(seed.as_secret_slice());
+        hasher.finalize_xof().read(&mut expanded);
+
+        let mut scalar_bytes = [0u8; ENCODED_SIZE];
+        scalar_bytes.copy_from_slice(&expanded[..ENCODED_SIZE]);
+        scalar_bytes[0] &= 0xfc;
+        scalar_bytes[55] |= 0x80;
+        scalar_bytes[56] = 0;
+
+        let mut nonce_prefix = [0u8; ENCODED_SIZE];
+        nonce_prefix.copy_from_slice(&expanded[ENCODED_SIZE..]);
+
+        let secret_scalar = Scalar::from_bytes_mod_order(&scalar_bytes);
+        let public_key = PublicKey::new(EdwardsPoint::generator().mul(&secret_scalar).compress());
+
+        expanded.zeroize();
+        scalar_bytes.zeroize();
+
+        Self {
+            secret_scalar,
+            nonce_prefix,
+            public_key,
+        }
+    }
+}
+
+impl Signer {
+    /// Sign the given message (or prehash) using the given variant
+    fn sign_variant(&self, variant: Variant<'_>, msg: &[u8]) -> Result<Signature, Error> {
+        let mut nonce_hasher = variant.hasher()?;
+        nonce_hasher.update(self.nonce_prefix);
+        nonce_hasher.update(msg);
+        let mut nonce = hash_to_scalar(nonce_hasher);
+
+        let r = EdwardsPoint::generator().mul(&nonce).compress();
+        let k = challenge(variant, &r, &self.public_key, msg)?;
+        let s = k.mul(&self.secret_scalar).add(&nonce);
+        nonce.zeroize();
+
+        let mut signature = [0u8; SIGNATURE_SIZE];
+        signature[..ENCODED_SIZE].copy_from_slice(&r);
+        signature[ENCODED_SIZE..].copy_from_slice(&s.to_bytes());
+        Ok(Signature::new(signature))
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+impl FromPkcs8 for Signer {
+    /// Create a new Ed448 signer from a PKCS#8 private key, checking it
+    /// against the public key if the document contains one
+    fn from_pkcs8<K: AsRef<[u8]>>(secret_key: K) -> Result<Self, encoding::Error> {
+        let private_key_info = PrivateKeyInfo::from_der(secret_key.as_ref())?;
+
+        if private_key_info.algorithm != Algorithm::Ed448 {
+            return Err(encoding::error::ErrorKind::Decode.into());
+        }
+
+        let seed = Seed::from_bytes(private_key_info.private_key)
+            .ok_or(encoding::error::ErrorKind::Decode)?;
+
+        let signer = Signer::from(&seed);
+
+        if let Some(public_key) = private_key_info.public_key {
+            if PublicKey::from_bytes(public_key) != Some(signer.public_key) {
+                return Err(encoding::error::ErrorKind::Decode.into());
+            }
+        }
+
+        Ok(signer)
+    }
+}
+
+impl PublicKeyed<PublicKey> for Signer {
+    fn public_key(&self) -> Result<PublicKey, Error> {
+        Ok(self.public_key)
+    }
+}
+
+impl signature::Signer<Signature> for Signer {
+    fn try_sign(&self, msg: &[u8]) -> Result<Signature, Error> {
+        self.sign_variant(Variant::Pure(&[]), msg)
+    }
+}
+
+impl ContextSigner for Signer {
+    fn try_sign_with_context(&self, context: &[u8], msg: &[u8]) -> Result<Signature, Error> {
+        self.sign_variant(Variant::Pure(context), msg)
+    }
+}
+
+impl PrehashSigner for Signer {
+    fn try_sign_prehashed(&self, context: &[u8], prehash: Shake256) -> Result<Signature, Error> {
+        self.sign_variant(Variant::Prehash(context), &finalize_prehash(prehash))
+    }
+}
+
+impl Drop for Signer {
+    fn drop(&mut self) {
+        self.secret_scalar.zeroize();
+        self.nonce_prefix.zeroize();
+    }
+}
+
+/// Ed448 verifier
+#[derive(Clone, Debug)]
+pub struct Verifier {
+    /// Public key being verified against
+    public_key: PublicKey,
+
+    /// Negated public key point, or `None` if the public key isn't a valid
+    /// point (in which case all signatures are rejected)
+    minus_a: Option<EdwardsPoint>,
+}
+
+impl<'a> From<&'a PublicKey> for Verifier {
+    fn from(public_key: &'a PublicKey) -> Self {
+        Self {
+            public_key: *public_key,
+            minus_a: EdwardsPoint::decompress(public_key.as_bytes()).map(|point| point.neg()),
+        }
+    }
+}
+
+impl Verifier {
+    /// Verify the given signature of the given message (or prehash) using the
+    /// given variant
+    fn verify_variant(
+        &self,
+        variant: Variant<'_>,
+        msg: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error> {
+        let minus_a = self.minus_a.as_ref().ok_or_else(Error::new)?;
+
+        let bytes = signature.to_bytes();
+        let mut r = [0u8; ENCODED_SIZE];
+        r.copy_from_slice(&bytes[..ENCODED_SIZE]);
+        let r_point = EdwardsPoint::decompress(&r).ok_or_else(Error::new)?;
+
+        let mut s_bytes = [0u8; ENCODED_SIZE];
+        s_bytes.copy_from_slice(&bytes[ENCODED_SIZE..]);
+        let s = Scalar::from_canonical_bytes(&s_bytes).ok_or_else(Error::new)?;
+
+        let k = challenge(variant, &r, &self.public_key, msg)?;
+
+        // Check `[4]([s]B - [k]A - R)` is the identity
+        let difference = EdwardsPoint::generator()
+            .mul(&s)
+            .add(&minus_a.mul(&k))
+            .add(&r_point.neg());
+
+        if difference.mul_by_cofactor().is_identity() {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+impl signature::Verifier<Signature> for Verifier {
+    fn verify(&self, msg: &[u8], signature: &Signature) -> Result<(), Error> {
+        self.verify_variant(Variant::Pure(&[]), msg, signature)
+    }
+}
+
+impl ContextVerifier for Verifier {
+    fn verify_with_context(
+        &self,
+        context: &[u8],
+        msg: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error> {
+        self.verify_variant(Variant::Pure(context), msg, signature)
+    }
+}
+
+impl PrehashVerifier for Verifier {
+    fn verify_prehashed(
+        &self,
+        context: &[u8],
+        prehash: Shake256,
+        signature: &Signature,
+    ) -> Result<(), Error> {
+        self.verify_variant(
+            Variant::Prehash(context),
+            &finalize_prehash(prehash),
+            signature,
+        )
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl BatchVerifier<PublicKey, Signature> for Verifier {
+    fn verify_batch(items: &[BatchItem<'_, PublicKey, Signature>]) -> Result<(), BatchError> {
+        batch::verify_sequentially::<_, _, Self>(items)
+    }
+}
+
+/// Compute the 64-byte Ed448ph prehash `PH(M) = SHAKE256(M, 64)`
+fn finalize_prehash(prehash: Shake256) -> [u8; PREHASH_SIZE] {
+    let mut output = [0u8; PREHASH_SIZE];
+    prehash.finalize_xof().read(&mut output);
+    output
+}
+
+/// Read 114 bytes of output from the given hasher and reduce them modulo
+/// the group order
+fn hash_to_scalar(hasher: Shake256) -> Scalar {
+    let mut output = [0u8; 2 * ENCODED_SIZE];
+    hasher.finalize_xof().read(&mut output);
+    let scalar = Scalar::from_bytes_mod_order_wide(&output);
+    output.zeroize();
+    scalar
+}
+
+/// Compute the challenge scalar `k = H(dom4 || R || A || M)`
+fn challenge(
+    variant: Variant<'_>,
+    r: &[u8; ENCODED_SIZE],
+    public_key: &PublicKey,
+    msg: &[u8],
+) -> Result<Scalar, Error> {
+    let mut hasher = variant.hasher()?;
+    hasher.update(&r[..]);
+    hasher.update(public_key.as_bytes());
+    hasher.update(msg);
+    Ok(hash_to_scalar(hasher))
+}
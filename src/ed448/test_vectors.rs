@@ -0,0 +1,71 @@
+use crate::test_vector::{ContextTestVector, TestVector, TestVectorAlgorithm};
+
+/// Ed448 test vectors with an empty context string (from RFC 8032,
+/// converted to Rust bytestring literals)
+#[rustfmt::skip]
+pub const TEST_VECTORS: &[TestVector] = &[
+    TestVector {
+        alg: TestVectorAlgorithm::Ed448,
+        sk: b"\x6C\x82\xA5\x62\xCB\x80\x8D\x10\xD6\x32\xBE\x89\xC8\x51\x3E\xBF\x6C\x92\x9F\x34\xDD\xFA\x8C\x9F\x63\xC9\x96\x0E\xF6\xE3\x48\xA3\x52\x8C\x8A\x3F\xCC\x2F\x04\x4E\x39\xA3\xFC\x5B\x94\x49\x2F\x8F\x03\x2E\x75\x49\xA2\x00\x98\xF9\x5B",
+        pk: b"\x5F\xD7\x44\x9B\x59\xB4\x61\xFD\x2C\xE7\x87\xEC\x61\x6A\xD4\x6A\x1D\xA1\x34\x24\x85\xA7\x0E\x1F\x8A\x0E\xA7\x5D\x80\xE9\x67\x78\xED\xF1\x24\x76\x9B\x46\xC7\x06\x1B\xD6\x78\x3D\xF1\xE5\x0F\x6C\xD1\xFA\x1A\xBE\xAF\xE8\x25\x61\x80",
+        nonce: None, // Ed448 uses deterministic nonces,
+        msg: b"",
+        sig: b"\x53\x3A\x37\xF6\xBB\xE4\x57\x25\x1F\x02\x3C\x0D\x88\xF9\x76\xAE\x2D\xFB\x50\x4A\x84\x3E\x34\xD2\x07\x4F\xD8\x23\xD4\x1A\x59\x1F\x2B\x23\x3F\x03\x4F\x62\x82\x81\xF2\xFD\x7A\x22\xDD\xD4\x7D\x78\x28\xC5\x9B\xD0\xA2\x1B\xFD\x39\x80\xFF\x0D\x20\x28\xD4\xB1\x8A\x9D\xF6\x3E\x00\x6C\x5D\x1C\x2D\x34\x5B\x92\x5D\x8D\xC0\x0B\x41\x04\x85\x2D\xB9\x9A\xC5\xC7\xCD\xDA\x85\x30\xA1\x13\xA0\xF4\xDB\xB6\x11\x49\xF0\x5A\x73\x63\x26\x8C\x71\xD9\x58\x08\xFF\x2E\x65\x26\x00",
+        pass: true
+    },
+    TestVector {
+        alg: TestVectorAlgorithm::Ed448,
+        sk: b"\xC4\xEA\xB0\x5D\x35\x70\x07\xC6\x32\xF3\xDB\xB4\x84\x89\x92\x4D\x55\x2B\x08\xFE\x0C\x35\x3A\x0D\x4A\x1F\x00\xAC\xDA\x2C\x46\x3A\xFB\xEA\x67\xC5\xE8\xD2\x87\x7C\x5E\x3B\xC3\x97\xA6\x59\x94\x9E\xF8\x02\x1E\x95\x4E\x0A\x12\x27\x4E",
+        pk: b"\x43\xBA\x28\xF4\x30\xCD\xFF\x45\x6A\xE5\x31\x54\x5F\x7E\xCD\x0A\xC8\x34\xA5\x5D\x93\x58\xC0\x37\x2B\xFA\x0C\x6C\x67\x98\xC0\x86\x6A\xEA\x01\xEB\x00\x74\x28\x02\xB8\x43\x8E\xA4\xCB\x82\x16\x9C\x23\x51\x60\x62\x7B\x4C\x3A\x94\x80",
+        nonce: None,
+        msg: b"\x03",
+        sig: b"\x26\xB8\xF9\x17\x27\xBD\x62\x89\x7A\xF1\x5E\x41\xEB\x43\xC3\x77\xEF\xB9\xC6\x10\xD4\x8F\x23\x35\xCB\x0B\xD0\x08\x78\x10\xF4\x35\x25\x41\xB1\x43\xC4\xB9\x81\xB7\xE1\x8F\x62\xDE\x8C\xCD\xF6\x33\xFC\x1B\xF0\x37\xAB\x7C\xD7\x79\x80\x5E\x0D\xBC\xC0\xAA\xE1\xCB\xCE\xE1\xAF\xB2\xE0\x27\xDF\x36\xBC\x04\xDC\xEC\xBF\x15\x43\x36\xC1\x9F\x0A\xF7\xE0\xA6\x47\x29\x05\xE7\x99\xF1\x95\x3D\x2A\x0F\xF3\x34\x8A\xB2\x1A\xA4\xAD\xAF\xD1\xD2\x34\x44\x1C\xF8\x07\xC0\x3A\x00",
+        pass: true
+    },
+];
+
+/// Ed448 test vectors with a non-empty context string (from RFC 8032)
+#[rustfmt::skip]
+pub const ED448_CONTEXT_TEST_VECTORS: &[ContextTestVector] = &[
+    ContextTestVector {
+        ctx: b"\x66\x6F\x6F",
+        vector: TestVector {
+            alg: TestVectorAlgorithm::Ed448,
+            sk: b"\xC4\xEA\xB0\x5D\x35\x70\x07\xC6\x32\xF3\xDB\xB4\x84\x89\x92\x4D\x55\x2B\x08\xFE\x0C\x35\x3A\x0D\x4A\x1F\x00\xAC\xDA\x2C\x46\x3A\xFB\xEA\x67\xC5\xE8\xD2\x87\x7C\x5E\x3B\xC3\x97\xA6\x59\x94\x9E\xF8\x02\x1E\x95\x4E\x0A\x12\x27\x4E",
+            pk: b"\x43\xBA\x28\xF4\x30\xCD\xFF\x45\x6A\xE5\x31\x54\x5F\x7E\xCD\x0A\xC8\x34\xA5\x5D\x93\x58\xC0\x37\x2B\xFA\x0C\x6C\x67\x98\xC0\x86\x6A\xEA\x01\xEB\x00\x74\x28\x02\xB8\x43\x8E\xA4\xCB\x82\x16\x9C\x23\x51\x60\x62\x7B\x4C\x3A\x94\x80",
+            nonce: None,
+            msg: b"\x03",
+            sig: b"\xD4\xF8\xF6\x13\x17\x70\xDD\x46\xF4\x08\x67\xD6\xFD\x5D\x50\x55\xDE\x43\x54\x1F\x8C\x5E\x35\xAB\xBC\xD0\x01\xB3\x2A\x89\xF7\xD2\x15\x1F\x76\x47\xF1\x1D\x8C\xA2\xAE\x27\x9F\xB8\x42\xD6\x07\x21\x7F\xCE\x6E\x04\x2F\x68\x15\xEA\x00\x0C\x85\x74\x1D\xE5\xC8\xDA\x11\x44\xA6\xA1\xAB\xA7\xF9\x6D\xE4\x25\x05\xD7\xA7\x29\x85\x24\xFD\xA5\x38\xFC\xCB\xBB\x75\x4F\x57\x8C\x1C\xAD\x10\xD5\x4D\x0D\x54\x28\x40\x7E\x85\xDC\xBC\x98\xA4\x91\x55\xC1\x37\x64\xE6\x6C\x3C\x00",
+            pass: true
+        },
+    },
+];
+
+/// Ed448ph test vectors (from RFC 8032)
+#[rustfmt::skip]
+pub const ED448PH_TEST_VECTORS: &[ContextTestVector] = &[
+    ContextTestVector {
+        ctx: b"",
+        vector: TestVector {
+            alg: TestVectorAlgorithm::Ed448ph,
+            sk: b"\x83\x3F\xE6\x24\x09\x23\x7B\x9D\x62\xEC\x77\x58\x75\x20\x91\x1E\x9A\x75\x9C\xEC\x1D\x19\x75\x5B\x7D\xA9\x01\xB9\x6D\xCA\x3D\x42\xEF\x78\x22\xE0\xD5\x10\x41\x27\xDC\x05\xD6\xDB\xEF\xDE\x69\xE3\xAB\x2C\xEC\x7C\x86\x7C\x6E\x2C\x49",
+            pk: b"\x25\x9B\x71\xC1\x9F\x83\xEF\x77\xA7\xAB\xD2\x65\x24\xCB\xDB\x31\x61\xB5\x90\xA4\x8F\x7D\x17\xDE\x3E\xE0\xBA\x9C\x52\xBE\xB7\x43\xC0\x94\x28\xA1\x31\xD6\xB1\xB5\x73\x03\xD9\x0D\x81\x32\xC2\x76\xD5\xED\x3D\x5D\x01\xC0\xF5\x38\x80",
+            nonce: None,
+            msg: b"\x61\x62\x63",
+            sig: b"\x82\x2F\x69\x01\xF7\x48\x0F\x3D\x5F\x56\x2C\x59\x29\x94\xD9\x69\x36\x02\x87\x56\x14\x48\x32\x56\x50\x56\x00\xBB\xC2\x81\xAE\x38\x1F\x54\xD6\xBC\xE2\xEA\x91\x15\x74\x93\x2F\x52\xA4\xE6\xCA\xDD\x78\x76\x93\x75\xEC\x3F\xFD\x1B\x80\x1A\x0D\x9B\x3F\x40\x30\xCD\x43\x39\x64\xB6\x45\x7E\xA3\x94\x76\x51\x12\x14\xF9\x74\x69\xB5\x7D\xD3\x2D\xBC\x56\x0A\x9A\x94\xD0\x0B\xFF\x07\x62\x04\x64\xA3\xAD\x20\x3D\xF7\xDC\x7C\xE3\x60\xC3\xCD\x36\x96\xD9\xD9\xFA\xB9\x0F\x00",
+            pass: true
+        },
+    },
+    ContextTestVector {
+        ctx: b"\x66\x6F\x6F",
+        vector: TestVector {
+            alg: TestVectorAlgorithm::Ed448ph,
+            sk: b"\x83\x3F\xE6\x24\x09\x23\x7B\x9D\x62\xEC\x77\x58\x75\x20\x91\x1E\x9A\x75\x9C\xEC\x1D\x19\x75\x5B\x7D\xA9\x01\xB9\x6D\xCA\x3D\x42\xEF\x78\x22\xE0\xD5\x10\x41\x27\xDC\x05\xD6\xDB\xEF\xDE\x69\xE3\xAB\x2C\xEC\x7C\x86\x7C\x6E\x2C\x49",
+            pk: b"\x25\x9B\x71\xC1\x9F\x83\xEF\x77\xA7\xAB\xD2\x65\x24\xCB\xDB\x31\x61\xB5\x90\xA4\x8F\x7D\x17\xDE\x3E\xE0\xBA\x9C\x52\xBE\xB7\x43\xC0\x94\x28\xA1\x31\xD6\xB1\xB5\x73\x03\xD9\x0D\x81\x32\xC2\x76\xD5\xED\x3D\x5D\x01\xC0\xF5\x38\x80",
+            nonce: None,
+            msg: b"\x61\x62\x63",
+            sig: b"\xC3\x22\x99\xD4\x6E\xC8\xFF\x02\xB5\x45\x40\x98\x28\x14\xDC\xE9\xA0\x58\x12\xF8\x19\x62\xB6\x49\xD5\x28\x09\x59\x16\xA2\xAA\x48\x10\x65\xB1\x58\x04\x23\xEF\x92\x7E\xCF\x0A\xF5\x88\x8F\x90\xDA\x0F\x6A\x9A\x85\xAD\x5D\xC3\xF2\x80\xD9\x12\x24\xBA\x99\x11\xA3\x65\x3D\x00\xE4\x84\xE2\xCE\x23\x25\x21\x48\x1C\x86\x58\xDF\x30\x4B\xB7\x74\x5A\x73\x51\x4C\xDB\x9B\xF3\xE1\x57\x84\xAB\x71\x28\x4F\x8D\x07\x04\xA6\x08\xC5\x4A\x6B\x62\xD9\x7B\xEB\x51\x1D\x13\x21\x00",
+            pass: true
+        },
+    },
+];
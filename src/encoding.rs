@@ -1,24 +1,94 @@
-//! Support for encoding and decoding serialization formats (hex and Base64)
-//! with implementations that do not branch on potentially secret data, such
-//! as cryptographic keys.
+//! Support for encoding and decoding serialization formats (hex, Base64,
+//! PEM, PKCS#8, SEC1, SPKI, OpenSSH, JWK, JWS, and COSE) with implementations
+//! that do not branch on potentially secret data, such as cryptographic keys.
 
 #[macro_use]
 mod macros;
 
 pub use subtle_encoding::{Base64, Hex, Identity};
+#[cfg(any(
+    feature = "cose",
+    feature = "jwk",
+    feature = "jws",
+    feature = "openssh",
+    feature = "pkcs8",
+    feature = "sec1",
+    feature = "spki"
+))]
+mod algorithm;
+#[cfg(any(feature = "jwk", feature = "jws"))]
+mod base64url;
+#[cfg(feature = "cose")]
+mod cbor;
+#[cfg(feature = "cose")]
+pub mod cose;
 mod decode;
+#[cfg(any(feature = "pkcs8", feature = "sec1", feature = "spki"))]
+mod der;
 #[cfg(feature = "alloc")]
 mod encode;
 pub mod error;
+#[cfg(any(feature = "jwk", feature = "jws"))]
+mod json;
+#[cfg(feature = "jwk")]
+pub mod jwk;
+#[cfg(feature = "jws")]
+pub mod jws;
+#[cfg(feature = "openssh")]
+pub mod openssh;
+#[cfg(feature = "pem")]
+pub mod pem;
 #[cfg(feature = "pkcs8")]
 pub mod pkcs8;
+#[cfg(feature = "sec1")]
+pub mod sec1;
+#[cfg(feature = "spki")]
+pub mod spki;
 
+#[cfg(any(
+    feature = "cose",
+    feature = "jwk",
+    feature = "jws",
+    feature = "openssh",
+    feature = "pkcs8",
+    feature = "sec1",
+    feature = "spki"
+))]
+pub use self::algorithm::Algorithm;
+#[cfg(feature = "cose")]
+pub use self::cose::{FromCoseKey, ToCoseKey};
 #[cfg(feature = "alloc")]
 pub use self::encode::Encode;
+#[cfg(feature = "jwk")]
+pub use self::jwk::{FromJwk, Jwk, JwkSet, ToJwk};
+#[cfg(feature = "openssh")]
+pub use self::openssh::{FromOpenSshPrivateKey, FromOpenSshPublicKey, ToOpenSshPublicKey};
 #[cfg(feature = "pkcs8")]
 pub use self::pkcs8::FromPkcs8;
+#[cfg(feature = "sec1")]
+pub use self::sec1::FromSec1;
+#[cfg(all(feature = "alloc", feature = "sec1"))]
+pub use self::sec1::ToSec1;
+#[cfg(feature = "spki")]
+pub use self::spki::FromPublicKeyDer;
+#[cfg(all(feature = "alloc", feature = "spki"))]
+pub use self::spki::ToPublicKeyDer;
 pub use self::{decode::Decode, error::Error};
 
 /// Mode to use for newly created files
 #[cfg(all(unix, feature = "std"))]
 pub const FILE_MODE: u32 = 0o600;
+
+/// Open an existing file containing an encoded key
+#[cfg(all(
+    feature = "std",
+    any(feature = "openssh", feature = "pkcs8", feature = "sec1")
+))]
+pub(crate) fn open_file(path: &std::path::Path) -> Result<std::fs::File, Error> {
+    std::fs::File::open(path).map_err(|e| {
+        Error::new(
+            error::ErrorKind::Io,
+            Some(&format!("couldn't open {}: {}", path.display(), e)),
+        )
+    })
+}
@@ -0,0 +1,217 @@
+//! Algorithm identifiers (i.e. `AlgorithmIdentifier` as described in
+//! [RFC 5280 Section 4.1.1.2]) for the key types supported by Signatory.
+//!
+//! [RFC 5280 Section 4.1.1.2]: https://tools.ietf.org/html/rfc5280#section-4.1.1.2
+
+#[cfg(any(feature = "pkcs8", feature = "spki"))]
+use super::der::Decoder;
+#[cfg(all(feature = "alloc", any(feature = "pkcs8", feature = "spki")))]
+use super::der::{self, Encoder};
+#[cfg(any(
+    feature = "cose",
+    feature = "jwk",
+    feature = "openssh",
+    feature = "pkcs8",
+    feature = "sec1",
+    feature = "spki"
+))]
+use super::error::{decode_error, Error};
+use core::fmt::{self, Display};
+
+/// `id-ecPublicKey` OID (1.2.840.10045.2.1): RFC 5480 Section 2.1.1
+#[cfg(any(feature = "pkcs8", feature = "spki"))]
+const EC_PUBLIC_KEY_OID: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01];
+
+/// `secp256r1` (a.k.a. `prime256v1`) OID (1.2.840.10045.3.1.7)
+#[cfg(any(feature = "pkcs8", feature = "sec1", feature = "spki"))]
+const NIST_P256_OID: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07];
+
+/// `secp384r1` OID (1.3.132.0.34)
+#[cfg(any(feature = "pkcs8", feature = "sec1", feature = "spki"))]
+const NIST_P384_OID: &[u8] = &[0x2b, 0x81, 0x04, 0x00, 0x22];
+
+/// `secp521r1` OID (1.3.132.0.35)
+#[cfg(any(feature = "pkcs8", feature = "sec1", feature = "spki"))]
+const NIST_P521_OID: &[u8] = &[0x2b, 0x81, 0x04, 0x00, 0x23];
+
+/// `secp256k1` OID (1.3.132.0.10)
+#[cfg(any(feature = "pkcs8", feature = "sec1", feature = "spki"))]
+const SECP256K1_OID: &[u8] = &[0x2b, 0x81, 0x04, 0x00, 0x0a];
+
+/// `id-Ed25519` OID (1.3.101.112): RFC 8410 Section 3
+#[cfg(any(feature = "pkcs8", feature = "spki"))]
+const ED25519_OID: &[u8] = &[0x2b, 0x65, 0x70];
+
+/// `id-Ed448` OID (1.3.101.113): RFC 8410 Section 3
+#[cfg(any(feature = "pkcs8", feature = "spki"))]
+const ED448_OID: &[u8] = &[0x2b, 0x65, 0x71];
+
+/// Key algorithms which can be identified by an `AlgorithmIdentifier`
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Algorithm {
+    /// Ed25519 (RFC 8410)
+    Ed25519,
+
+    /// Ed448 (RFC 8410)
+    Ed448,
+
+    /// ECDSA with the NIST P-256 (a.k.a. prime256v1, secp256r1) elliptic curve
+    NistP256,
+
+    /// ECDSA with the NIST P-384 (a.k.a. secp384r1) elliptic curve
+    NistP384,
+
+    /// ECDSA with the NIST P-521 (a.k.a. secp521r1) elliptic curve
+    NistP521,
+
+    /// ECDSA with the secp256k1 elliptic curve
+    Secp256k1,
+}
+
+impl Algorithm {
+    /// Size of a secret key (i.e. seed or scalar) for this algorithm in bytes
+    pub fn secret_key_size(self) -> usize {
+        match self {
+            Algorithm::Ed25519 | Algorithm::NistP256 | Algorithm::Secp256k1 => 32,
+            Algorithm::NistP384 => 48,
+            Algorithm::Ed448 => 57,
+            Algorithm::NistP521 => 66,
+        }
+    }
+
+    /// Is this an elliptic curve algorithm whose keys are identified by the
+    /// `id-ecPublicKey` OID and a named curve parameter?
+    pub fn is_ecdsa(self) -> bool {
+        !matches!(self, Algorithm::Ed25519 | Algorithm::Ed448)
+    }
+
+    /// Check that the given serialized public key is well-formed for this
+    /// algorithm.
+    ///
+    /// Ed25519 and Ed448 public keys must be 32 and 57 bytes respectively.
+    /// Elliptic curve public keys must be SEC1-encoded points in either
+    /// compressed or uncompressed form.
+    #[cfg(any(
+        feature = "cose",
+        feature = "jwk",
+        feature = "openssh",
+        feature = "pkcs8",
+        feature = "sec1",
+        feature = "spki"
+    ))]
+    pub(crate) fn check_public_key(self, public_key: &[u8]) -> Result<(), Error> {
+        let size = self.secret_key_size();
+
+        let valid = match public_key.first() {
+            _ if !self.is_ecdsa() => public_key.len() == size,
+            Some(0x02) | Some(0x03) => public_key.len() == add!(size, 1),
+            Some(0x04) => public_key.len() == add!(mul!(size, 2), 1),
+            _ => false,
+        };
+
+        if valid {
+            Ok(())
+        } else {
+            Err(decode_error("malformed public key"))
+        }
+    }
+
+    /// Get the OID of the named curve for elliptic curve algorithms
+    #[cfg(any(feature = "pkcs8", feature = "sec1", feature = "spki"))]
+    pub(crate) fn curve_oid(self) -> Option<&'static [u8]> {
+        match self {
+            Algorithm::Ed25519 | Algorithm::Ed448 => None,
+            Algorithm::NistP256 => Some(NIST_P256_OID),
+            Algorithm::NistP384 => Some(NIST_P384_OID),
+            Algorithm::NistP521 => Some(NIST_P521_OID),
+            Algorithm::Secp256k1 => Some(SECP256K1_OID),
+        }
+    }
+
+    /// Find the elliptic curve algorithm with the given named curve OID
+    #[cfg(any(feature = "pkcs8", feature = "sec1", feature = "spki"))]
+    pub(crate) fn from_curve_oid(oid: &[u8]) -> Result<Self, Error> {
+        [
+            Algorithm::NistP256,
+            Algorithm::NistP384,
+            Algorithm::NistP521,
+            Algorithm::Secp256k1,
+        ]
+        .iter()
+        .find(|alg| alg.curve_oid() == Some(oid))
+        .cloned()
+        .ok_or_else(|| decode_error("unsupported elliptic curve"))
+    }
+
+    /// Decode an `AlgorithmIdentifier`
+    #[cfg(any(feature = "pkcs8", feature = "spki"))]
+    pub(crate) fn decode(decoder: &mut Decoder<'_>) -> Result<Self, Error> {
+        let mut alg_id = decoder.sequence()?;
+        let oid = alg_id.oid()?;
+
+        // RFC 8410 Section 3: parameters MUST be absent for Ed25519/Ed448
+        let algorithm = if oid == ED25519_OID {
+            Algorithm::Ed25519
+        } else if oid == ED448_OID {
+            Algorithm::Ed448
+        } else if oid == EC_PUBLIC_KEY_OID {
+            Self::from_curve_oid(alg_id.oid()?)?
+        } else {
+            return Err(decode_error("unsupported algorithm OID"));
+        };
+
+        alg_id.finish()?;
+        Ok(algorithm)
+    }
+
+    /// Length of this algorithm's serialized `AlgorithmIdentifier`
+    #[cfg(all(feature = "alloc", any(feature = "pkcs8", feature = "spki")))]
+    pub(crate) fn encoded_len(self) -> usize {
+        der::encoded_len(self.contents_len())
+    }
+
+    /// Encode this algorithm as an `AlgorithmIdentifier`
+    #[cfg(all(feature = "alloc", any(feature = "pkcs8", feature = "spki")))]
+    pub(crate) fn encode(self, encoder: &mut Encoder) {
+        encoder.header(der::SEQUENCE, self.contents_len());
+        encoder.value(der::OBJECT_IDENTIFIER, self.oid());
+
+        if let Some(curve_oid) = self.curve_oid() {
+            encoder.value(der::OBJECT_IDENTIFIER, curve_oid);
+        }
+    }
+
+    /// Get the algorithm OID of this algorithm's `AlgorithmIdentifier`
+    #[cfg(all(feature = "alloc", any(feature = "pkcs8", feature = "spki")))]
+    fn oid(self) -> &'static [u8] {
+        match self {
+            Algorithm::Ed25519 => ED25519_OID,
+            Algorithm::Ed448 => ED448_OID,
+            _ => EC_PUBLIC_KEY_OID,
+        }
+    }
+
+    /// Length of the contents of the `AlgorithmIdentifier` sequence
+    #[cfg(all(feature = "alloc", any(feature = "pkcs8", feature = "spki")))]
+    fn contents_len(self) -> usize {
+        let oid_len = der::encoded_len(self.oid().len());
+
+        match self.curve_oid() {
+            Some(curve_oid) => add!(oid_len, der::encoded_len(curve_oid.len())),
+            None => oid_len,
+        }
+    }
+}
+
+impl Display for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Algorithm::Ed25519 => "Ed25519",
+            Algorithm::Ed448 => "Ed448",
+            Algorithm::NistP256 => "NIST P-256",
+            Algorithm::NistP384 => "NIST P-384",
+            Algorithm::NistP521 => "NIST P-521",
+            Algorithm::Secp256k1 => "secp256k1",
+        })
+    }
+}
@@ -0,0 +1,75 @@
+//! URL-safe Base64 without padding ("base64url") as described in
+//! [RFC 4648 Section 5], as used by the JOSE formats (e.g. JWK and JWS).
+//!
+//! Implemented by translating to/from the alphabet of the constant-time
+//! Base64 implementation in `subtle-encoding` without branching on the data.
+//!
+//! [RFC 4648 Section 5]: https://tools.ietf.org/html/rfc4648#section-5
+
+use super::error::{decode_error, Error};
+use alloc::{string::String, vec, vec::Vec};
+use subtle_encoding::{Base64, Encoding};
+use zeroize::Zeroizing;
+
+/// Encode the given bytes as unpadded base64url
+pub(crate) fn encode(bytes: &[u8]) -> String {
+    let encoding = Base64::default();
+    let mut base64 = Zeroizing::new(vec![0u8; encoding.encoded_len(bytes)]);
+    encoding
+        .encode_to_slice(bytes, &mut base64)
+        .expect("base64 buffer size mismatch");
+
+    for byte in base64.iter_mut() {
+        *byte = replace(*byte, b'+', b'-');
+        *byte = replace(*byte, b'/', b'_');
+    }
+
+    // Remove padding, the amount of which depends only on the input length
+    base64.truncate(div!(add!(mul!(bytes.len(), 4), 2), 3));
+
+    String::from_utf8(base64.to_vec()).expect("base64 wasn't ASCII")
+}
+
+/// Decode the given unpadded base64url string
+pub(crate) fn decode(encoded: &str) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let encoded = encoded.as_bytes();
+
+    // A single character remaining after the last full group is never valid
+    if encoded.len() % 4 == 1 {
+        return Err(decode_error("malformed base64url"));
+    }
+
+    let padded_len = add!(encoded.len(), 3usize) & !3;
+    let mut base64 = Zeroizing::new(vec![b'='; padded_len]);
+    let mut invalid = 0u8;
+
+    for (src, dst) in encoded.iter().zip(base64.iter_mut()) {
+        // Reject characters from the standard alphabet and padding
+        invalid |= eq_mask(*src, b'+') | eq_mask(*src, b'/') | eq_mask(*src, b'=');
+        *dst = replace(*src, b'-', b'+');
+        *dst = replace(*dst, b'_', b'/');
+    }
+
+    if invalid != 0 {
+        return Err(decode_error("malformed base64url"));
+    }
+
+    let encoding = Base64::default();
+    let mut decoded = Zeroizing::new(vec![0u8; encoding.decoded_len(&base64)?]);
+    let decoded_len = encoding
+        .decode_to_slice(&base64, &mut decoded)
+        .map_err(|_| decode_error("malformed base64url"))?;
+
+    decoded.truncate(decoded_len);
+    Ok(decoded)
+}
+
+/// Replace `byte` with `to` if it's equal to `from`, without branching
+fn replace(byte: u8, from: u8, to: u8) -> u8 {
+    byte ^ (eq_mask(byte, from) & (from ^ to))
+}
+
+/// Return `0xFF` if `a == b`, or `0` otherwise, without branching
+fn eq_mask(a: u8, b: u8) -> u8 {
+    (u16::from(a ^ b).wrapping_sub(1) >> 8) as u8
+}
@@ -0,0 +1,298 @@
+//! Minimal CBOR ([RFC 8949]) support for COSE.
+//!
+//! Only definite-length items are supported, and floating point values are
+//! rejected. Integers are limited to the range of `i64`, which covers every
+//! label and value used by COSE. Maps may have at most 32 entries (COSE
+//! headers and keys only use a handful of labels), which keeps checking them
+//! for duplicate keys cheap.
+//!
+//! Encoding always uses the shortest form of each item header, and map
+//! entries are emitted in the order given, so callers are responsible for
+//! ordering them as described in RFC 8949 Section 4.2.1 ("Core Deterministic
+//! Encoding Requirements").
+//!
+//! [RFC 8949]: https://tools.ietf.org/html/rfc8949
+
+use super::error::{decode_error, Error};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{convert::TryFrom, str};
+
+/// Maximum nesting depth of arrays, maps, and tags
+const MAX_DEPTH: usize = 16;
+
+/// Maximum number of entries in a map
+const MAX_MAP_ENTRIES: usize = 32;
+
+/// Major type: unsigned integer
+const MAJOR_UNSIGNED: u8 = 0;
+
+/// Major type: negative integer
+const MAJOR_NEGATIVE: u8 = 1;
+
+/// Major type: byte string
+const MAJOR_BYTES: u8 = 2;
+
+/// Major type: text string
+const MAJOR_TEXT: u8 = 3;
+
+/// Major type: array
+const MAJOR_ARRAY: u8 = 4;
+
+/// Major type: map
+const MAJOR_MAP: u8 = 5;
+
+/// Major type: tag
+const MAJOR_TAG: u8 = 6;
+
+/// Major type: simple values and floats
+const MAJOR_SIMPLE: u8 = 7;
+
+/// Simple value: `false`
+const SIMPLE_FALSE: u8 = 20;
+
+/// Simple value: `true`
+const SIMPLE_TRUE: u8 = 21;
+
+/// Simple value: `null`
+const SIMPLE_NULL: u8 = 22;
+
+/// CBOR data items
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Value {
+    /// Unsigned or negative integer
+    Int(i64),
+
+    /// Byte string
+    Bytes(Vec<u8>),
+
+    /// Text string
+    Text(String),
+
+    /// Array
+    Array(Vec<Value>),
+
+    /// Map (in the order the entries were encoded)
+    Map(Vec<(Value, Value)>),
+
+    /// Tagged item
+    Tag(u64, Box<Value>),
+
+    /// `true` or `false`
+    Bool(bool),
+
+    /// `null`
+    Null,
+}
+
+impl Value {
+    /// Decode a single data item which must span the entire input
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let mut decoder = Decoder { bytes, pos: 0 };
+        let value = decoder.value(0)?;
+
+        if decoder.pos == bytes.len() {
+            Ok(value)
+        } else {
+            Err(decode_error("trailing data after CBOR item"))
+        }
+    }
+
+    /// Encode this data item
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        self.encode(&mut bytes);
+        bytes
+    }
+
+    /// Look up the value for the given integer label in a map
+    pub fn get(&self, label: i64) -> Option<&Value> {
+        match self {
+            Value::Map(entries) => entries
+                .iter()
+                .find(|(key, _)| *key == Value::Int(label))
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    /// Encode this data item, appending it to the given buffer
+    fn encode(&self, bytes: &mut Vec<u8>) {
+        match self {
+            Value::Int(n) if *n >= 0 => header(bytes, MAJOR_UNSIGNED, *n as u64),
+            Value::Int(n) => header(bytes, MAJOR_NEGATIVE, !*n as u64),
+            Value::Bytes(b) => {
+                header(bytes, MAJOR_BYTES, b.len() as u64);
+                bytes.extend_from_slice(b);
+            }
+            Value::Text(s) => {
+                header(bytes, MAJOR_TEXT, s.len() as u64);
+                bytes.extend_from_slice(s.as_bytes());
+            }
+            Value::Array(items) => {
+                header(bytes, MAJOR_ARRAY, items.len() as u64);
+
+                for item in items {
+                    item.encode(bytes);
+                }
+            }
+            Value::Map(entries) => {
+                header(bytes, MAJOR_MAP, entries.len() as u64);
+
+                for (key, value) in entries {
+                    key.encode(bytes);
+                    value.encode(bytes);
+                }
+            }
+            Value::Tag(tag, item) => {
+                header(bytes, MAJOR_TAG, *tag);
+                item.encode(bytes);
+            }
+            Value::Bool(false) => bytes.push(shl!(MAJOR_SIMPLE, 5) | SIMPLE_FALSE),
+            Value::Bool(true) => bytes.push(shl!(MAJOR_SIMPLE, 5) | SIMPLE_TRUE),
+            Value::Null => bytes.push(shl!(MAJOR_SIMPLE, 5) | SIMPLE_NULL),
+        }
+    }
+}
+
+/// CBOR decoder
+struct Decoder<'a> {
+    /// Input being decoded
+    bytes: &'a [u8],
+
+    /// Current position
+    pos: usize,
+}
+
+impl<'a> Decoder<'a> {
+    /// Decode a data item
+    fn value(&mut self, depth: usize) -> Result<Value, Error> {
+        if depth >= MAX_DEPTH {
+            return Err(decode_error("CBOR nesting too deep"));
+        }
+
+        let initial = self.byte()?;
+        let major = shr!(initial, 5);
+        let info = initial & 0x1f;
+
+        if major == MAJOR_SIMPLE {
+            return match info {
+                SIMPLE_FALSE => Ok(Value::Bool(false)),
+                SIMPLE_TRUE => Ok(Value::Bool(true)),
+                SIMPLE_NULL => Ok(Value::Null),
+                _ => Err(decode_error("unsupported CBOR simple value")),
+            };
+        }
+
+        let arg = self.argument(info)?;
+
+        match major {
+            MAJOR_UNSIGNED => i64::try_from(arg)
+                .map(Value::Int)
+                .map_err(|_| decode_error("CBOR integer out of range")),
+            MAJOR_NEGATIVE => i64::try_from(arg)
+                .map(|n| Value::Int(!n))
+                .map_err(|_| decode_error("CBOR integer out of range")),
+            MAJOR_BYTES => Ok(Value::Bytes(self.take(arg)?.to_vec())),
+            MAJOR_TEXT => str::from_utf8(self.take(arg)?)
+                .map(|s| Value::Text(String::from(s)))
+                .map_err(|_| decode_error("CBOR text isn't UTF-8")),
+            MAJOR_ARRAY => {
+                let mut items = Vec::new();
+
+                for _ in 0..self.count(arg)? {
+                    items.push(self.value(add!(depth, 1))?);
+                }
+
+                Ok(Value::Array(items))
+            }
+            MAJOR_MAP => {
+                let count = self.count(arg)?;
+
+                if count > MAX_MAP_ENTRIES {
+                    return Err(decode_error("too many CBOR map entries"));
+                }
+
+                let mut entries: Vec<(Value, Value)> = Vec::with_capacity(count);
+
+                for _ in 0..count {
+                    let key = self.value(add!(depth, 1))?;
+
+                    if entries.iter().any(|(k, _)| *k == key) {
+                        return Err(decode_error("duplicate CBOR map key"));
+                    }
+
+                    let value = self.value(add!(depth, 1))?;
+                    entries.push((key, value));
+                }
+
+                Ok(Value::Map(entries))
+            }
+            _ => Ok(Value::Tag(arg, Box::new(self.value(add!(depth, 1))?))),
+        }
+    }
+
+    /// Decode the argument of an item header with the given additional info
+    fn argument(&mut self, info: u8) -> Result<u64, Error> {
+        let len = match info {
+            0..=23 => return Ok(u64::from(info)),
+            24 => 1,
+            25 => 2,
+            26 => 4,
+            27 => 8,
+            _ => return Err(decode_error("unsupported CBOR indefinite-length item")),
+        };
+
+        Ok(self
+            .take(len)?
+            .iter()
+            .fold(0u64, |arg, byte| shl!(arg, 8) | u64::from(*byte)))
+    }
+
+    /// Get the number of items in an array or map, ensuring there is at
+    /// least one byte of input remaining for each of them
+    fn count(&self, arg: u64) -> Result<usize, Error> {
+        usize::try_from(arg)
+            .ok()
+            .filter(|&count| count <= sub!(self.bytes.len(), self.pos))
+            .ok_or_else(|| decode_error("truncated CBOR item"))
+    }
+
+    /// Take the given number of bytes from the input
+    fn take(&mut self, len: u64) -> Result<&'a [u8], Error> {
+        let end = usize::try_from(len)
+            .ok()
+            .and_then(|len| self.pos.checked_add(len))
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or_else(|| decode_error("truncated CBOR item"))?;
+
+        let bytes = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    /// Take a single byte from the input
+    fn byte(&mut self) -> Result<u8, Error> {
+        self.take(1).map(|bytes| bytes[0])
+    }
+}
+
+/// Encode an item header using the shortest possible form
+fn header(bytes: &mut Vec<u8>, major: u8, arg: u64) {
+    let major = shl!(major, 5);
+
+    if arg < 24 {
+        bytes.push(major | arg as u8);
+    } else if arg <= 0xff {
+        bytes.push(major | 24);
+        bytes.push(arg as u8);
+    } else if arg <= 0xffff {
+        bytes.push(major | 25);
+        bytes.extend_from_slice(&(arg as u16).to_be_bytes());
+    } else if arg <= 0xffff_ffff {
+        bytes.push(major | 26);
+        bytes.extend_from_slice(&(arg as u32).to_be_bytes());
+    } else {
+        bytes.push(major | 27);
+        bytes.extend_from_slice(&arg.to_be_bytes());
+    }
+}
@@ -0,0 +1,596 @@
+//! Support for CBOR Object Signing and Encryption (COSE) as described in
+//! [RFC 8152] (and its successor [RFC 9052]): single-signer `COSE_Sign1`
+//! messages and `COSE_Key` public keys.
+//!
+//! The following algorithms are supported:
+//!
+//! - `EdDSA` (-8): Ed25519
+//! - `ES256` (-7): ECDSA with NIST P-256 and SHA-256
+//! - `ES384` (-35): ECDSA with NIST P-384 and SHA-384
+//! - `ES512` (-36): ECDSA with NIST P-521 and SHA-512
+//! - `ES256K` (-47): ECDSA with secp256k1 and SHA-256 ([RFC 8812])
+//!
+//! Messages can be signed and verified using any Signatory provider which
+//! implements `signature::Signer` or `signature::Verifier` for one of the
+//! corresponding signature types. The algorithm must be identified in the
+//! protected header, and is always checked against the signature type of
+//! the verifier.
+//!
+//! [RFC 8152]: https://tools.ietf.org/html/rfc8152
+//! [RFC 8812]: https://tools.ietf.org/html/rfc8812
+//! [RFC 9052]: https://tools.ietf.org/html/rfc9052
+
+use super::{
+    cbor::Value,
+    error::{crypto_error, decode_error, Error},
+    Algorithm,
+};
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
+use signature::{Signature, Signer, Verifier};
+
+/// CBOR tag identifying a `COSE_Sign1` message
+const COSE_SIGN1_TAG: u64 = 18;
+
+/// Context string of the `Sig_structure` for `COSE_Sign1`
+const SIGNATURE1_CONTEXT: &str = "Signature1";
+
+/// Header parameter: algorithm
+const HEADER_ALG: i64 = 1;
+
+/// Header parameter: critical headers
+const HEADER_CRIT: i64 = 2;
+
+/// Header parameter: key identifier
+const HEADER_KID: i64 = 4;
+
+/// `COSE_Key` parameter: key type
+const KEY_KTY: i64 = 1;
+
+/// `COSE_Key` parameter: key identifier
+const KEY_KID: i64 = 2;
+
+/// `COSE_Key` parameter: algorithm
+const KEY_ALG: i64 = 3;
+
+/// `COSE_Key` parameter: curve
+const KEY_CRV: i64 = -1;
+
+/// `COSE_Key` parameter: x-coordinate (or OKP public key)
+const KEY_X: i64 = -2;
+
+/// `COSE_Key` parameter: y-coordinate (or its sign bit)
+const KEY_Y: i64 = -3;
+
+/// Key type: Octet Key Pair
+const KTY_OKP: i64 = 1;
+
+/// Key type: elliptic curve keys with x and y coordinates
+const KTY_EC2: i64 = 2;
+
+/// Decode a public key from a `COSE_Key`
+pub trait FromCoseKey: Sized {
+    /// Parse the given CBOR-encoded `COSE_Key`, returning an error if it's
+    /// malformed or for a different algorithm
+    fn from_cose_key(bytes: &[u8]) -> Result<Self, Error>;
+}
+
+/// Encode a public key as a `COSE_Key`
+pub trait ToCoseKey {
+    /// Serialize this public key as a CBOR-encoded `COSE_Key`
+    fn to_cose_key(&self) -> Result<Vec<u8>, Error>;
+}
+
+/// Signature types which can be used to create `COSE_Sign1` messages
+pub trait CoseSignature: Signature {
+    /// COSE algorithm identifying this signature type
+    const ALGORITHM: CoseAlgorithm;
+}
+
+#[cfg(feature = "ed25519")]
+impl CoseSignature for crate::ed25519::Signature {
+    const ALGORITHM: CoseAlgorithm = CoseAlgorithm::EdDsa;
+}
+
+#[cfg(all(feature = "ecdsa", feature = "p256"))]
+impl CoseSignature for crate::ecdsa::nistp256::FixedSignature {
+    const ALGORITHM: CoseAlgorithm = CoseAlgorithm::Es256;
+}
+
+#[cfg(all(feature = "ecdsa", feature = "p384"))]
+impl CoseSignature for crate::ecdsa::nistp384::FixedSignature {
+    const ALGORITHM: CoseAlgorithm = CoseAlgorithm::Es384;
+}
+
+#[cfg(all(feature = "ecdsa", feature = "p521"))]
+impl CoseSignature for crate::ecdsa::nistp521::FixedSignature {
+    const ALGORITHM: CoseAlgorithm = CoseAlgorithm::Es512;
+}
+
+#[cfg(all(feature = "ecdsa", feature = "k256"))]
+impl CoseSignature for crate::ecdsa::secp256k1::FixedSignature {
+    const ALGORITHM: CoseAlgorithm = CoseAlgorithm::Es256k;
+}
+
+/// COSE signature algorithms
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum CoseAlgorithm {
+    /// `EdDSA` (-8): Ed25519
+    EdDsa,
+
+    /// `ES256` (-7): ECDSA with NIST P-256 and SHA-256
+    Es256,
+
+    /// `ES384` (-35): ECDSA with NIST P-384 and SHA-384
+    Es384,
+
+    /// `ES512` (-36): ECDSA with NIST P-521 and SHA-512
+    Es512,
+
+    /// `ES256K` (-47): ECDSA with secp256k1 and SHA-256
+    Es256k,
+}
+
+impl CoseAlgorithm {
+    /// Get the COSE algorithm identifier
+    pub fn id(self) -> i64 {
+        match self {
+            CoseAlgorithm::EdDsa => -8,
+            CoseAlgorithm::Es256 => -7,
+            CoseAlgorithm::Es384 => -35,
+            CoseAlgorithm::Es512 => -36,
+            CoseAlgorithm::Es256k => -47,
+        }
+    }
+
+    /// Get the algorithm of the keys used with this COSE algorithm
+    pub fn key_algorithm(self) -> Algorithm {
+        match self {
+            CoseAlgorithm::EdDsa => Algorithm::Ed25519,
+            CoseAlgorithm::Es256 => Algorithm::NistP256,
+            CoseAlgorithm::Es384 => Algorithm::NistP384,
+            CoseAlgorithm::Es512 => Algorithm::NistP521,
+            CoseAlgorithm::Es256k => Algorithm::Secp256k1,
+        }
+    }
+
+    /// Get the COSE algorithm used with keys of the given algorithm
+    pub fn for_key_algorithm(algorithm: Algorithm) -> Self {
+        match algorithm {
+            Algorithm::Ed25519 | Algorithm::Ed448 => CoseAlgorithm::EdDsa,
+            Algorithm::NistP256 => CoseAlgorithm::Es256,
+            Algorithm::NistP384 => CoseAlgorithm::Es384,
+            Algorithm::NistP521 => CoseAlgorithm::Es512,
+            Algorithm::Secp256k1 => CoseAlgorithm::Es256k,
+        }
+    }
+
+    /// Find the algorithm with the given COSE algorithm identifier
+    fn from_id(id: i64) -> Result<Self, Error> {
+        [
+            CoseAlgorithm::EdDsa,
+            CoseAlgorithm::Es256,
+            CoseAlgorithm::Es384,
+            CoseAlgorithm::Es512,
+            CoseAlgorithm::Es256k,
+        ]
+        .iter()
+        .find(|alg| alg.id() == id)
+        .cloned()
+        .ok_or_else(|| decode_error("unsupported COSE algorithm"))
+    }
+}
+
+/// `COSE_Sign1` message: a payload with a single signature
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CoseSign1 {
+    /// Serialized protected header (which is covered by the signature)
+    protected: Vec<u8>,
+
+    /// Signature algorithm (from the protected header)
+    alg: CoseAlgorithm,
+
+    /// Key identifier (from the protected or unprotected header)
+    kid: Option<Vec<u8>>,
+
+    /// Payload
+    payload: Vec<u8>,
+
+    /// Raw signature: a 64-byte Ed25519 signature or a fixed-width ECDSA
+    /// signature (i.e. `r || s`)
+    signature: Vec<u8>,
+}
+
+impl CoseSign1 {
+    /// Sign the given payload using the given signer.
+    ///
+    /// The algorithm is placed in the protected header, and the key ID (if
+    /// any) in the unprotected header. The `external_aad` is authenticated
+    /// but not included in the message, and is usually empty.
+    pub fn sign<S, Sig>(
+        signer: &S,
+        kid: Option<&[u8]>,
+        payload: &[u8],
+        external_aad: &[u8],
+    ) -> Result<Self, Error>
+    where
+        S: Signer<Sig>,
+        Sig: CoseSignature,
+    {
+        let protected = Value::Map(vec![(
+            Value::Int(HEADER_ALG),
+            Value::Int(Sig::ALGORITHM.id()),
+        )]);
+
+        let mut cose_sign1 = Self {
+            protected: protected.to_bytes(),
+            alg: Sig::ALGORITHM,
+            kid: kid.map(|kid| kid.to_vec()),
+            payload: payload.to_vec(),
+            signature: Vec::new(),
+        };
+
+        let signature = signer
+            .try_sign(&cose_sign1.signed_data(external_aad))
+            .map_err(|_| crypto_error("signing failed"))?;
+
+        cose_sign1.signature = signature.as_ref().to_vec();
+        Ok(cose_sign1)
+    }
+
+    /// Parse a CBOR-encoded `COSE_Sign1` message, which may be tagged or
+    /// untagged.
+    ///
+    /// The signature is not verified until `CoseSign1::verify()` is called.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let message = match Value::from_bytes(bytes)? {
+            Value::Tag(COSE_SIGN1_TAG, message) => *message,
+            Value::Tag(..) => return Err(decode_error("unexpected CBOR tag for COSE_Sign1")),
+            message => message,
+        };
+
+        let mut fields = match message {
+            Value::Array(fields) if fields.len() == 4 => fields.into_iter(),
+            _ => return Err(decode_error("malformed COSE_Sign1")),
+        };
+
+        let (protected, unprotected, payload, signature) =
+            match (fields.next(), fields.next(), fields.next(), fields.next()) {
+                (
+                    Some(Value::Bytes(protected)),
+                    Some(unprotected @ Value::Map(_)),
+                    Some(payload),
+                    Some(Value::Bytes(signature)),
+                ) => (protected, unprotected, payload, signature),
+                _ => return Err(decode_error("malformed COSE_Sign1")),
+            };
+
+        let payload = match payload {
+            Value::Bytes(payload) => payload,
+            Value::Null => return Err(decode_error("detached COSE payloads are unsupported")),
+            _ => return Err(decode_error("malformed COSE_Sign1")),
+        };
+
+        // The algorithm must be protected to prevent substitution attacks
+        let protected_header = Value::from_bytes(&protected)?;
+
+        if protected_header.get(HEADER_CRIT).is_some() {
+            return Err(decode_error("unsupported critical COSE header parameters"));
+        }
+
+        let alg = match protected_header.get(HEADER_ALG) {
+            Some(Value::Int(id)) => CoseAlgorithm::from_id(*id)?,
+            _ => return Err(decode_error("COSE protected header is missing alg")),
+        };
+
+        let kid = match protected_header
+            .get(HEADER_KID)
+            .or_else(|| unprotected.get(HEADER_KID))
+        {
+            Some(Value::Bytes(kid)) => Some(kid.clone()),
+            Some(_) => return Err(decode_error("malformed COSE key identifier")),
+            None => None,
+        };
+
+        Ok(Self {
+            protected,
+            alg,
+            kid,
+            payload,
+            signature,
+        })
+    }
+
+    /// Serialize this message as a tagged `COSE_Sign1`.
+    ///
+    /// Only the key ID is retained from the unprotected header of messages
+    /// which were parsed with `CoseSign1::from_bytes()`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let unprotected = match &self.kid {
+            Some(kid) if !self.protected_kid() => {
+                vec![(Value::Int(HEADER_KID), Value::Bytes(kid.clone()))]
+            }
+            _ => vec![],
+        };
+
+        Value::Tag(
+            COSE_SIGN1_TAG,
+            Box::new(Value::Array(vec![
+                Value::Bytes(self.protected.clone()),
+                Value::Map(unprotected),
+                Value::Bytes(self.payload.clone()),
+                Value::Bytes(self.signature.clone()),
+            ])),
+        )
+        .to_bytes()
+    }
+
+    /// Verify this message using the given verifier and external additional
+    /// authenticated data (usually empty), returning the payload if the
+    /// signature is valid.
+    ///
+    /// The verifier's signature type must match the algorithm in the
+    /// protected header, and it must be constructed from a trusted public key
+    /// (which may be selected using `CoseSign1::kid()`).
+    pub fn verify<V, Sig>(&self, verifier: &V, external_aad: &[u8]) -> Result<&[u8], Error>
+    where
+        V: Verifier<Sig>,
+        Sig: CoseSignature,
+    {
+        if self.alg != Sig::ALGORITHM {
+            return Err(crypto_error("COSE algorithm doesn't match key type"));
+        }
+
+        let signature = Sig::from_bytes(&self.signature)
+            .map_err(|_| decode_error("malformed COSE signature"))?;
+
+        verifier
+            .verify(&self.signed_data(external_aad), &signature)
+            .map_err(|_| crypto_error("COSE_Sign1 verification failed"))?;
+
+        Ok(&self.payload)
+    }
+
+    /// Get the signature algorithm
+    pub fn alg(&self) -> CoseAlgorithm {
+        self.alg
+    }
+
+    /// Get the key identifier, if present.
+    ///
+    /// Key identifiers in the unprotected header are unauthenticated.
+    pub fn kid(&self) -> Option<&[u8]> {
+        self.kid.as_deref()
+    }
+
+    /// Get the raw signature: a 64-byte Ed25519 signature or a fixed-width
+    /// ECDSA signature (i.e. `r || s`)
+    pub fn signature(&self) -> &[u8] {
+        &self.signature
+    }
+
+    /// Is the key identifier in the protected header?
+    fn protected_kid(&self) -> bool {
+        Value::from_bytes(&self.protected)
+            .map(|header| header.get(HEADER_KID).is_some())
+            .unwrap_or(false)
+    }
+
+    /// Compute the `Sig_structure` which is actually signed
+    fn signed_data(&self, external_aad: &[u8]) -> Vec<u8> {
+        Value::Array(vec![
+            Value::Text(String::from(SIGNATURE1_CONTEXT)),
+            Value::Bytes(self.protected.clone()),
+            Value::Bytes(external_aad.to_vec()),
+            Value::Bytes(self.payload.clone()),
+        ])
+        .to_bytes()
+    }
+}
+
+/// `COSE_Key` containing a public key
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CoseKey {
+    /// Algorithm of this key
+    algorithm: Algorithm,
+
+    /// Public key: a 32-byte Ed25519 or 57-byte Ed448 public key, or a
+    /// SEC1-encoded elliptic curve point (compressed or uncompressed)
+    public_key: Vec<u8>,
+
+    /// Key identifier
+    kid: Option<Vec<u8>>,
+}
+
+impl CoseKey {
+    /// Create a `COSE_Key` from a serialized public key
+    pub fn new(algorithm: Algorithm, public_key: &[u8]) -> Result<Self, Error> {
+        algorithm.check_public_key(public_key)?;
+
+        Ok(Self {
+            algorithm,
+            public_key: public_key.to_vec(),
+            kid: None,
+        })
+    }
+
+    /// Set the key identifier of this `COSE_Key`
+    pub fn with_kid(mut self, kid: &[u8]) -> Self {
+        self.kid = Some(kid.to_vec());
+        self
+    }
+
+    /// Parse a CBOR-encoded `COSE_Key`.
+    ///
+    /// If the key has an `alg` parameter, it must match the key's curve.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let key = Value::from_bytes(bytes)?;
+
+        let kty = match key.get(KEY_KTY) {
+            Some(Value::Int(kty)) => *kty,
+            _ => return Err(decode_error("COSE_Key is missing kty")),
+        };
+
+        let algorithm = match key.get(KEY_CRV) {
+            Some(Value::Int(crv)) => curve_algorithm(*crv)?,
+            _ => return Err(decode_error("COSE_Key is missing crv")),
+        };
+
+        if kty != key_type(algorithm) {
+            return Err(decode_error("COSE_Key type doesn't match curve"));
+        }
+
+        let alg = match key.get(KEY_ALG) {
+            Some(Value::Int(id)) => Some(CoseAlgorithm::from_id(*id)?),
+            Some(_) => return Err(decode_error("malformed COSE_Key algorithm")),
+            None => None,
+        };
+
+        match alg {
+            Some(alg) if alg != CoseAlgorithm::for_key_algorithm(algorithm) => {
+                return Err(decode_error("COSE_Key algorithm doesn't match curve"))
+            }
+            _ => (),
+        }
+
+        let x = match key.get(KEY_X) {
+            Some(Value::Bytes(x)) => x,
+            _ => return Err(decode_error("COSE_Key is missing x")),
+        };
+
+        let mut public_key = Vec::new();
+
+        if algorithm.is_ecdsa() {
+            // RFC 8152 Section 13.1.1: `y` is either the y-coordinate or
+            // the sign bit of a compressed point
+            match key.get(KEY_Y) {
+                Some(Value::Bytes(y)) => {
+                    public_key.push(0x04);
+                    public_key.extend_from_slice(x);
+                    public_key.extend_from_slice(y);
+                }
+                Some(Value::Bool(sign)) => {
+                    public_key.push(if *sign { 0x03 } else { 0x02 });
+                    public_key.extend_from_slice(x);
+                }
+                _ => return Err(decode_error("COSE_Key is missing y")),
+            }
+        } else if key.get(KEY_Y).is_some() {
+            return Err(decode_error("unexpected COSE_Key y parameter"));
+        } else {
+            public_key.extend_from_slice(x);
+        }
+
+        let kid = match key.get(KEY_KID) {
+            Some(Value::Bytes(kid)) => Some(kid.clone()),
+            Some(_) => return Err(decode_error("malformed COSE_Key key identifier")),
+            None => None,
+        };
+
+        Ok(Self::new(algorithm, &public_key)?.with_kid_option(kid))
+    }
+
+    /// Serialize this key as a CBOR-encoded `COSE_Key`.
+    ///
+    /// The `alg` parameter is always included to restrict the key's use to
+    /// a single algorithm, and compressed points are encoded using the sign
+    /// bit of the y-coordinate.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        // RFC 8949 Section 4.2.1: keys are sorted by their encoded form
+        let mut entries = vec![(Value::Int(KEY_KTY), Value::Int(key_type(self.algorithm)))];
+
+        if let Some(kid) = &self.kid {
+            entries.push((Value::Int(KEY_KID), Value::Bytes(kid.clone())));
+        }
+
+        entries.push((
+            Value::Int(KEY_ALG),
+            Value::Int(CoseAlgorithm::for_key_algorithm(self.algorithm).id()),
+        ));
+
+        entries.push((Value::Int(KEY_CRV), Value::Int(curve_id(self.algorithm))));
+
+        if self.algorithm.is_ecdsa() {
+            let size = self.algorithm.secret_key_size();
+            let (x, y) = self.public_key[1..].split_at(size);
+            entries.push((Value::Int(KEY_X), Value::Bytes(x.to_vec())));
+
+            let y = match self.public_key[0] {
+                0x04 => Value::Bytes(y.to_vec()),
+                tag => Value::Bool(tag == 0x03),
+            };
+
+            entries.push((Value::Int(KEY_Y), y));
+        } else {
+            entries.push((Value::Int(KEY_X), Value::Bytes(self.public_key.clone())));
+        }
+
+        Value::Map(entries).to_bytes()
+    }
+
+    /// Get the algorithm of this key
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    /// Get the serialized public key: a 32-byte Ed25519 or 57-byte Ed448
+    /// public key, or a SEC1-encoded elliptic curve point
+    pub fn public_key(&self) -> &[u8] {
+        &self.public_key
+    }
+
+    /// Get the key identifier, if present
+    pub fn kid(&self) -> Option<&[u8]> {
+        self.kid.as_deref()
+    }
+
+    /// Get the public key, ensuring this key is for the given algorithm
+    pub fn public_key_for(&self, algorithm: Algorithm) -> Result<&[u8], Error> {
+        if self.algorithm == algorithm {
+            Ok(&self.public_key)
+        } else {
+            Err(decode_error("unexpected COSE_Key type"))
+        }
+    }
+
+    /// Set the key identifier, if any
+    fn with_kid_option(mut self, kid: Option<Vec<u8>>) -> Self {
+        self.kid = kid;
+        self
+    }
+}
+
+/// Get the COSE key type for the given algorithm
+fn key_type(algorithm: Algorithm) -> i64 {
+    match algorithm {
+        Algorithm::Ed25519 | Algorithm::Ed448 => KTY_OKP,
+        _ => KTY_EC2,
+    }
+}
+
+/// Get the COSE curve identifier for the given algorithm
+fn curve_id(algorithm: Algorithm) -> i64 {
+    match algorithm {
+        Algorithm::NistP256 => 1,
+        Algorithm::NistP384 => 2,
+        Algorithm::NistP521 => 3,
+        Algorithm::Ed25519 => 6,
+        Algorithm::Ed448 => 7,
+        Algorithm::Secp256k1 => 8,
+    }
+}
+
+/// Find the algorithm with the given COSE curve identifier
+fn curve_algorithm(crv: i64) -> Result<Algorithm, Error> {
+    [
+        Algorithm::Ed25519,
+        Algorithm::Ed448,
+        Algorithm::NistP256,
+        Algorithm::NistP384,
+        Algorithm::NistP521,
+        Algorithm::Secp256k1,
+    ]
+    .iter()
+    .find(|alg| curve_id(**alg) == crv)
+    .cloned()
+    .ok_or_else(|| decode_error("unsupported COSE curve"))
+}
@@ -0,0 +1,304 @@
+//! Minimal ASN.1 DER decoder/encoder supporting the subset of DER needed to
+//! parse and serialize the key formats used by Signatory.
+//!
+//! The decoder operates on borrowed byte slices and doesn't require `alloc`,
+//! which makes it usable on `no_std` targets. It only supports definite-length,
+//! minimally-encoded lengths of up to 65535 bytes, which is ample for keys.
+
+use super::error::{decode_error, Error};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// ASN.1 `INTEGER` tag
+#[cfg(any(feature = "pkcs8", feature = "sec1"))]
+pub(crate) const INTEGER: u8 = 0x02;
+
+/// ASN.1 `BIT STRING` tag
+pub(crate) const BIT_STRING: u8 = 0x03;
+
+/// ASN.1 `OCTET STRING` tag
+#[cfg(any(feature = "pkcs8", feature = "sec1"))]
+pub(crate) const OCTET_STRING: u8 = 0x04;
+
+/// ASN.1 `NULL` tag
+#[cfg(feature = "encryption")]
+pub(crate) const NULL: u8 = 0x05;
+
+/// ASN.1 `OBJECT IDENTIFIER` tag
+pub(crate) const OBJECT_IDENTIFIER: u8 = 0x06;
+
+/// ASN.1 `SEQUENCE` tag (constructed)
+pub(crate) const SEQUENCE: u8 = 0x30;
+
+/// Largest length we support encoding
+#[cfg(feature = "alloc")]
+const MAX_LENGTH: usize = 0xFFFF;
+
+/// Tag for a context-specific field with the given tag number, i.e. `[n]`
+#[cfg(any(feature = "pkcs8", feature = "sec1"))]
+pub(crate) const fn context_specific(tag_number: u8, constructed: bool) -> u8 {
+    if constructed {
+        0xA0 | tag_number
+    } else {
+        0x80 | tag_number
+    }
+}
+
+/// DER decoder which reads TLV-encoded values from a borrowed byte slice
+#[derive(Clone, Debug)]
+pub(crate) struct Decoder<'a> {
+    /// Remaining data to be decoded
+    bytes: &'a [u8],
+}
+
+impl<'a> Decoder<'a> {
+    /// Create a new decoder for the given DER-encoded data
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Peek at the tag of the next value (if any) without consuming it
+    pub fn peek_tag(&self) -> Option<u8> {
+        self.bytes.first().cloned()
+    }
+
+    /// Ensure all of the input has been consumed
+    pub fn finish(self) -> Result<(), Error> {
+        if self.bytes.is_empty() {
+            Ok(())
+        } else {
+            Err(decode_error("trailing data after DER value"))
+        }
+    }
+
+    /// Decode a TLV value with the given tag, returning its contents
+    pub fn value(&mut self, tag: u8) -> Result<&'a [u8], Error> {
+        match self.peek_tag() {
+            Some(t) if t == tag => (),
+            Some(_) => return Err(decode_error("unexpected DER tag")),
+            None => return Err(decode_error("unexpected end of DER input")),
+        }
+
+        let (length, header_len) = decode_length(&self.bytes[1..])?;
+        let start = add!(header_len, 1);
+        let end = add!(start, length);
+
+        if end > self.bytes.len() {
+            return Err(decode_error("truncated DER value"));
+        }
+
+        let contents = &self.bytes[start..end];
+        self.bytes = &self.bytes[end..];
+        Ok(contents)
+    }
+
+    /// Decode an optional TLV value with the given tag, returning its contents
+    /// if it's present
+    #[cfg(any(feature = "pkcs8", feature = "sec1"))]
+    pub fn optional(&mut self, tag: u8) -> Result<Option<&'a [u8]>, Error> {
+        if self.peek_tag() == Some(tag) {
+            self.value(tag).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Decode a `SEQUENCE`, returning a decoder for its contents
+    pub fn sequence(&mut self) -> Result<Decoder<'a>, Error> {
+        self.value(SEQUENCE).map(Decoder::new)
+    }
+
+    /// Decode a small non-negative `INTEGER` (e.g. a version number)
+    #[cfg(any(feature = "pkcs8", feature = "sec1"))]
+    pub fn integer_u8(&mut self) -> Result<u8, Error> {
+        match self.value(INTEGER)? {
+            [n] if *n < 0x80 => Ok(*n),
+            _ => Err(decode_error("unsupported INTEGER value")),
+        }
+    }
+
+    /// Decode a non-negative `INTEGER` which fits in a `u32` (e.g. an
+    /// iteration count)
+    #[cfg(feature = "encryption")]
+    pub fn integer_u32(&mut self) -> Result<u32, Error> {
+        let bytes = match self.value(INTEGER)? {
+            // Leading zero is only allowed when the next byte's high bit is set
+            [0, rest @ ..] if rest.first().map(|b| *b >= 0x80).unwrap_or(true) => rest,
+            [first, ..] if *first >= 0x80 => {
+                return Err(decode_error("negative INTEGER value"));
+            }
+            [0, ..] | [] => return Err(decode_error("non-minimal INTEGER encoding")),
+            bytes => bytes,
+        };
+
+        if bytes.len() > 4 {
+            return Err(decode_error("INTEGER value too large"));
+        }
+
+        Ok(bytes
+            .iter()
+            .fold(0u32, |n, byte| shl!(n, 8) | u32::from(*byte)))
+    }
+
+    /// Decode an optional ASN.1 `NULL` (e.g. `AlgorithmIdentifier` parameters)
+    #[cfg(feature = "encryption")]
+    pub fn optional_null(&mut self) -> Result<(), Error> {
+        match self.optional(NULL)? {
+            Some([]) | None => Ok(()),
+            Some(_) => Err(decode_error("malformed NULL")),
+        }
+    }
+
+    /// Decode an `OBJECT IDENTIFIER`, returning its encoded arcs
+    pub fn oid(&mut self) -> Result<&'a [u8], Error> {
+        let oid = self.value(OBJECT_IDENTIFIER)?;
+
+        if oid.is_empty() {
+            Err(decode_error("empty OBJECT IDENTIFIER"))
+        } else {
+            Ok(oid)
+        }
+    }
+
+    /// Decode an `OCTET STRING`
+    #[cfg(any(feature = "pkcs8", feature = "sec1"))]
+    pub fn octet_string(&mut self) -> Result<&'a [u8], Error> {
+        self.value(OCTET_STRING)
+    }
+
+    /// Decode a `BIT STRING` containing a whole number of octets
+    pub fn bit_string(&mut self) -> Result<&'a [u8], Error> {
+        parse_bit_string(self.value(BIT_STRING)?)
+    }
+}
+
+/// Parse the contents of a `BIT STRING`, ensuring it contains no unused bits
+pub(crate) fn parse_bit_string(contents: &[u8]) -> Result<&[u8], Error> {
+    match contents.split_first() {
+        Some((0, bits)) => Ok(bits),
+        _ => Err(decode_error("unsupported BIT STRING")),
+    }
+}
+
+/// Decode a DER length, returning the length and the number of bytes it occupied
+fn decode_length(bytes: &[u8]) -> Result<(usize, usize), Error> {
+    match bytes {
+        [n, ..] if *n < 0x80 => Ok((*n as usize, 1)),
+        [0x81, n, ..] if *n >= 0x80 => Ok((*n as usize, 2)),
+        [0x82, hi, lo, ..] if *hi != 0 => Ok(((*hi as usize) << 8 | *lo as usize, 3)),
+        [] => Err(decode_error("unexpected end of DER input")),
+        _ => Err(decode_error("invalid or unsupported DER length")),
+    }
+}
+
+/// Compute the length of the header (tag + length) for a value with the given
+/// content length
+#[cfg(feature = "alloc")]
+pub(crate) fn header_len(length: usize) -> usize {
+    assert!(length <= MAX_LENGTH, "DER value too long");
+
+    match length {
+        0..=0x7F => 2,
+        0x80..=0xFF => 3,
+        _ => 4,
+    }
+}
+
+/// Compute the total encoded length of a value with the given content length
+#[cfg(feature = "alloc")]
+pub(crate) fn encoded_len(length: usize) -> usize {
+    add!(header_len(length), length)
+}
+
+/// Compute the length of the contents of a non-negative `INTEGER`
+#[cfg(all(feature = "alloc", feature = "encryption"))]
+pub(crate) fn integer_len(n: u32) -> usize {
+    // One leading sign bit, rounded up to whole bytes
+    let bits = add!(sub!(32usize, n.leading_zeros() as usize), 1);
+    div!(add!(bits, 7), 8)
+}
+
+/// DER encoder which writes values into a preallocated buffer.
+///
+/// Callers compute the lengths of constructed values up front, which avoids
+/// having to allocate intermediate buffers that might contain secrets.
+#[cfg(feature = "alloc")]
+pub(crate) struct Encoder {
+    /// Encoded output
+    bytes: Vec<u8>,
+
+    /// Expected total length of the document
+    length: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl Encoder {
+    /// Create a new encoder for a document of the given total length
+    pub fn new(length: usize) -> Self {
+        Self {
+            bytes: Vec::with_capacity(length),
+            length,
+        }
+    }
+
+    /// Write the header (tag + length) for a value
+    pub fn header(&mut self, tag: u8, length: usize) {
+        assert!(length <= MAX_LENGTH, "DER value too long");
+        self.bytes.push(tag);
+
+        match length {
+            0..=0x7F => self.bytes.push(length as u8),
+            0x80..=0xFF => self.bytes.extend_from_slice(&[0x81, length as u8]),
+            _ => self
+                .bytes
+                .extend_from_slice(&[0x82, shr!(length, 8) as u8, length as u8]),
+        }
+    }
+
+    /// Write a complete value with the given tag and contents
+    pub fn value(&mut self, tag: u8, contents: &[u8]) {
+        self.header(tag, contents.len());
+        self.bytes.extend_from_slice(contents);
+    }
+
+    /// Write a small non-negative `INTEGER`
+    #[cfg(any(feature = "pkcs8", feature = "sec1"))]
+    pub fn integer_u8(&mut self, n: u8) {
+        assert!(n < 0x80, "INTEGER value too large");
+        self.value(INTEGER, &[n]);
+    }
+
+    /// Write a non-negative `INTEGER`
+    #[cfg(feature = "encryption")]
+    pub fn integer_u32(&mut self, n: u32) {
+        let len = integer_len(n);
+        self.header(INTEGER, len);
+
+        if len > 4 {
+            self.bytes.push(0);
+            self.bytes.extend_from_slice(&n.to_be_bytes());
+        } else {
+            self.bytes
+                .extend_from_slice(&n.to_be_bytes()[sub!(4usize, len)..]);
+        }
+    }
+
+    /// Write an ASN.1 `NULL`
+    #[cfg(feature = "encryption")]
+    pub fn null(&mut self) {
+        self.value(NULL, &[]);
+    }
+
+    /// Write a `BIT STRING` containing a whole number of octets
+    pub fn bit_string(&mut self, tag: u8, bits: &[u8]) {
+        self.header(tag, add!(bits.len(), 1));
+        self.bytes.push(0);
+        self.bytes.extend_from_slice(bits);
+    }
+
+    /// Finish encoding, returning the serialized document
+    pub fn finish(self) -> Vec<u8> {
+        debug_assert_eq!(self.bytes.len(), self.length);
+        self.bytes
+    }
+}
@@ -3,7 +3,6 @@
 //! Uses a constant-time implementation which is suitable for use with
 //! secret keys.
 
-#[cfg(feature = "std")]
 use super::error::ErrorKind;
 #[cfg(all(unix, feature = "std"))]
 use super::FILE_MODE;
@@ -76,6 +76,32 @@ impl From<subtle_encoding::Error> for Error {
     }
 }
 
+/// Create a decode error with the given message
+#[cfg(any(
+    feature = "cose",
+    feature = "jwk",
+    feature = "jws",
+    feature = "openssh",
+    feature = "pem",
+    feature = "pkcs8",
+    feature = "sec1",
+    feature = "spki"
+))]
+pub(crate) fn decode_error(msg: &str) -> Error {
+    Error::new(ErrorKind::Decode, Some(msg))
+}
+
+/// Create a cryptographic error with the given message
+#[cfg(any(
+    feature = "cose",
+    feature = "encryption",
+    feature = "jws",
+    feature = "openssh"
+))]
+pub(crate) fn crypto_error(msg: &str) -> Error {
+    Error::new(ErrorKind::Crypto, Some(msg))
+}
+
 /// Kinds of errors
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub enum ErrorKind {
@@ -87,6 +113,9 @@ pub enum ErrorKind {
 
     /// Input/output error
     Io,
+
+    /// Cryptographic error (e.g. decrypting with an incorrect password)
+    Crypto,
 }
 
 impl Display for ErrorKind {
@@ -95,6 +124,7 @@ impl Display for ErrorKind {
             ErrorKind::Decode => "decode error",
             ErrorKind::Encode => "encode error",
             ErrorKind::Io => "i/o error",
+            ErrorKind::Crypto => "cryptographic error",
         };
 
         write!(f, "{}", msg)
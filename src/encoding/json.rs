@@ -0,0 +1,425 @@
+//! Minimal JSON ([RFC 8259]) support for the JOSE formats (e.g. JWK).
+//!
+//! Only what's needed to parse and serialize JOSE objects is implemented:
+//! booleans and numbers are validated but their values are discarded, as no
+//! supported JOSE member uses them, and objects with duplicate member names
+//! are rejected as required by [RFC 7515 Section 4].
+//!
+//! String values are zeroized on drop, as they may contain secret keys.
+//!
+//! [RFC 7515 Section 4]: https://tools.ietf.org/html/rfc7515#section-4
+//! [RFC 8259]: https://tools.ietf.org/html/rfc8259
+
+use super::error::{decode_error, Error};
+use alloc::{string::String, vec::Vec};
+use core::{char, fmt::Write, str};
+use zeroize::Zeroizing;
+
+/// Maximum nesting depth of arrays and objects
+const MAX_DEPTH: usize = 16;
+
+/// JSON values
+#[derive(Clone, Debug)]
+pub(crate) enum Value {
+    /// `null`
+    Null,
+
+    /// `true` or `false`
+    Bool,
+
+    /// Number
+    Number,
+
+    /// String
+    String(Zeroizing<String>),
+
+    /// Array
+    // Only JWK sets look inside arrays and nested objects
+    #[cfg_attr(not(feature = "jwk"), allow(dead_code))]
+    Array(Vec<Value>),
+
+    /// Object
+    #[cfg_attr(not(feature = "jwk"), allow(dead_code))]
+    Object(Object),
+}
+
+impl Value {
+    /// Get this value as a string, if it is one
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s.as_str()),
+            _ => None,
+        }
+    }
+}
+
+/// JSON object
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Object(Vec<(String, Value)>);
+
+impl Object {
+    /// Parse a JSON document which must contain an object
+    pub fn parse(json: &str) -> Result<Self, Error> {
+        let mut parser = Parser {
+            json: json.as_bytes(),
+            pos: 0,
+        };
+
+        parser.skip_whitespace();
+        let object = parser.object(0)?;
+        parser.skip_whitespace();
+
+        if parser.pos == parser.json.len() {
+            Ok(object)
+        } else {
+            Err(parser.error("trailing data after JSON object"))
+        }
+    }
+
+    /// Get the member with the given name
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.0.iter().find(|(n, _)| n == name).map(|(_, v)| v)
+    }
+
+    /// Get the member with the given name, which must be a string if present
+    pub fn get_str(&self, name: &str) -> Result<Option<&str>, Error> {
+        match self.get(name) {
+            Some(value) => value
+                .as_str()
+                .map(Some)
+                .ok_or_else(|| decode_error("expected JSON string")),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Recursive descent JSON parser
+struct Parser<'a> {
+    /// JSON document being parsed
+    json: &'a [u8],
+
+    /// Current position
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// Parse any JSON value
+    fn value(&mut self, depth: usize) -> Result<Value, Error> {
+        match self.peek() {
+            Some(b'{') => self.object(depth).map(Value::Object),
+            Some(b'[') => self.array(depth).map(Value::Array),
+            Some(b'"') => self.string().map(Value::String),
+            Some(b't') => self.literal("true").map(|_| Value::Bool),
+            Some(b'f') => self.literal("false").map(|_| Value::Bool),
+            Some(b'n') => self.literal("null").map(|_| Value::Null),
+            Some(b'-') | Some(b'0'..=b'9') => self.number().map(|_| Value::Number),
+            _ => Err(self.error("expected JSON value")),
+        }
+    }
+
+    /// Parse an object
+    fn object(&mut self, depth: usize) -> Result<Object, Error> {
+        self.enter(b'{', depth)?;
+        let mut members: Vec<(String, Value)> = Vec::new();
+
+        if !self.consume(b'}') {
+            loop {
+                self.skip_whitespace();
+                let name = String::from(self.string()?.as_str());
+
+                if members.iter().any(|(n, _)| *n == name) {
+                    return Err(self.error("duplicate JSON member name"));
+                }
+
+                self.skip_whitespace();
+                self.expect(b':')?;
+                self.skip_whitespace();
+                let value = self.value(add!(depth, 1))?;
+                members.push((name, value));
+                self.skip_whitespace();
+
+                if self.consume(b'}') {
+                    break;
+                }
+
+                self.expect(b',')?;
+            }
+        }
+
+        Ok(Object(members))
+    }
+
+    /// Parse an array
+    fn array(&mut self, depth: usize) -> Result<Vec<Value>, Error> {
+        self.enter(b'[', depth)?;
+        let mut elements = Vec::new();
+
+        if !self.consume(b']') {
+            loop {
+                self.skip_whitespace();
+                elements.push(self.value(add!(depth, 1))?);
+                self.skip_whitespace();
+
+                if self.consume(b']') {
+                    break;
+                }
+
+                self.expect(b',')?;
+            }
+        }
+
+        Ok(elements)
+    }
+
+    /// Parse a string, processing escape sequences
+    fn string(&mut self) -> Result<Zeroizing<String>, Error> {
+        self.expect(b'"')?;
+        let mut result = Zeroizing::new(String::new());
+
+        loop {
+            let start = self.pos;
+
+            while let Some(byte) = self.peek() {
+                if byte == b'"' || byte == b'\\' || byte < 0x20 {
+                    break;
+                }
+
+                self.pos = add!(self.pos, 1);
+            }
+
+            // The input is a `str` and the run stops at an ASCII byte, so it's
+            // always valid UTF-8
+            result.push_str(str::from_utf8(&self.json[start..self.pos]).unwrap());
+
+            match self.next() {
+                Some(b'"') => return Ok(result),
+                Some(b'\\') => result.push(self.escape()?),
+                _ => return Err(self.error("unterminated JSON string")),
+            }
+        }
+    }
+
+    /// Parse the remainder of an escape sequence (after the backslash)
+    fn escape(&mut self) -> Result<char, Error> {
+        let c = match self.next() {
+            Some(b'"') => '"',
+            Some(b'\\') => '\\',
+            Some(b'/') => '/',
+            Some(b'b') => '\u{8}',
+            Some(b'f') => '\u{c}',
+            Some(b'n') => '\n',
+            Some(b'r') => '\r',
+            Some(b't') => '\t',
+            Some(b'u') => {
+                let high = self.hex4()?;
+
+                let code_point = if (0xd800..0xdc00).contains(&high) {
+                    // UTF-16 surrogate pair
+                    self.expect(b'\\')?;
+                    self.expect(b'u')?;
+                    let low = self.hex4()?;
+
+                    if !(0xdc00..0xe000).contains(&low) {
+                        return Err(self.error("invalid JSON surrogate pair"));
+                    }
+
+                    add!(0x10000u32, shl!(sub!(high, 0xd800), 10) | sub!(low, 0xdc00))
+                } else {
+                    high
+                };
+
+                return char::from_u32(code_point)
+                    .ok_or_else(|| self.error("invalid JSON unicode escape"));
+            }
+            _ => return Err(self.error("invalid JSON escape sequence")),
+        };
+
+        Ok(c)
+    }
+
+    /// Parse 4 hex digits
+    fn hex4(&mut self) -> Result<u32, Error> {
+        let mut result = 0u32;
+
+        for _ in 0..4 {
+            let digit = self
+                .next()
+                .and_then(|byte| char::from(byte).to_digit(16))
+                .ok_or_else(|| self.error("invalid JSON unicode escape"))?;
+
+            result = shl!(result, 4) | digit;
+        }
+
+        Ok(result)
+    }
+
+    /// Parse a number, checking it matches the JSON number grammar
+    fn number(&mut self) -> Result<(), Error> {
+        self.consume(b'-');
+
+        if !self.consume(b'0') && self.digits() == 0 {
+            return Err(self.error("invalid JSON number"));
+        }
+
+        if self.consume(b'.') && self.digits() == 0 {
+            return Err(self.error("invalid JSON number"));
+        }
+
+        if self.consume(b'e') || self.consume(b'E') {
+            if !self.consume(b'+') {
+                self.consume(b'-');
+            }
+
+            if self.digits() == 0 {
+                return Err(self.error("invalid JSON number"));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Consume a run of decimal digits, returning how many were consumed
+    fn digits(&mut self) -> usize {
+        let start = self.pos;
+
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.pos = add!(self.pos, 1);
+        }
+
+        sub!(self.pos, start)
+    }
+
+    /// Parse the given literal (e.g. `true`)
+    fn literal(&mut self, literal: &str) -> Result<(), Error> {
+        if self.json[self.pos..].starts_with(literal.as_bytes()) {
+            self.pos = add!(self.pos, literal.len());
+            Ok(())
+        } else {
+            Err(self.error("invalid JSON literal"))
+        }
+    }
+
+    /// Enter an array or object, checking the nesting depth
+    fn enter(&mut self, delimiter: u8, depth: usize) -> Result<(), Error> {
+        if depth >= MAX_DEPTH {
+            return Err(self.error("JSON nesting too deep"));
+        }
+
+        self.expect(delimiter)?;
+        self.skip_whitespace();
+        Ok(())
+    }
+
+    /// Skip insignificant whitespace
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ') | Some(b'\t') | Some(b'\n') | Some(b'\r') = self.peek() {
+            self.pos = add!(self.pos, 1);
+        }
+    }
+
+    /// Consume the given byte if it's next
+    fn consume(&mut self, byte: u8) -> bool {
+        if self.peek() == Some(byte) {
+            self.pos = add!(self.pos, 1);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consume the given byte, returning an error if it isn't next
+    fn expect(&mut self, byte: u8) -> Result<(), Error> {
+        if self.consume(byte) {
+            Ok(())
+        } else {
+            Err(self.error("malformed JSON"))
+        }
+    }
+
+    /// Peek at the next byte
+    fn peek(&self) -> Option<u8> {
+        self.json.get(self.pos).cloned()
+    }
+
+    /// Consume the next byte
+    fn next(&mut self) -> Option<u8> {
+        let byte = self.peek()?;
+        self.pos = add!(self.pos, 1);
+        Some(byte)
+    }
+
+    /// Create a decode error with the given message
+    fn error(&self, msg: &str) -> Error {
+        decode_error(msg)
+    }
+}
+
+/// Serializer for JSON objects
+pub(crate) struct ObjectWriter {
+    /// Serialized JSON
+    json: Zeroizing<String>,
+
+    /// Have any members been written yet?
+    empty: bool,
+}
+
+impl ObjectWriter {
+    /// Begin serializing an object
+    pub fn new() -> Self {
+        Self {
+            json: Zeroizing::new(String::from("{")),
+            empty: true,
+        }
+    }
+
+    /// Write a member with a string value
+    pub fn string(&mut self, name: &str, value: &str) {
+        self.name(name);
+        write_string(&mut self.json, value);
+    }
+
+    /// Write a member whose value is already-serialized JSON
+    #[cfg(feature = "jwk")]
+    pub fn raw(&mut self, name: &str, json: &str) {
+        self.name(name);
+        self.json.push_str(json);
+    }
+
+    /// Finish serializing the object
+    pub fn finish(mut self) -> Zeroizing<String> {
+        self.json.push('}');
+        self.json
+    }
+
+    /// Write a member name
+    fn name(&mut self, name: &str) {
+        if !self.empty {
+            self.json.push(',');
+        }
+
+        self.empty = false;
+        write_string(&mut self.json, name);
+        self.json.push(':');
+    }
+}
+
+/// Serialize a string, escaping it as needed
+pub(crate) fn write_string(json: &mut String, s: &str) {
+    json.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                write!(json, "\\u{:04x}", c as u32).expect("write to String failed")
+            }
+            c => json.push(c),
+        }
+    }
+
+    json.push('"');
+}
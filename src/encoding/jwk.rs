@@ -0,0 +1,361 @@
+//! Support for JSON Web Keys (JWK) as described in [RFC 7517], including
+//! elliptic curve keys ([RFC 7518 Section 6.2]), Ed25519 and Ed448 "Octet Key
+//! Pair" keys ([RFC 8037]), secp256k1 keys ([RFC 8812]), and JWK Sets (JWKS).
+//!
+//! Key IDs (i.e. `kid`) can be computed from the key itself using the
+//! JWK Thumbprint method described in [RFC 7638].
+//!
+//! [RFC 7517]: https://tools.ietf.org/html/rfc7517
+//! [RFC 7518 Section 6.2]: https://tools.ietf.org/html/rfc7518#section-6.2
+//! [RFC 7638]: https://tools.ietf.org/html/rfc7638
+//! [RFC 8037]: https://tools.ietf.org/html/rfc8037
+//! [RFC 8812]: https://tools.ietf.org/html/rfc8812
+
+use super::{
+    base64url,
+    error::{decode_error, Error},
+    json::{self, Object, ObjectWriter},
+    Algorithm,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{self, Debug};
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+/// Algorithms which can be represented as JWKs
+const ALGORITHMS: &[Algorithm] = &[
+    Algorithm::Ed25519,
+    Algorithm::Ed448,
+    Algorithm::NistP256,
+    Algorithm::NistP384,
+    Algorithm::NistP521,
+    Algorithm::Secp256k1,
+];
+
+/// Decode a key from a JWK
+pub trait FromJwk: Sized {
+    /// Obtain a key from the given parsed JWK, returning an error if it's
+    /// for a different algorithm or is missing the key material needed
+    fn from_jwk(jwk: &Jwk) -> Result<Self, Error>;
+
+    /// Parse the given JWK serialized as JSON
+    fn from_jwk_json<S: AsRef<str>>(json: S) -> Result<Self, Error> {
+        Self::from_jwk(&Jwk::from_json(json.as_ref())?)
+    }
+}
+
+/// Encode a public key as a JWK
+pub trait ToJwk {
+    /// Serialize this public key as a JWK whose `kid` is its RFC 7638
+    /// thumbprint
+    fn to_jwk(&self) -> Result<Jwk, Error>;
+
+    /// Serialize this public key as a JWK encoded as JSON
+    fn to_jwk_json(&self) -> Result<String, Error> {
+        Ok(self.to_jwk()?.to_json().to_string())
+    }
+}
+
+/// JSON Web Key
+#[derive(Clone)]
+pub struct Jwk {
+    /// Algorithm of this key
+    algorithm: Algorithm,
+
+    /// Public key: a 32-byte Ed25519 or 57-byte Ed448 public key, or an
+    /// uncompressed SEC1-encoded elliptic curve point
+    public_key: Vec<u8>,
+
+    /// Private key (i.e. `d`): an Ed25519/Ed448 seed or elliptic curve scalar
+    private_key: Option<Zeroizing<Vec<u8>>>,
+
+    /// Key ID
+    kid: Option<String>,
+}
+
+impl Jwk {
+    /// Create a JWK from a serialized public key.
+    ///
+    /// Elliptic curve public keys must be uncompressed SEC1-encoded points,
+    /// as JWKs contain both the `x` and `y` coordinates.
+    pub fn new(algorithm: Algorithm, public_key: &[u8]) -> Result<Self, Error> {
+        algorithm.check_public_key(public_key)?;
+
+        if algorithm.is_ecdsa() && public_key[0] != 0x04 {
+            return Err(decode_error("JWK public keys must be uncompressed"));
+        }
+
+        Ok(Self {
+            algorithm,
+            public_key: public_key.to_vec(),
+            private_key: None,
+            kid: None,
+        })
+    }
+
+    /// Add the private key (i.e. `d`) corresponding to this JWK's public key.
+    ///
+    /// The private key is not checked against the public key. Prefer the
+    /// `ToJwk` impls for seeds and secret keys, which derive the public key.
+    pub fn with_private_key(mut self, private_key: &[u8]) -> Result<Self, Error> {
+        if private_key.len() != self.algorithm.secret_key_size() {
+            return Err(decode_error("invalid JWK private key size"));
+        }
+
+        self.private_key = Some(Zeroizing::new(private_key.to_vec()));
+        Ok(self)
+    }
+
+    /// Set the key ID (i.e. `kid`) of this JWK
+    pub fn with_kid<S: Into<String>>(mut self, kid: S) -> Self {
+        self.kid = Some(kid.into());
+        self
+    }
+
+    /// Set the key ID (i.e. `kid`) of this JWK to its RFC 7638 thumbprint
+    pub fn with_thumbprint_kid(self) -> Self {
+        let thumbprint = self.thumbprint();
+        self.with_kid(thumbprint)
+    }
+
+    /// Parse a JWK serialized as JSON
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        Self::from_object(&Object::parse(json)?)
+    }
+
+    /// Serialize this JWK as JSON, including the private key (if present)
+    pub fn to_json(&self) -> Zeroizing<String> {
+        let mut writer = ObjectWriter::new();
+        writer.string("kty", key_type(self.algorithm));
+        writer.string("crv", curve_name(self.algorithm));
+        self.write_coordinates(&mut writer);
+
+        if let Some(private_key) = &self.private_key {
+            writer.string("d", &Zeroizing::new(base64url::encode(private_key)));
+        }
+
+        if let Some(kid) = &self.kid {
+            writer.string("kid", kid);
+        }
+
+        writer.finish()
+    }
+
+    /// Get a copy of this JWK with the private key removed
+    pub fn to_public_jwk(&self) -> Self {
+        Self {
+            algorithm: self.algorithm,
+            public_key: self.public_key.clone(),
+            private_key: None,
+            kid: self.kid.clone(),
+        }
+    }
+
+    /// Compute the RFC 7638 thumbprint of this JWK: the base64url-encoded
+    /// SHA-256 digest of its required public members
+    pub fn thumbprint(&self) -> String {
+        // RFC 7638 Section 3.2: members in lexicographic order, no whitespace
+        let mut writer = ObjectWriter::new();
+        writer.string("crv", curve_name(self.algorithm));
+        writer.string("kty", key_type(self.algorithm));
+        self.write_coordinates(&mut writer);
+
+        base64url::encode(&Sha256::digest(writer.finish().as_bytes()))
+    }
+
+    /// Get the algorithm of this JWK
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    /// Get the serialized public key: a 32-byte Ed25519 or 57-byte Ed448
+    /// public key, or an uncompressed SEC1-encoded elliptic curve point
+    pub fn public_key(&self) -> &[u8] {
+        &self.public_key
+    }
+
+    /// Get the private key (i.e. `d`), if present
+    pub fn private_key(&self) -> Option<&[u8]> {
+        self.private_key.as_ref().map(|pk| pk.as_slice())
+    }
+
+    /// Get the key ID (i.e. `kid`), if present
+    pub fn kid(&self) -> Option<&str> {
+        self.kid.as_deref()
+    }
+
+    /// Get the public key, ensuring this JWK is for the given algorithm
+    pub fn public_key_for(&self, algorithm: Algorithm) -> Result<&[u8], Error> {
+        if self.algorithm == algorithm {
+            Ok(&self.public_key)
+        } else {
+            Err(decode_error("unexpected JWK key type"))
+        }
+    }
+
+    /// Get the private key, ensuring this JWK is for the given algorithm
+    pub fn private_key_for(&self, algorithm: Algorithm) -> Result<&[u8], Error> {
+        self.public_key_for(algorithm)?;
+        self.private_key()
+            .ok_or_else(|| decode_error("JWK is missing private key"))
+    }
+
+    /// Parse a JWK from a JSON object
+    fn from_object(object: &Object) -> Result<Self, Error> {
+        let kty = required_str(object, "kty")?;
+        let crv = required_str(object, "crv")?;
+
+        let algorithm = ALGORITHMS
+            .iter()
+            .find(|alg| key_type(**alg) == kty && curve_name(**alg) == crv)
+            .cloned()
+            .ok_or_else(|| decode_error("unsupported JWK key type or curve"))?;
+
+        let size = algorithm.secret_key_size();
+        let x = decode_member(object, "x", size)?;
+
+        let public_key = if algorithm.is_ecdsa() {
+            let y = decode_member(object, "y", size)?;
+            let mut public_key = Vec::with_capacity(add!(mul!(size, 2), 1));
+            public_key.push(0x04);
+            public_key.extend_from_slice(&x);
+            public_key.extend_from_slice(&y);
+            public_key
+        } else if object.get("y").is_some() {
+            return Err(decode_error("unexpected JWK member: y"));
+        } else {
+            x.to_vec()
+        };
+
+        let private_key = match object.get_str("d")? {
+            Some(_) => Some(decode_member(object, "d", size)?),
+            None => None,
+        };
+
+        let kid = object.get_str("kid")?.map(ToString::to_string);
+
+        Ok(Self {
+            algorithm,
+            public_key,
+            private_key,
+            kid,
+        })
+    }
+
+    /// Write the public key coordinates (i.e. `x` and `y`)
+    fn write_coordinates(&self, writer: &mut ObjectWriter) {
+        if self.algorithm.is_ecdsa() {
+            let (x, y) = self.public_key[1..].split_at(self.algorithm.secret_key_size());
+            writer.string("x", &base64url::encode(x));
+            writer.string("y", &base64url::encode(y));
+        } else {
+            writer.string("x", &base64url::encode(&self.public_key));
+        }
+    }
+}
+
+impl Debug for Jwk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Jwk")
+            .field("algorithm", &self.algorithm)
+            .field("public_key", &self.public_key)
+            .field("kid", &self.kid)
+            .finish()
+    }
+}
+
+/// JSON Web Key Set (JWKS) as described in RFC 7517 Section 5
+#[derive(Clone, Debug, Default)]
+pub struct JwkSet {
+    /// Keys in this set
+    pub keys: Vec<Jwk>,
+}
+
+impl JwkSet {
+    /// Parse a JWK Set serialized as JSON.
+    ///
+    /// Keys with unsupported key types or curves, or which are otherwise
+    /// malformed, are ignored as recommended by RFC 7517 Section 5.
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        let object = Object::parse(json)?;
+
+        let keys = match object.get("keys") {
+            Some(json::Value::Array(keys)) => keys
+                .iter()
+                .filter_map(|key| match key {
+                    json::Value::Object(key) => Jwk::from_object(key).ok(),
+                    _ => None,
+                })
+                .collect(),
+            _ => return Err(decode_error("JWK Set is missing keys")),
+        };
+
+        Ok(Self { keys })
+    }
+
+    /// Serialize this JWK Set as JSON, including any private keys
+    pub fn to_json(&self) -> Zeroizing<String> {
+        let mut keys = Zeroizing::new(String::from("["));
+
+        for (i, key) in self.keys.iter().enumerate() {
+            if i > 0 {
+                keys.push(',');
+            }
+
+            keys.push_str(&key.to_json());
+        }
+
+        keys.push(']');
+
+        let mut writer = ObjectWriter::new();
+        writer.raw("keys", &keys);
+        writer.finish()
+    }
+
+    /// Find the key with the given key ID (i.e. `kid`)
+    pub fn find(&self, kid: &str) -> Option<&Jwk> {
+        self.keys.iter().find(|key| key.kid() == Some(kid))
+    }
+}
+
+/// Get the JWK key type (i.e. `kty`) for the given algorithm
+fn key_type(algorithm: Algorithm) -> &'static str {
+    match algorithm {
+        Algorithm::Ed25519 | Algorithm::Ed448 => "OKP",
+        _ => "EC",
+    }
+}
+
+/// Get the JWK curve name (i.e. `crv`) for the given algorithm
+fn curve_name(algorithm: Algorithm) -> &'static str {
+    match algorithm {
+        Algorithm::Ed25519 => "Ed25519",
+        Algorithm::Ed448 => "Ed448",
+        Algorithm::NistP256 => "P-256",
+        Algorithm::NistP384 => "P-384",
+        Algorithm::NistP521 => "P-521",
+        Algorithm::Secp256k1 => "secp256k1",
+    }
+}
+
+/// Get a required string member of a JWK
+fn required_str<'a>(object: &'a Object, name: &str) -> Result<&'a str, Error> {
+    object
+        .get_str(name)?
+        .ok_or_else(|| decode_error("JWK is missing a required member"))
+}
+
+/// Decode a required base64url-encoded member of a JWK, ensuring it's the
+/// expected size
+fn decode_member(object: &Object, name: &str, size: usize) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let decoded = base64url::decode(required_str(object, name)?)?;
+
+    if decoded.len() == size {
+        Ok(decoded)
+    } else {
+        Err(decode_error("invalid JWK member size"))
+    }
+}
@@ -0,0 +1,359 @@
+//! Support for JSON Web Signatures (JWS) in the compact serialization
+//! described in [RFC 7515 Section 7.1], i.e. `header.payload.signature`,
+//! which is also the format of signed JSON Web Tokens (JWT, [RFC 7519]).
+//!
+//! The following algorithms are supported:
+//!
+//! - `EdDSA`: Ed25519 ([RFC 8037])
+//! - `ES256`: ECDSA with NIST P-256 and SHA-256 ([RFC 7518 Section 3.4])
+//! - `ES384`: ECDSA with NIST P-384 and SHA-384 ([RFC 7518 Section 3.4])
+//! - `ES512`: ECDSA with NIST P-521 and SHA-512 ([RFC 7518 Section 3.4])
+//! - `ES256K`: ECDSA with secp256k1 and SHA-256 ([RFC 8812])
+//!
+//! Tokens can be signed and verified using any Signatory provider which
+//! implements `signature::Signer` or `signature::Verifier` for one of the
+//! corresponding signature types. The `alg` header is always checked against
+//! the signature type (and therefore the key type) of the signer or verifier,
+//! so a token can never be verified using a different algorithm than the
+//! one its key is intended for.
+//!
+//! [RFC 7515 Section 7.1]: https://tools.ietf.org/html/rfc7515#section-7.1
+//! [RFC 7518 Section 3.4]: https://tools.ietf.org/html/rfc7518#section-3.4
+//! [RFC 7519]: https://tools.ietf.org/html/rfc7519
+//! [RFC 8037]: https://tools.ietf.org/html/rfc8037
+//! [RFC 8812]: https://tools.ietf.org/html/rfc8812
+
+use super::{
+    base64url,
+    error::{crypto_error, decode_error, Error},
+    json::{Object, ObjectWriter},
+    Algorithm,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    fmt::{self, Display},
+    str,
+};
+use signature::{Signature, Signer, Verifier};
+
+/// Signature types which can be used to create JWS signatures
+pub trait JwsSignature: Signature {
+    /// JWS algorithm (i.e. `alg`) identifying this signature type
+    const ALGORITHM: JwsAlgorithm;
+}
+
+#[cfg(feature = "ed25519")]
+impl JwsSignature for crate::ed25519::Signature {
+    const ALGORITHM: JwsAlgorithm = JwsAlgorithm::EdDsa;
+}
+
+#[cfg(all(feature = "ecdsa", feature = "p256"))]
+impl JwsSignature for crate::ecdsa::nistp256::FixedSignature {
+    const ALGORITHM: JwsAlgorithm = JwsAlgorithm::Es256;
+}
+
+#[cfg(all(feature = "ecdsa", feature = "p384"))]
+impl JwsSignature for crate::ecdsa::nistp384::FixedSignature {
+    const ALGORITHM: JwsAlgorithm = JwsAlgorithm::Es384;
+}
+
+#[cfg(all(feature = "ecdsa", feature = "p521"))]
+impl JwsSignature for crate::ecdsa::nistp521::FixedSignature {
+    const ALGORITHM: JwsAlgorithm = JwsAlgorithm::Es512;
+}
+
+#[cfg(all(feature = "ecdsa", feature = "k256"))]
+impl JwsSignature for crate::ecdsa::secp256k1::FixedSignature {
+    const ALGORITHM: JwsAlgorithm = JwsAlgorithm::Es256k;
+}
+
+/// JWS algorithms (i.e. the `alg` header parameter)
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum JwsAlgorithm {
+    /// `EdDSA`: Ed25519
+    EdDsa,
+
+    /// `ES256`: ECDSA with NIST P-256 and SHA-256
+    Es256,
+
+    /// `ES384`: ECDSA with NIST P-384 and SHA-384
+    Es384,
+
+    /// `ES512`: ECDSA with NIST P-521 and SHA-512
+    Es512,
+
+    /// `ES256K`: ECDSA with secp256k1 and SHA-256
+    Es256k,
+}
+
+impl JwsAlgorithm {
+    /// Get the name of this algorithm as used in the `alg` header parameter
+    pub fn as_str(self) -> &'static str {
+        match self {
+            JwsAlgorithm::EdDsa => "EdDSA",
+            JwsAlgorithm::Es256 => "ES256",
+            JwsAlgorithm::Es384 => "ES384",
+            JwsAlgorithm::Es512 => "ES512",
+            JwsAlgorithm::Es256k => "ES256K",
+        }
+    }
+
+    /// Get the algorithm of the keys used with this JWS algorithm
+    pub fn key_algorithm(self) -> Algorithm {
+        match self {
+            JwsAlgorithm::EdDsa => Algorithm::Ed25519,
+            JwsAlgorithm::Es256 => Algorithm::NistP256,
+            JwsAlgorithm::Es384 => Algorithm::NistP384,
+            JwsAlgorithm::Es512 => Algorithm::NistP521,
+            JwsAlgorithm::Es256k => Algorithm::Secp256k1,
+        }
+    }
+
+    /// Find the algorithm with the given name.
+    ///
+    /// Names are case sensitive, and `none` is never supported.
+    fn from_name(name: &str) -> Result<Self, Error> {
+        [
+            JwsAlgorithm::EdDsa,
+            JwsAlgorithm::Es256,
+            JwsAlgorithm::Es384,
+            JwsAlgorithm::Es512,
+            JwsAlgorithm::Es256k,
+        ]
+        .iter()
+        .find(|alg| alg.as_str() == name)
+        .cloned()
+        .ok_or_else(|| decode_error("unsupported JWS algorithm"))
+    }
+}
+
+impl Display for JwsAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// JWS protected header
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Header {
+    /// Signature algorithm (i.e. `alg`)
+    alg: JwsAlgorithm,
+
+    /// Key ID (i.e. `kid`)
+    kid: Option<String>,
+
+    /// Media type (i.e. `typ`), e.g. `JWT`
+    typ: Option<String>,
+}
+
+impl Header {
+    /// Create a new header for the given algorithm
+    pub fn new(alg: JwsAlgorithm) -> Self {
+        Self {
+            alg,
+            kid: None,
+            typ: None,
+        }
+    }
+
+    /// Set the key ID (i.e. `kid`), e.g. a JWK thumbprint
+    pub fn with_kid<S: Into<String>>(mut self, kid: S) -> Self {
+        self.kid = Some(kid.into());
+        self
+    }
+
+    /// Set the media type (i.e. `typ`), e.g. `JWT`
+    pub fn with_typ<S: Into<String>>(mut self, typ: S) -> Self {
+        self.typ = Some(typ.into());
+        self
+    }
+
+    /// Get the signature algorithm (i.e. `alg`)
+    pub fn alg(&self) -> JwsAlgorithm {
+        self.alg
+    }
+
+    /// Get the key ID (i.e. `kid`), if present
+    pub fn kid(&self) -> Option<&str> {
+        self.kid.as_deref()
+    }
+
+    /// Get the media type (i.e. `typ`), if present
+    pub fn typ(&self) -> Option<&str> {
+        self.typ.as_deref()
+    }
+
+    /// Ensure this header's algorithm is intended for keys of the given
+    /// algorithm, e.g. one obtained from `Jwk::algorithm()` after looking up
+    /// the key by `kid`
+    pub fn check_key_algorithm(&self, algorithm: Algorithm) -> Result<(), Error> {
+        if self.alg.key_algorithm() == algorithm {
+            Ok(())
+        } else {
+            Err(crypto_error("JWS algorithm doesn't match key type"))
+        }
+    }
+
+    /// Parse a header from its JSON serialization
+    fn from_json(json: &str) -> Result<Self, Error> {
+        let object = Object::parse(json)?;
+
+        // RFC 7515 Section 4.1.11: no extensions are understood, so critical
+        // extensions (e.g. unencoded payloads) can't be processed
+        if object.get("crit").is_some() {
+            return Err(decode_error("unsupported critical JWS header parameters"));
+        }
+
+        let alg = object
+            .get_str("alg")?
+            .ok_or_else(|| decode_error("JWS header is missing alg"))?;
+
+        Ok(Self {
+            alg: JwsAlgorithm::from_name(alg)?,
+            kid: object.get_str("kid")?.map(ToString::to_string),
+            typ: object.get_str("typ")?.map(ToString::to_string),
+        })
+    }
+
+    /// Serialize this header as JSON
+    fn to_json(&self) -> String {
+        let mut writer = ObjectWriter::new();
+        writer.string("alg", self.alg.as_str());
+
+        if let Some(kid) = &self.kid {
+            writer.string("kid", kid);
+        }
+
+        if let Some(typ) = &self.typ {
+            writer.string("typ", typ);
+        }
+
+        writer.finish().to_string()
+    }
+}
+
+/// JSON Web Signature in the compact serialization
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Jws {
+    /// Protected header
+    header: Header,
+
+    /// Signing input: the base64url-encoded header and payload, separated
+    /// by a period
+    signing_input: String,
+
+    /// Decoded payload
+    payload: Vec<u8>,
+
+    /// Raw signature: a 64-byte Ed25519 signature or a fixed-width ECDSA
+    /// signature (i.e. `r || s`)
+    signature: Vec<u8>,
+}
+
+impl Jws {
+    /// Sign the given payload with the given header using the given signer.
+    ///
+    /// The header's algorithm must match the signature type being produced.
+    pub fn sign<S, Sig>(signer: &S, header: Header, payload: &[u8]) -> Result<Self, Error>
+    where
+        S: Signer<Sig>,
+        Sig: JwsSignature,
+    {
+        if header.alg != Sig::ALGORITHM {
+            return Err(crypto_error("JWS algorithm doesn't match signature type"));
+        }
+
+        let mut signing_input = base64url::encode(header.to_json().as_bytes());
+        signing_input.push('.');
+        signing_input.push_str(&base64url::encode(payload));
+
+        let signature = signer
+            .try_sign(signing_input.as_bytes())
+            .map_err(|_| crypto_error("signing failed"))?;
+
+        Ok(Self {
+            header,
+            signing_input,
+            payload: payload.to_vec(),
+            signature: signature.as_ref().to_vec(),
+        })
+    }
+
+    /// Parse a JWS in the compact serialization, i.e. `header.payload.signature`.
+    ///
+    /// The signature is not verified until `Jws::verify()` is called.
+    pub fn parse(compact: &str) -> Result<Self, Error> {
+        let mut parts = compact.split('.');
+
+        let (encoded_header, encoded_payload, encoded_signature) =
+            match (parts.next(), parts.next(), parts.next(), parts.next()) {
+                (Some(header), Some(payload), Some(signature), None) => {
+                    (header, payload, signature)
+                }
+                _ => return Err(decode_error("malformed JWS compact serialization")),
+            };
+
+        let header_json = base64url::decode(encoded_header)?;
+        let header = Header::from_json(
+            str::from_utf8(&header_json).map_err(|_| decode_error("JWS header isn't UTF-8"))?,
+        )?;
+
+        let signing_input_len = add!(add!(encoded_header.len(), encoded_payload.len()), 1);
+
+        Ok(Self {
+            header,
+            signing_input: compact[..signing_input_len].to_string(),
+            payload: base64url::decode(encoded_payload)?.to_vec(),
+            signature: base64url::decode(encoded_signature)?.to_vec(),
+        })
+    }
+
+    /// Verify this JWS using the given verifier, returning the payload if
+    /// the signature is valid.
+    ///
+    /// The verifier's signature type must match the `alg` header, and it must
+    /// be constructed from a trusted public key (which may be selected using
+    /// `Header::kid()`).
+    pub fn verify<V, Sig>(&self, verifier: &V) -> Result<&[u8], Error>
+    where
+        V: Verifier<Sig>,
+        Sig: JwsSignature,
+    {
+        if self.header.alg != Sig::ALGORITHM {
+            return Err(crypto_error("JWS algorithm doesn't match key type"));
+        }
+
+        let signature = Sig::from_bytes(&self.signature)
+            .map_err(|_| decode_error("malformed JWS signature"))?;
+
+        verifier
+            .verify(self.signing_input.as_bytes(), &signature)
+            .map_err(|_| crypto_error("JWS verification failed"))?;
+
+        Ok(&self.payload)
+    }
+
+    /// Serialize this JWS in the compact serialization
+    pub fn to_compact(&self) -> String {
+        let mut compact = self.signing_input.clone();
+        compact.push('.');
+        compact.push_str(&base64url::encode(&self.signature));
+        compact
+    }
+
+    /// Get the protected header.
+    ///
+    /// The header is unauthenticated until `Jws::verify()` succeeds.
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Get the raw signature: a 64-byte Ed25519 signature or a fixed-width
+    /// ECDSA signature (i.e. `r || s`)
+    pub fn signature(&self) -> &[u8] {
+        &self.signature
+    }
+}
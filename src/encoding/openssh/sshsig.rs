@@ -0,0 +1,308 @@
+//! Support for the OpenSSH `SSHSIG` signature format described in
+//! [PROTOCOL.sshsig], i.e. `-----BEGIN SSH SIGNATURE-----`.
+//!
+//! These signatures are produced and verified by `ssh-keygen -Y sign` and
+//! `ssh-keygen -Y verify`, and are used by git to sign commits and tags when
+//! configured with `gpg.format=ssh`.
+//!
+//! Signatures can be created using any Signatory provider which implements
+//! `signature::Signer` and `PublicKeyed` for Ed25519, NIST P-256, NIST
+//! P-384, or NIST P-521 keys.
+//!
+//! [PROTOCOL.sshsig]: https://cvsweb.openbsd.org/src/usr.bin/ssh/PROTOCOL.sshsig?annotate=HEAD
+
+use super::{PublicKey, Reader, Writer};
+use crate::{
+    encoding::{
+        error::{crypto_error, decode_error, Error},
+        pem, Algorithm,
+    },
+    public_key::PublicKeyed,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use sha2::{Digest, Sha256, Sha512};
+use signature::{Signature, Signer, Verifier};
+
+/// Magic string which begins every `SSHSIG` signature and signed message
+const MAGIC_PREAMBLE: &[u8] = b"SSHSIG";
+
+/// `SSHSIG` format version
+const VERSION: u32 = 1;
+
+/// Signature types which can be used to create `SSHSIG` signatures
+pub trait SshSignature: Signature {
+    /// Algorithm of the keys which produce this signature type
+    const ALGORITHM: Algorithm;
+}
+
+#[cfg(feature = "ed25519")]
+impl SshSignature for crate::ed25519::Signature {
+    const ALGORITHM: Algorithm = Algorithm::Ed25519;
+}
+
+#[cfg(all(feature = "ecdsa", feature = "p256"))]
+impl SshSignature for crate::ecdsa::nistp256::FixedSignature {
+    const ALGORITHM: Algorithm = Algorithm::NistP256;
+}
+
+#[cfg(all(feature = "ecdsa", feature = "p384"))]
+impl SshSignature for crate::ecdsa::nistp384::FixedSignature {
+    const ALGORITHM: Algorithm = Algorithm::NistP384;
+}
+
+#[cfg(all(feature = "ecdsa", feature = "p521"))]
+impl SshSignature for crate::ecdsa::nistp521::FixedSignature {
+    const ALGORITHM: Algorithm = Algorithm::NistP521;
+}
+
+/// Hash algorithms used to compute the message digest which is signed
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum HashAlgorithm {
+    /// SHA-256
+    Sha256,
+
+    /// SHA-512 (the default used by `ssh-keygen`)
+    Sha512,
+}
+
+impl HashAlgorithm {
+    /// Get the name of this hash algorithm as used in `SSHSIG` signatures
+    pub fn as_str(self) -> &'static str {
+        match self {
+            HashAlgorithm::Sha256 => "sha256",
+            HashAlgorithm::Sha512 => "sha512",
+        }
+    }
+
+    /// Find the hash algorithm with the given name
+    fn from_name(name: &str) -> Result<Self, Error> {
+        [HashAlgorithm::Sha256, HashAlgorithm::Sha512]
+            .iter()
+            .find(|alg| alg.as_str() == name)
+            .cloned()
+            .ok_or_else(|| decode_error("unsupported SSHSIG hash algorithm"))
+    }
+
+    /// Compute the digest of the given message
+    fn digest(self, msg: &[u8]) -> Vec<u8> {
+        match self {
+            HashAlgorithm::Sha256 => Sha256::digest(msg).to_vec(),
+            HashAlgorithm::Sha512 => Sha512::digest(msg).to_vec(),
+        }
+    }
+}
+
+// `#[default]` on enum variants isn't supported on our MSRV
+impl Default for HashAlgorithm {
+    fn default() -> Self {
+        HashAlgorithm::Sha512
+    }
+}
+
+/// OpenSSH `SSHSIG` signature
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SshSig {
+    /// Public key of the signer
+    public_key: PublicKey,
+
+    /// Namespace which prevents signatures being reused in other contexts,
+    /// e.g. `git` or `file`
+    namespace: String,
+
+    /// Reserved field (empty in signatures created by this crate)
+    reserved: Vec<u8>,
+
+    /// Hash algorithm used to compute the signed message digest
+    hash_algorithm: HashAlgorithm,
+
+    /// Raw signature: a 64-byte Ed25519 signature or a fixed-width ECDSA
+    /// signature (i.e. `r || s`)
+    signature: Vec<u8>,
+}
+
+impl SshSig {
+    /// Sign the given message in the given namespace (e.g. `git` or `file`)
+    /// using the given signer
+    pub fn sign<S, Sig, K>(
+        signer: &S,
+        namespace: &str,
+        hash_algorithm: HashAlgorithm,
+        msg: &[u8],
+    ) -> Result<Self, Error>
+    where
+        S: Signer<Sig> + PublicKeyed<K>,
+        Sig: SshSignature,
+        K: crate::public_key::PublicKey,
+    {
+        if namespace.is_empty() {
+            return Err(crypto_error("SSHSIG namespace must not be empty"));
+        }
+
+        let public_key = signer
+            .public_key()
+            .map_err(|_| crypto_error("couldn't get signer's public key"))?;
+
+        let mut sshsig = Self {
+            public_key: PublicKey::new(Sig::ALGORITHM, public_key.as_ref(), "")?,
+            namespace: namespace.to_string(),
+            reserved: Vec::new(),
+            hash_algorithm,
+            signature: Vec::new(),
+        };
+
+        let signature = signer
+            .try_sign(&sshsig.signed_data(msg))
+            .map_err(|_| crypto_error("signing failed"))?;
+
+        sshsig.signature = signature.as_ref().to_vec();
+        Ok(sshsig)
+    }
+
+    /// Parse an armored `-----BEGIN SSH SIGNATURE-----` document
+    pub fn from_pem<S: AsRef<str>>(pem: S) -> Result<Self, Error> {
+        let bytes = pem::decode_label(pem.as_ref().as_bytes(), pem::Label::SshSignature)?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// Parse a binary (i.e. Base64-decoded) `SSHSIG` signature
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if !bytes.starts_with(MAGIC_PREAMBLE) {
+            return Err(decode_error("missing SSHSIG magic"));
+        }
+
+        let mut reader = Reader::new(&bytes[MAGIC_PREAMBLE.len()..]);
+
+        if reader.u32()? != VERSION {
+            return Err(decode_error("unsupported SSHSIG version"));
+        }
+
+        let mut public_key_reader = Reader::new(reader.string()?);
+        let public_key = PublicKey::decode(&mut public_key_reader, "")?;
+        public_key_reader.finish()?;
+
+        let namespace = reader.str()?.to_string();
+        let reserved = reader.string()?.to_vec();
+        let hash_algorithm = HashAlgorithm::from_name(reader.str()?)?;
+
+        let mut signature_reader = Reader::new(reader.string()?);
+        reader.finish()?;
+
+        if signature_reader.str()? != public_key.key_type() {
+            return Err(decode_error("mismatched SSHSIG signature type"));
+        }
+
+        let signature = match public_key.algorithm {
+            Algorithm::Ed25519 => signature_reader.string()?.to_vec(),
+            algorithm => {
+                let size = algorithm.secret_key_size();
+                let mut ecdsa_reader = Reader::new(signature_reader.string()?);
+                let mut signature = ecdsa_reader.mpint(size)?.to_vec();
+                signature.extend_from_slice(&ecdsa_reader.mpint(size)?);
+                ecdsa_reader.finish()?;
+                signature
+            }
+        };
+
+        signature_reader.finish()?;
+
+        Ok(Self {
+            public_key,
+            namespace,
+            reserved,
+            hash_algorithm,
+            signature,
+        })
+    }
+
+    /// Serialize this signature as an armored
+    /// `-----BEGIN SSH SIGNATURE-----` document
+    pub fn to_pem(&self) -> String {
+        pem::encode(pem::Label::SshSignature, &self.to_bytes()).to_string()
+    }
+
+    /// Serialize this signature in the binary `SSHSIG` format
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut signature = Writer::default();
+        signature.string(self.public_key.key_type().as_bytes());
+
+        if self.public_key.algorithm == Algorithm::Ed25519 {
+            signature.string(&self.signature);
+        } else {
+            let (r, s) = self.signature.split_at(self.signature.len() / 2);
+            let mut ecdsa_signature = Writer::default();
+            ecdsa_signature.mpint(r);
+            ecdsa_signature.mpint(s);
+            signature.string(&ecdsa_signature.0);
+        }
+
+        let mut writer = Writer(MAGIC_PREAMBLE.to_vec());
+        writer.u32(VERSION);
+        writer.string(&self.public_key.to_wire());
+        writer.string(self.namespace.as_bytes());
+        writer.string(&self.reserved);
+        writer.string(self.hash_algorithm.as_str().as_bytes());
+        writer.string(&signature.0);
+        writer.0
+    }
+
+    /// Verify this signature over the given message using the given
+    /// verifier, ensuring it was created in the expected namespace.
+    ///
+    /// The verifier must be constructed from a trusted public key (e.g. one
+    /// listed in an `allowed_signers` file), which should be compared to
+    /// `SshSig::public_key()` before calling this method.
+    pub fn verify<V, Sig>(&self, verifier: &V, namespace: &str, msg: &[u8]) -> Result<(), Error>
+    where
+        V: Verifier<Sig>,
+        Sig: SshSignature,
+    {
+        if self.namespace != namespace {
+            return Err(crypto_error("SSHSIG namespace mismatch"));
+        }
+
+        if self.public_key.algorithm != Sig::ALGORITHM {
+            return Err(crypto_error("SSHSIG algorithm mismatch"));
+        }
+
+        let signature = Sig::from_bytes(&self.signature)
+            .map_err(|_| decode_error("malformed SSHSIG signature"))?;
+
+        verifier
+            .verify(&self.signed_data(msg), &signature)
+            .map_err(|_| crypto_error("SSHSIG verification failed"))
+    }
+
+    /// Get the public key of the signer
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    /// Get the namespace this signature was created in
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    /// Get the hash algorithm used to compute the signed message digest
+    pub fn hash_algorithm(&self) -> HashAlgorithm {
+        self.hash_algorithm
+    }
+
+    /// Get the raw signature: a 64-byte Ed25519 signature or a fixed-width
+    /// ECDSA signature (i.e. `r || s`)
+    pub fn signature(&self) -> &[u8] {
+        &self.signature
+    }
+
+    /// Compute the data which is actually signed for the given message
+    fn signed_data(&self, msg: &[u8]) -> Vec<u8> {
+        let mut writer = Writer(MAGIC_PREAMBLE.to_vec());
+        writer.string(self.namespace.as_bytes());
+        writer.string(&self.reserved);
+        writer.string(self.hash_algorithm.as_str().as_bytes());
+        writer.string(&self.hash_algorithm.digest(msg));
+        writer.0
+    }
+}
@@ -0,0 +1,111 @@
+//! Support for the **SubjectPublicKeyInfo** (SPKI) public key format
+//! described in [RFC 5280 Section 4.1.2.7], as used by X.509 certificates
+//! and emitted by tools like OpenSSL, Go's `crypto/x509`, and Java.
+//!
+//! Algorithm-specific details are described in [RFC 5480] (elliptic curve
+//! keys) and [RFC 8410] (Ed25519 and Ed448 keys).
+//!
+//! [RFC 5280 Section 4.1.2.7]: https://tools.ietf.org/html/rfc5280#section-4.1.2.7
+//! [RFC 5480]: https://tools.ietf.org/html/rfc5480
+//! [RFC 8410]: https://tools.ietf.org/html/rfc8410
+
+#[cfg(feature = "alloc")]
+use super::der::Encoder;
+#[cfg(feature = "pem")]
+use super::pem;
+use super::{
+    der::{self, Decoder},
+    error::{decode_error, Error},
+    Algorithm,
+};
+#[cfg(feature = "pem")]
+use alloc::string::{String, ToString};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Decode a public key from a DER-encoded **SubjectPublicKeyInfo** document
+pub trait FromPublicKeyDer: Sized {
+    /// Parse the given DER-encoded **SubjectPublicKeyInfo**, returning an
+    /// error if it's malformed or for a different algorithm.
+    fn from_public_key_der(bytes: &[u8]) -> Result<Self, Error>;
+
+    /// Parse the given PEM-encoded **SubjectPublicKeyInfo**, i.e. one
+    /// beginning with `-----BEGIN PUBLIC KEY-----`.
+    #[cfg(feature = "pem")]
+    fn from_public_key_pem<S: AsRef<str>>(pem: S) -> Result<Self, Error> {
+        let der = pem::decode_label(pem.as_ref().as_bytes(), pem::Label::PublicKey)?;
+        Self::from_public_key_der(&der)
+    }
+}
+
+/// Encode a public key as a DER-encoded **SubjectPublicKeyInfo** document
+#[cfg(feature = "alloc")]
+pub trait ToPublicKeyDer {
+    /// Serialize this public key as a DER-encoded **SubjectPublicKeyInfo**
+    fn to_public_key_der(&self) -> Vec<u8>;
+
+    /// Serialize this public key as a PEM-encoded **SubjectPublicKeyInfo**
+    #[cfg(feature = "pem")]
+    fn to_public_key_pem(&self) -> String {
+        pem::encode(pem::Label::PublicKey, &self.to_public_key_der()).to_string()
+    }
+}
+
+/// Parsed **SubjectPublicKeyInfo** borrowing from a serialized document
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct SubjectPublicKeyInfo<'a> {
+    /// Algorithm this key is intended to be used with
+    pub algorithm: Algorithm,
+
+    /// Serialized public key: the compressed Edwards-y coordinate for Ed25519
+    /// and Ed448, or a SEC1-encoded point for elliptic curve keys
+    pub subject_public_key: &'a [u8],
+}
+
+impl<'a> SubjectPublicKeyInfo<'a> {
+    /// Parse a DER-encoded **SubjectPublicKeyInfo**, ensuring it's well-formed
+    pub fn from_der(bytes: &'a [u8]) -> Result<Self, Error> {
+        let mut decoder = Decoder::new(bytes);
+        let mut spki = decoder.sequence()?;
+        decoder.finish()?;
+
+        let algorithm = Algorithm::decode(&mut spki)?;
+        let subject_public_key = spki.bit_string()?;
+        spki.finish()?;
+
+        algorithm.check_public_key(subject_public_key)?;
+
+        Ok(Self {
+            algorithm,
+            subject_public_key,
+        })
+    }
+
+    /// Parse a DER-encoded **SubjectPublicKeyInfo**, ensuring it's for the
+    /// expected algorithm
+    pub fn from_der_with_algorithm(bytes: &'a [u8], algorithm: Algorithm) -> Result<Self, Error> {
+        let spki = Self::from_der(bytes)?;
+
+        if spki.algorithm == algorithm {
+            Ok(spki)
+        } else {
+            Err(decode_error("unexpected public key algorithm"))
+        }
+    }
+
+    /// Serialize this key as a DER-encoded **SubjectPublicKeyInfo**
+    #[cfg(feature = "alloc")]
+    pub fn to_der(&self) -> Vec<u8> {
+        let public_key_len = add!(self.subject_public_key.len(), 1);
+        let contents_len = add!(
+            self.algorithm.encoded_len(),
+            der::encoded_len(public_key_len)
+        );
+
+        let mut encoder = Encoder::new(der::encoded_len(contents_len));
+        encoder.header(der::SEQUENCE, contents_len);
+        self.algorithm.encode(&mut encoder);
+        encoder.bit_string(der::BIT_STRING, self.subject_public_key);
+        encoder.finish()
+    }
+}
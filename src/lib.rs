@@ -9,12 +9,27 @@
 //! - [ecdsa]: Elliptic Curve Digital Signature Algorithm ([FIPS 186-4])
 //! - [ed25519]: Edwards Digital Signature Algorithm (EdDSA) instantiated using
 //!   the twisted Edwards form of Curve25519 ([RFC 8032]).
+//! - [ed448]: EdDSA instantiated using the Edwards form of Curve448
+//!   ([RFC 8032]).
 //!
 //! ## Providers
 //!
 //! There are several backend providers available, which are each available
 //! in their own crates:
 //!
+//! - built-in: Ed25519 (including Ed25519ctx and Ed25519ph) signing and
+//!   verification using the pure-Rust [curve25519-dalek] crate, available
+//!   via the `ed25519-software` cargo feature of this crate.
+//! - built-in: deterministic (RFC 6979) ECDSA signing and verification for
+//!   NIST P-256 and secp256k1 using the pure-Rust [p256] and [k256] crates,
+//!   and for NIST P-521 (`p521` feature) using built-in curve arithmetic,
+//!   available via the `ecdsa-software` cargo feature of this crate.
+//! - built-in: Ed448 (including Ed448ph) signing and verification using
+//!   built-in curve arithmetic, available via the `ed448-software` cargo
+//!   feature of this crate. All built-in providers are `no_std` and don't
+//!   require an RNG.
+//! - built-in: ECDH for NIST P-256, P-384 and secp256k1 keys, and X25519 for
+//!   Ed25519 keys (see the [`ecdh`] module).
 //! - [signatory-dalek]: Ed25519 signing/verification using the pure-Rust
 //!   [ed25519-dalek] crate.
 //! - [signatory-ring]: ECDSA and Ed25519 signing/verification provider
@@ -39,12 +54,17 @@
 //!
 //! - [Verifier]: trait for verifying
 //! - [DigestVerifier]: trait for verifying digests
+//! - [BatchVerifier]: trait for verifying many signatures at once
 //!
 //! [FIPS 186-4]: https://csrc.nist.gov/publications/detail/fips/186/4/final
 //! [RFC 8032]: https://tools.ietf.org/html/rfc8032
 //! [ecdsa]: https://docs.rs/signatory/latest/signatory/ecdsa/index.html
 //! [ed25519]: https://docs.rs/signatory/latest/signatory/ed25519/index.html
+//! [ed448]: https://docs.rs/signatory/latest/signatory/ed448/index.html
 //! [signatory-dalek]: https://docs.rs/crate/signatory-dalek/
+//! [curve25519-dalek]: https://docs.rs/crate/curve25519-dalek/
+//! [p256]: https://docs.rs/crate/p256/
+//! [k256]: https://docs.rs/crate/k256/
 //! [ed25519-dalek]: https://docs.rs/crate/ed25519-dalek/
 //! [signatory-ring]: https://docs.rs/crate/signatory-ring/
 //! [*ring*]: https://github.com/briansmith/ring
@@ -59,6 +79,7 @@
 //! [DigestSigner]: https://docs.rs/signatory/latest/signatory/trait.DigestSigner.html
 //! [Verifier]: https://docs.rs/signatory/latest/signatory/trait.Verifier.html
 //! [DigestVerifier]: https://docs.rs/signatory/latest/signatory/trait.DigestVerifier.html
+//! [BatchVerifier]: https://docs.rs/signatory/latest/signatory/batch/trait.BatchVerifier.html
 
 #![no_std]
 #![forbid(unsafe_code)]
@@ -75,11 +96,16 @@ extern crate alloc;
 #[macro_use]
 extern crate std;
 
+#[cfg(feature = "alloc")]
+pub mod batch;
+pub mod ecdh;
 #[cfg(feature = "ecdsa")]
 pub mod ecdsa;
 #[cfg(feature = "ed25519")]
 #[macro_use]
 pub mod ed25519;
+#[cfg(feature = "ed448")]
+pub mod ed448;
 #[cfg(feature = "encoding")]
 pub mod encoding;
 pub mod public_key;
@@ -89,4 +115,6 @@ pub mod test_vector;
 pub use generic_array;
 #[cfg(feature = "sha2")]
 pub use sha2;
+#[cfg(feature = "sha3")]
+pub use sha3;
 pub use signature;
@@ -4,7 +4,11 @@ use core::fmt::Debug;
 use signature::Error;
 
 /// Signers which know their public keys (to be implemented by Signatory
-/// providers)
+/// providers).
+///
+/// With the `ed25519-software` and `ecdsa-software` features, this is also
+/// implemented for `ed25519::Seed` and the ECDSA `SecretKey` types, which
+/// derive their public keys using curve arithmetic.
 pub trait PublicKeyed<K: PublicKey>: Send + Sync {
     /// Public key which can verify signatures created by this signer
     fn public_key(&self) -> Result<K, Error>;
@@ -1,9 +1,13 @@
 //! Test vector structure for signatures
 
-#[cfg(all(feature = "alloc", feature = "ecdsa"))]
+#[cfg(feature = "test-vectors")]
+mod bip340;
+#[cfg(all(feature = "alloc", feature = "ecdsa", feature = "pkcs8"))]
 mod pkcs8;
 
-#[cfg(all(feature = "alloc", feature = "ecdsa"))]
+#[cfg(feature = "test-vectors")]
+pub use bip340::BIP340_TEST_VECTORS;
+#[cfg(all(feature = "alloc", feature = "ecdsa", feature = "pkcs8"))]
 pub use pkcs8::ToPkcs8;
 
 /// Signature test vector
@@ -30,6 +34,61 @@ pub struct TestVector {
     pub pass: bool,
 }
 
+/// Signature test vector for algorithms which bind a context string into
+/// the signature (e.g. Ed25519ctx)
+pub struct ContextTestVector {
+    /// Context string
+    pub ctx: &'static [u8],
+
+    /// Test vector
+    pub vector: TestVector,
+}
+
+/// Ed25519 verification edge case, along with whether it's expected to
+/// verify under each `ed25519::VerificationPolicy`
+#[cfg(feature = "ed25519")]
+pub struct EdgeCaseTestVector {
+    /// Description of the edge case
+    pub description: &'static str,
+
+    /// Public key (possibly a non-canonical or small-order encoding)
+    pub pk: &'static [u8],
+
+    /// Message which was signed
+    pub msg: &'static [u8],
+
+    /// Signature to be verified
+    pub sig: &'static [u8],
+
+    /// Expected to verify under `VerificationPolicy::Rfc8032`
+    pub rfc8032: bool,
+
+    /// Expected to verify under `VerificationPolicy::Zip215`
+    pub zip215: bool,
+
+    /// Expected to verify under `VerificationPolicy::Libsodium`
+    pub libsodium: bool,
+}
+
+#[cfg(feature = "ed25519")]
+impl EdgeCaseTestVector {
+    /// Is this edge case expected to verify under the given policy?
+    pub fn expected(&self, policy: crate::ed25519::VerificationPolicy) -> bool {
+        use crate::ed25519::VerificationPolicy;
+
+        match policy {
+            VerificationPolicy::Rfc8032 => self.rfc8032,
+            VerificationPolicy::Zip215 => self.zip215,
+            VerificationPolicy::Libsodium => self.libsodium,
+        }
+    }
+
+    /// Do all verification policies agree on whether this edge case is valid?
+    pub fn is_unambiguous(&self) -> bool {
+        self.rfc8032 == self.zip215 && self.zip215 == self.libsodium
+    }
+}
+
 /// Algorithms for which we have test vectors
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub enum TestVectorAlgorithm {
@@ -39,9 +98,27 @@ pub enum TestVectorAlgorithm {
     /// NIST P-384 (a.k.a. secp384r1) elliptic curve
     NistP384,
 
+    /// NIST P-521 (a.k.a. secp521r1) elliptic curve
+    NistP521,
+
     /// secp256k1 elliptic curve
     Secp256k1,
 
     /// "edwards25519" elliptic curve
     Ed25519,
+
+    /// Ed25519ctx: Ed25519 with a context string
+    Ed25519ctx,
+
+    /// Ed25519ph: Ed25519 with a SHA-512 prehash of the message
+    Ed25519ph,
+
+    /// "edwards448" elliptic curve
+    Ed448,
+
+    /// Ed448ph: Ed448 with a SHAKE256 prehash of the message
+    Ed448ph,
+
+    /// BIP-340 Schnorr signatures over the secp256k1 elliptic curve
+    Bip340,
 }
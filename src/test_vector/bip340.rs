@@ -0,0 +1,200 @@
+//! BIP-340 Schnorr signature test vectors
+
+use super::{TestVector, TestVectorAlgorithm};
+
+/// BIP-340 Schnorr signature test vectors for secp256k1, from the official
+/// `test-vectors.csv` in the BIP repository (converted to Rust bytestring
+/// literals).
+///
+/// `pk` is the 32-byte x-only public key and `nonce` is the auxiliary random
+/// data (`aux_rand`) used when signing. Vectors with an empty secret key are
+/// for verification only.
+#[rustfmt::skip]
+pub const BIP340_TEST_VECTORS: &[TestVector] = &[
+    TestVector {
+        alg: TestVectorAlgorithm::Bip340,
+        sk: b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x03",
+        pk: b"\xf9\x30\x8a\x01\x92\x58\xc3\x10\x49\x34\x4f\x85\xf8\x9d\x52\x29\xb5\x31\xc8\x45\x83\x6f\x99\xb0\x86\x01\xf1\x13\xbc\xe0\x36\xf9",
+        nonce: Some(b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00"),
+        msg: b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+        sig: b"\xe9\x07\x83\x1f\x80\x84\x8d\x10\x69\xa5\x37\x1b\x40\x24\x10\x36\x4b\xdf\x1c\x5f\x83\x07\xb0\x08\x4c\x55\xf1\xce\x2d\xca\x82\x15\x25\xf6\x6a\x4a\x85\xea\x8b\x71\xe4\x82\xa7\x4f\x38\x2d\x2c\xe5\xeb\xee\xe8\xfd\xb2\x17\x2f\x47\x7d\xf4\x90\x0d\x31\x05\x36\xc0",
+        pass: true
+    },
+    TestVector {
+        alg: TestVectorAlgorithm::Bip340,
+        sk: b"\xb7\xe1\x51\x62\x8a\xed\x2a\x6a\xbf\x71\x58\x80\x9c\xf4\xf3\xc7\x62\xe7\x16\x0f\x38\xb4\xda\x56\xa7\x84\xd9\x04\x51\x90\xcf\xef",
+        pk: b"\xdf\xf1\xd7\x7f\x2a\x67\x1c\x5f\x36\x18\x37\x26\xdb\x23\x41\xbe\x58\xfe\xae\x1d\xa2\xde\xce\xd8\x43\x24\x0f\x7b\x50\x2b\xa6\x59",
+        nonce: Some(b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01"),
+        msg: b"\x24\x3f\x6a\x88\x85\xa3\x08\xd3\x13\x19\x8a\x2e\x03\x70\x73\x44\xa4\x09\x38\x22\x29\x9f\x31\xd0\x08\x2e\xfa\x98\xec\x4e\x6c\x89",
+        sig: b"\x68\x96\xbd\x60\xee\xae\x29\x6d\xb4\x8a\x22\x9f\xf7\x1d\xfe\x07\x1b\xde\x41\x3e\x6d\x43\xf9\x17\xdc\x8d\xcf\x8c\x78\xde\x33\x41\x89\x06\xd1\x1a\xc9\x76\xab\xcc\xb2\x0b\x09\x12\x92\xbf\xf4\xea\x89\x7e\xfc\xb6\x39\xea\x87\x1c\xfa\x95\xf6\xde\x33\x9e\x4b\x0a",
+        pass: true
+    },
+    TestVector {
+        alg: TestVectorAlgorithm::Bip340,
+        sk: b"\xc9\x0f\xda\xa2\x21\x68\xc2\x34\xc4\xc6\x62\x8b\x80\xdc\x1c\xd1\x29\x02\x4e\x08\x8a\x67\xcc\x74\x02\x0b\xbe\xa6\x3b\x14\xe5\xc9",
+        pk: b"\xdd\x30\x8a\xfe\xc5\x77\x7e\x13\x12\x1f\xa7\x2b\x9c\xc1\xb7\xcc\x01\x39\x71\x53\x09\xb0\x86\xc9\x60\xe1\x8f\xd9\x69\x77\x4e\xb8",
+        nonce: Some(b"\xc8\x7a\xa5\x38\x24\xb4\xd7\xae\x2e\xb0\x35\xa2\xb5\xbb\xbc\xcc\x08\x0e\x76\xcd\xc6\xd1\x69\x2c\x4b\x0b\x62\xd7\x98\xe6\xd9\x06"),
+        msg: b"\x7e\x2d\x58\xd8\xb3\xbc\xdf\x1a\xba\xde\xc7\x82\x90\x54\xf9\x0d\xda\x98\x05\xaa\xb5\x6c\x77\x33\x30\x24\xb9\xd0\xa5\x08\xb7\x5c",
+        sig: b"\x58\x31\xaa\xee\xd7\xb4\x4b\xb7\x4e\x5e\xab\x94\xba\x9d\x42\x94\xc4\x9b\xcf\x2a\x60\x72\x8d\x8b\x4c\x20\x0f\x50\xdd\x31\x3c\x1b\xab\x74\x58\x79\xa5\xad\x95\x4a\x72\xc4\x5a\x91\xc3\xa5\x1d\x3c\x7a\xde\xa9\x8d\x82\xf8\x48\x1e\x0e\x1e\x03\x67\x4a\x6f\x3f\xb7",
+        pass: true
+    },
+    // test fails if msg is reduced modulo p or n
+    TestVector {
+        alg: TestVectorAlgorithm::Bip340,
+        sk: b"\x0b\x43\x2b\x26\x77\x93\x73\x81\xae\xf0\x5b\xb0\x2a\x66\xec\xd0\x12\x77\x30\x62\xcf\x3f\xa2\x54\x9e\x44\xf5\x8e\xd2\x40\x17\x10",
+        pk: b"\x25\xd1\xdf\xf9\x51\x05\xf5\x25\x3c\x40\x22\xf6\x28\xa9\x96\xad\x3a\x0d\x95\xfb\xf2\x1d\x46\x8a\x1b\x33\xf8\xc1\x60\xd8\xf5\x17",
+        nonce: Some(b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff"),
+        msg: b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff",
+        sig: b"\x7e\xb0\x50\x97\x57\xe2\x46\xf1\x94\x49\x88\x56\x51\x61\x1c\xb9\x65\xec\xc1\xa1\x87\xdd\x51\xb6\x4f\xda\x1e\xdc\x96\x37\xd5\xec\x97\x58\x2b\x9c\xb1\x3d\xb3\x93\x37\x05\xb3\x2b\xa9\x82\xaf\x5a\xf2\x5f\xd7\x88\x81\xeb\xb3\x27\x71\xfc\x59\x22\xef\xc6\x6e\xa3",
+        pass: true
+    },
+    TestVector {
+        alg: TestVectorAlgorithm::Bip340,
+        sk: b"",
+        pk: b"\xd6\x9c\x35\x09\xbb\x99\xe4\x12\xe6\x8b\x0f\xe8\x54\x4e\x72\x83\x7d\xfa\x30\x74\x6d\x8b\xe2\xaa\x65\x97\x5f\x29\xd2\x2d\xc7\xb9",
+        nonce: None,
+        msg: b"\x4d\xf3\xc3\xf6\x8f\xcc\x83\xb2\x7e\x9d\x42\xc9\x04\x31\xa7\x24\x99\xf1\x78\x75\xc8\x1a\x59\x9b\x56\x6c\x98\x89\xb9\x69\x67\x03",
+        sig: b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x3b\x78\xce\x56\x3f\x89\xa0\xed\x94\x14\xf5\xaa\x28\xad\x0d\x96\xd6\x79\x5f\x9c\x63\x76\xaf\xb1\x54\x8a\xf6\x03\xb3\xeb\x45\xc9\xf8\x20\x7d\xee\x10\x60\xcb\x71\xc0\x4e\x80\xf5\x93\x06\x0b\x07\xd2\x83\x08\xd7\xf4",
+        pass: true
+    },
+    // public key not on the curve
+    TestVector {
+        alg: TestVectorAlgorithm::Bip340,
+        sk: b"",
+        pk: b"\xee\xfd\xea\x4c\xdb\x67\x77\x50\xa4\x20\xfe\xe8\x07\xea\xcf\x21\xeb\x98\x98\xae\x79\xb9\x76\x87\x66\xe4\xfa\xa0\x4a\x2d\x4a\x34",
+        nonce: None,
+        msg: b"\x24\x3f\x6a\x88\x85\xa3\x08\xd3\x13\x19\x8a\x2e\x03\x70\x73\x44\xa4\x09\x38\x22\x29\x9f\x31\xd0\x08\x2e\xfa\x98\xec\x4e\x6c\x89",
+        sig: b"\x6c\xff\x5c\x3b\xa8\x6c\x69\xea\x4b\x73\x76\xf3\x1a\x9b\xcb\x4f\x74\xc1\x97\x60\x89\xb2\xd9\x96\x3d\xa2\xe5\x54\x3e\x17\x77\x69\x69\xe8\x9b\x4c\x55\x64\xd0\x03\x49\x10\x6b\x84\x97\x78\x5d\xd7\xd1\xd7\x13\xa8\xae\x82\xb3\x2f\xa7\x9d\x5f\x7f\xc4\x07\xd3\x9b",
+        pass: false
+    },
+    // has_even_y(R) is false
+    TestVector {
+        alg: TestVectorAlgorithm::Bip340,
+        sk: b"",
+        pk: b"\xdf\xf1\xd7\x7f\x2a\x67\x1c\x5f\x36\x18\x37\x26\xdb\x23\x41\xbe\x58\xfe\xae\x1d\xa2\xde\xce\xd8\x43\x24\x0f\x7b\x50\x2b\xa6\x59",
+        nonce: None,
+        msg: b"\x24\x3f\x6a\x88\x85\xa3\x08\xd3\x13\x19\x8a\x2e\x03\x70\x73\x44\xa4\x09\x38\x22\x29\x9f\x31\xd0\x08\x2e\xfa\x98\xec\x4e\x6c\x89",
+        sig: b"\xff\xf9\x7b\xd5\x75\x5e\xee\xa4\x20\x45\x3a\x14\x35\x52\x35\xd3\x82\xf6\x47\x2f\x85\x68\xa1\x8b\x2f\x05\x7a\x14\x60\x29\x75\x56\x3c\xc2\x79\x44\x64\x0a\xc6\x07\xcd\x10\x7a\xe1\x09\x23\xd9\xef\x7a\x73\xc6\x43\xe1\x66\xbe\x5e\xbe\xaf\xa3\x4b\x1a\xc5\x53\xe2",
+        pass: false
+    },
+    // negated message
+    TestVector {
+        alg: TestVectorAlgorithm::Bip340,
+        sk: b"",
+        pk: b"\xdf\xf1\xd7\x7f\x2a\x67\x1c\x5f\x36\x18\x37\x26\xdb\x23\x41\xbe\x58\xfe\xae\x1d\xa2\xde\xce\xd8\x43\x24\x0f\x7b\x50\x2b\xa6\x59",
+        nonce: None,
+        msg: b"\x24\x3f\x6a\x88\x85\xa3\x08\xd3\x13\x19\x8a\x2e\x03\x70\x73\x44\xa4\x09\x38\x22\x29\x9f\x31\xd0\x08\x2e\xfa\x98\xec\x4e\x6c\x89",
+        sig: b"\x1f\xa6\x2e\x33\x1e\xdb\xc2\x1c\x39\x47\x92\xd2\xab\x11\x00\xa7\xb4\x32\xb0\x13\xdf\x3f\x6f\xf4\xf9\x9f\xcb\x33\xe0\xe1\x51\x5f\x28\x89\x0b\x3e\xdb\x6e\x71\x89\xb6\x30\x44\x8b\x51\x5c\xe4\xf8\x62\x2a\x95\x4c\xfe\x54\x57\x35\xaa\xea\x51\x34\xfc\xcd\xb2\xbd",
+        pass: false
+    },
+    // negated s value
+    TestVector {
+        alg: TestVectorAlgorithm::Bip340,
+        sk: b"",
+        pk: b"\xdf\xf1\xd7\x7f\x2a\x67\x1c\x5f\x36\x18\x37\x26\xdb\x23\x41\xbe\x58\xfe\xae\x1d\xa2\xde\xce\xd8\x43\x24\x0f\x7b\x50\x2b\xa6\x59",
+        nonce: None,
+        msg: b"\x24\x3f\x6a\x88\x85\xa3\x08\xd3\x13\x19\x8a\x2e\x03\x70\x73\x44\xa4\x09\x38\x22\x29\x9f\x31\xd0\x08\x2e\xfa\x98\xec\x4e\x6c\x89",
+        sig: b"\x6c\xff\x5c\x3b\xa8\x6c\x69\xea\x4b\x73\x76\xf3\x1a\x9b\xcb\x4f\x74\xc1\x97\x60\x89\xb2\xd9\x96\x3d\xa2\xe5\x54\x3e\x17\x77\x69\x96\x17\x64\xb3\xaa\x9b\x2f\xfc\xb6\xef\x94\x7b\x68\x87\xa2\x26\xe8\xd7\xc9\x3e\x00\xc5\xed\x0c\x18\x34\xff\x0d\x0c\x2e\x6d\xa6",
+        pass: false
+    },
+    // sG - eP is infinite. Test fails in single verification if has_even_y(inf) is defined as true and x(inf) as 0
+    TestVector {
+        alg: TestVectorAlgorithm::Bip340,
+        sk: b"",
+        pk: b"\xdf\xf1\xd7\x7f\x2a\x67\x1c\x5f\x36\x18\x37\x26\xdb\x23\x41\xbe\x58\xfe\xae\x1d\xa2\xde\xce\xd8\x43\x24\x0f\x7b\x50\x2b\xa6\x59",
+        nonce: None,
+        msg: b"\x24\x3f\x6a\x88\x85\xa3\x08\xd3\x13\x19\x8a\x2e\x03\x70\x73\x44\xa4\x09\x38\x22\x29\x9f\x31\xd0\x08\x2e\xfa\x98\xec\x4e\x6c\x89",
+        sig: b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x12\x3d\xda\x83\x28\xaf\x9c\x23\xa9\x4c\x1f\xee\xcf\xd1\x23\xba\x4f\xb7\x34\x76\xf0\xd5\x94\xdc\xb6\x5c\x64\x25\xbd\x18\x60\x51",
+        pass: false
+    },
+    // sG - eP is infinite. Test fails in single verification if has_even_y(inf) is defined as true and x(inf) as 1
+    TestVector {
+        alg: TestVectorAlgorithm::Bip340,
+        sk: b"",
+        pk: b"\xdf\xf1\xd7\x7f\x2a\x67\x1c\x5f\x36\x18\x37\x26\xdb\x23\x41\xbe\x58\xfe\xae\x1d\xa2\xde\xce\xd8\x43\x24\x0f\x7b\x50\x2b\xa6\x59",
+        nonce: None,
+        msg: b"\x24\x3f\x6a\x88\x85\xa3\x08\xd3\x13\x19\x8a\x2e\x03\x70\x73\x44\xa4\x09\x38\x22\x29\x9f\x31\xd0\x08\x2e\xfa\x98\xec\x4e\x6c\x89",
+        sig: b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x01\x76\x15\xfb\xaf\x5a\xe2\x88\x64\x01\x3c\x09\x97\x42\xde\xad\xb4\xdb\xa8\x7f\x11\xac\x67\x54\xf9\x37\x80\xd5\xa1\x83\x7c\xf1\x97",
+        pass: false
+    },
+    // sig[0:32] is not an X coordinate on the curve
+    TestVector {
+        alg: TestVectorAlgorithm::Bip340,
+        sk: b"",
+        pk: b"\xdf\xf1\xd7\x7f\x2a\x67\x1c\x5f\x36\x18\x37\x26\xdb\x23\x41\xbe\x58\xfe\xae\x1d\xa2\xde\xce\xd8\x43\x24\x0f\x7b\x50\x2b\xa6\x59",
+        nonce: None,
+        msg: b"\x24\x3f\x6a\x88\x85\xa3\x08\xd3\x13\x19\x8a\x2e\x03\x70\x73\x44\xa4\x09\x38\x22\x29\x9f\x31\xd0\x08\x2e\xfa\x98\xec\x4e\x6c\x89",
+        sig: b"\x4a\x29\x8d\xac\xae\x57\x39\x5a\x15\xd0\x79\x5d\xdb\xfd\x1d\xcb\x56\x4d\xa8\x2b\x0f\x26\x9b\xc7\x0a\x74\xf8\x22\x04\x29\xba\x1d\x69\xe8\x9b\x4c\x55\x64\xd0\x03\x49\x10\x6b\x84\x97\x78\x5d\xd7\xd1\xd7\x13\xa8\xae\x82\xb3\x2f\xa7\x9d\x5f\x7f\xc4\x07\xd3\x9b",
+        pass: false
+    },
+    // sig[0:32] is equal to field size
+    TestVector {
+        alg: TestVectorAlgorithm::Bip340,
+        sk: b"",
+        pk: b"\xdf\xf1\xd7\x7f\x2a\x67\x1c\x5f\x36\x18\x37\x26\xdb\x23\x41\xbe\x58\xfe\xae\x1d\xa2\xde\xce\xd8\x43\x24\x0f\x7b\x50\x2b\xa6\x59",
+        nonce: None,
+        msg: b"\x24\x3f\x6a\x88\x85\xa3\x08\xd3\x13\x19\x8a\x2e\x03\x70\x73\x44\xa4\x09\x38\x22\x29\x9f\x31\xd0\x08\x2e\xfa\x98\xec\x4e\x6c\x89",
+        sig: b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xfe\xff\xff\xfc\x2f\x69\xe8\x9b\x4c\x55\x64\xd0\x03\x49\x10\x6b\x84\x97\x78\x5d\xd7\xd1\xd7\x13\xa8\xae\x82\xb3\x2f\xa7\x9d\x5f\x7f\xc4\x07\xd3\x9b",
+        pass: false
+    },
+    // sig[32:64] is equal to curve order
+    TestVector {
+        alg: TestVectorAlgorithm::Bip340,
+        sk: b"",
+        pk: b"\xdf\xf1\xd7\x7f\x2a\x67\x1c\x5f\x36\x18\x37\x26\xdb\x23\x41\xbe\x58\xfe\xae\x1d\xa2\xde\xce\xd8\x43\x24\x0f\x7b\x50\x2b\xa6\x59",
+        nonce: None,
+        msg: b"\x24\x3f\x6a\x88\x85\xa3\x08\xd3\x13\x19\x8a\x2e\x03\x70\x73\x44\xa4\x09\x38\x22\x29\x9f\x31\xd0\x08\x2e\xfa\x98\xec\x4e\x6c\x89",
+        sig: b"\x6c\xff\x5c\x3b\xa8\x6c\x69\xea\x4b\x73\x76\xf3\x1a\x9b\xcb\x4f\x74\xc1\x97\x60\x89\xb2\xd9\x96\x3d\xa2\xe5\x54\x3e\x17\x77\x69\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xfe\xba\xae\xdc\xe6\xaf\x48\xa0\x3b\xbf\xd2\x5e\x8c\xd0\x36\x41\x41",
+        pass: false
+    },
+    // public key is not a valid X coordinate because it exceeds the field size
+    TestVector {
+        alg: TestVectorAlgorithm::Bip340,
+        sk: b"",
+        pk: b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xfe\xff\xff\xfc\x30",
+        nonce: None,
+        msg: b"\x24\x3f\x6a\x88\x85\xa3\x08\xd3\x13\x19\x8a\x2e\x03\x70\x73\x44\xa4\x09\x38\x22\x29\x9f\x31\xd0\x08\x2e\xfa\x98\xec\x4e\x6c\x89",
+        sig: b"\x6c\xff\x5c\x3b\xa8\x6c\x69\xea\x4b\x73\x76\xf3\x1a\x9b\xcb\x4f\x74\xc1\x97\x60\x89\xb2\xd9\x96\x3d\xa2\xe5\x54\x3e\x17\x77\x69\x69\xe8\x9b\x4c\x55\x64\xd0\x03\x49\x10\x6b\x84\x97\x78\x5d\xd7\xd1\xd7\x13\xa8\xae\x82\xb3\x2f\xa7\x9d\x5f\x7f\xc4\x07\xd3\x9b",
+        pass: false
+    },
+    // message of size 0 (added 2022-12)
+    TestVector {
+        alg: TestVectorAlgorithm::Bip340,
+        sk: b"\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40",
+        pk: b"\x77\x8c\xaa\x53\xb4\x39\x3a\xc4\x67\x77\x4d\x09\x49\x7a\x87\x22\x4b\xf9\xfa\xb6\xf6\xe6\x8b\x23\x08\x64\x97\x32\x4d\x6f\xd1\x17",
+        nonce: Some(b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00"),
+        msg: b"",
+        sig: b"\x71\x53\x5d\xb1\x65\xec\xd9\xfb\xbc\x04\x6e\x5f\xfa\xea\x61\x18\x6b\xb6\xad\x43\x67\x32\xfc\xcc\x25\x29\x1a\x55\x89\x54\x64\xcf\x60\x69\xce\x26\xbf\x03\x46\x62\x28\xf1\x9a\x3a\x62\xdb\x8a\x64\x9f\x2d\x56\x0f\xac\x65\x28\x27\xd1\xaf\x05\x74\xe4\x27\xab\x63",
+        pass: true
+    },
+    // message of size 1 (added 2022-12)
+    TestVector {
+        alg: TestVectorAlgorithm::Bip340,
+        sk: b"\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40",
+        pk: b"\x77\x8c\xaa\x53\xb4\x39\x3a\xc4\x67\x77\x4d\x09\x49\x7a\x87\x22\x4b\xf9\xfa\xb6\xf6\xe6\x8b\x23\x08\x64\x97\x32\x4d\x6f\xd1\x17",
+        nonce: Some(b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00"),
+        msg: b"\x11",
+        sig: b"\x08\xa2\x0a\x0a\xfe\xf6\x41\x24\x64\x92\x32\xe0\x69\x3c\x58\x3a\xb1\xb9\x93\x4a\xe6\x3b\x4c\x35\x11\xf3\xae\x11\x34\xc6\xa3\x03\xea\x31\x73\xbf\xea\x66\x83\xbd\x10\x1f\xa5\xaa\x5d\xbc\x19\x96\xfe\x7c\xac\xfc\x5a\x57\x7d\x33\xec\x14\x56\x4c\xec\x2b\xac\xbf",
+        pass: true
+    },
+    // message of size 17 (added 2022-12)
+    TestVector {
+        alg: TestVectorAlgorithm::Bip340,
+        sk: b"\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40",
+        pk: b"\x77\x8c\xaa\x53\xb4\x39\x3a\xc4\x67\x77\x4d\x09\x49\x7a\x87\x22\x4b\xf9\xfa\xb6\xf6\xe6\x8b\x23\x08\x64\x97\x32\x4d\x6f\xd1\x17",
+        nonce: Some(b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00"),
+        msg: b"\x01\x02\x03\x04\x05\x06\x07\x08\x09\x0a\x0b\x0c\x0d\x0e\x0f\x10\x11",
+        sig: b"\x51\x30\xf3\x9a\x40\x59\xb4\x3b\xc7\xca\xc0\x9a\x19\xec\xe5\x2b\x5d\x86\x99\xd1\xa7\x1e\x3c\x52\xda\x9a\xfd\xb6\xb5\x0a\xc3\x70\xc4\xa4\x82\xb7\x7b\xf9\x60\xf8\x68\x15\x40\xe2\x5b\x67\x71\xec\xe1\xe5\xa3\x7f\xd8\x0e\x5a\x51\x89\x7c\x55\x66\xa9\x7e\xa5\xa5",
+        pass: true
+    },
+    // message of size 100 (added 2022-12)
+    TestVector {
+        alg: TestVectorAlgorithm::Bip340,
+        sk: b"\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40\x03\x40",
+        pk: b"\x77\x8c\xaa\x53\xb4\x39\x3a\xc4\x67\x77\x4d\x09\x49\x7a\x87\x22\x4b\xf9\xfa\xb6\xf6\xe6\x8b\x23\x08\x64\x97\x32\x4d\x6f\xd1\x17",
+        nonce: Some(b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00"),
+        msg: b"\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99\x99",
+        sig: b"\x40\x3b\x12\xb0\xd8\x55\x5a\x34\x41\x75\xea\x7e\xc7\x46\x56\x63\x03\x32\x1e\x5d\xbf\xa8\xbe\x6f\x09\x16\x35\x16\x3e\xca\x79\xa8\x58\x5e\xd3\xe3\x17\x08\x07\xe7\xc0\x3b\x72\x0f\xc5\x4c\x7b\x23\x89\x7f\xcb\xa0\xe9\xd0\xb4\xa0\x68\x94\xcf\xd2\x49\xf2\x23\x67",
+        pass: true
+    },
+];
@@ -0,0 +1,209 @@
+//! Tests for ECDH and X25519 key agreement
+//!
+//! Shared secrets were computed independently with pyca/cryptography
+
+use signatory::ecdh::DiffieHellman;
+
+/// Decode a hex string
+fn hex(string: &str) -> Vec<u8> {
+    (0..string.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&string[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+/// Compressed point with the given tag and `x`-coordinate
+fn compressed_point(tag: u8, x: u8, size: usize) -> Vec<u8> {
+    let mut bytes = vec![0u8; size + 1];
+    bytes[0] = tag;
+    bytes[size] = x;
+    bytes
+}
+
+mod nistp256 {
+    use super::*;
+    use signatory::{
+        ecdsa::{
+            nistp256::{PublicKey, SecretKey},
+            PointConversion,
+        },
+        public_key::PublicKeyed,
+    };
+
+    const ALICE_SK: &str = "5cda8d1e4f9d3c705ac094c311e31b5b65a71b58d01967ce5e2f53a2ad2f9cea";
+    const BOB_SK: &str = "b9ba3a602f9d6fb97d715df2816d3e0402ebf4e1687cb1bd45c9692344cac664";
+    const BOB_PK: &str = "03336ab55934710867f3852d060c0c942522a51361620f34f5d476988e990211ef";
+    const SHARED: &str = "d92ae029bb5c078bf0dde36f2eccdf8e846b00b6676677b9b370cb5aba807f49";
+
+    #[test]
+    fn shared_secret() {
+        let alice = SecretKey::from_bytes(hex(ALICE_SK)).unwrap();
+        let bob = SecretKey::from_bytes(hex(BOB_SK)).unwrap();
+
+        let bob_pk = PublicKey::from_bytes(hex(BOB_PK)).unwrap();
+        assert_eq!(bob.public_key().unwrap().to_compressed().unwrap(), bob_pk);
+
+        let shared = alice.diffie_hellman(&bob_pk).unwrap();
+        assert_eq!(&shared[..], &hex(SHARED)[..]);
+
+        let alice_pk = alice.public_key().unwrap();
+        assert_eq!(&bob.diffie_hellman(&alice_pk).unwrap()[..], &shared[..]);
+    }
+
+    #[test]
+    fn rejects_invalid_keys() {
+        let bob_pk = PublicKey::from_bytes(hex(BOB_PK)).unwrap();
+        let zero = SecretKey::from_bytes([0u8; 32]).unwrap();
+        assert!(zero.diffie_hellman(&bob_pk).is_err());
+
+        let alice = SecretKey::from_bytes(hex(ALICE_SK)).unwrap();
+        let off_curve = PublicKey::from_bytes(compressed_point(0x02, 1, 32)).unwrap();
+        assert!(alice.diffie_hellman(&off_curve).is_err());
+    }
+}
+
+mod nistp384 {
+    use super::*;
+    use signatory::ecdsa::{
+        nistp384::{NistP384, PublicKey, SecretKey},
+        CurveOrder,
+    };
+
+    const ALICE_SK: &str = "6e3af3f7c4fbcc6e6aa7a8698c31f9d7324b96f6571ab7bf2dc824ade376b88bb8865cad3058e9d8f7d4bbc0133af6da";
+    const ALICE_PK: &str = "024dc3c59fe3f7ba4366ef251cdadd98fcf8f3e0857a2aee7387ddc3a777ba907e1a7ae0b90e9780e777dbbb418325dc2d";
+    const BOB_SK: &str = "486778d9d9b5ff5ac664ceda6faa3a50c919d924ea5e11af4ad35bf457219b474b7a3af0ba80c148e36ac70e2e3e3580";
+    const BOB_PK: &str = "03a4adf8f5257022cd210aaa961be4b6d5f1dceba145a91d9f0098aefbda7f4235fdc3798475a4d185320658b4da15f6e8";
+    const SHARED: &str = "1a045a30e7f2a90a92a7fd3617d28cf7779dd1d096ec0157263597be0338e046b58d8fa8a20059bc76a5ad0149967d39";
+
+    #[test]
+    fn shared_secret() {
+        let alice = SecretKey::from_bytes(hex(ALICE_SK)).unwrap();
+        let bob = SecretKey::from_bytes(hex(BOB_SK)).unwrap();
+        let alice_pk = PublicKey::from_bytes(hex(ALICE_PK)).unwrap();
+        let bob_pk = PublicKey::from_bytes(hex(BOB_PK)).unwrap();
+
+        let shared = alice.diffie_hellman(&bob_pk).unwrap();
+        assert_eq!(&shared[..], &hex(SHARED)[..]);
+        assert_eq!(&bob.diffie_hellman(&alice_pk).unwrap()[..], &shared[..]);
+    }
+
+    #[test]
+    fn rejects_invalid_keys() {
+        let bob_pk = PublicKey::from_bytes(hex(BOB_PK)).unwrap();
+
+        for secret_key in &[&[0u8; 48][..], NistP384::ORDER, &[0xffu8; 48][..]] {
+            let secret_key = SecretKey::from_bytes(secret_key).unwrap();
+            assert!(secret_key.diffie_hellman(&bob_pk).is_err());
+        }
+
+        let alice = SecretKey::from_bytes(hex(ALICE_SK)).unwrap();
+        let off_curve = PublicKey::from_bytes(compressed_point(0x03, 1, 48)).unwrap();
+        assert!(alice.diffie_hellman(&off_curve).is_err());
+    }
+}
+
+mod secp256k1 {
+    use super::*;
+    use signatory::{
+        ecdsa::{
+            secp256k1::{PublicKey, SecretKey},
+            PointConversion,
+        },
+        public_key::PublicKeyed,
+    };
+
+    const ALICE_SK: &str = "7f8a997318471625da838f94c4c1f283e97514734ee363bdb158b8c391351fdc";
+    const BOB_SK: &str = "096fbb54e8201f41074265bda0249de46ca185ecd21624d17dc11975349f676b";
+    const BOB_PK: &str = "025d3336d4660251479b21e0bd86d4627ddecc2c92365822c682ec629696cd876c";
+    const SHARED: &str = "264a2beac23d3c5916cfaab957a229284257687de3a93f371dd4f910404d76d0";
+
+    #[test]
+    fn shared_secret() {
+        let alice = SecretKey::from_bytes(hex(ALICE_SK)).unwrap();
+        let bob = SecretKey::from_bytes(hex(BOB_SK)).unwrap();
+
+        let bob_pk = PublicKey::from_bytes(hex(BOB_PK)).unwrap();
+        assert_eq!(bob.public_key().unwrap().to_compressed().unwrap(), bob_pk);
+
+        let shared = alice.diffie_hellman(&bob_pk).unwrap();
+        assert_eq!(&shared[..], &hex(SHARED)[..]);
+
+        let alice_pk = alice.public_key().unwrap();
+        assert_eq!(&bob.diffie_hellman(&alice_pk).unwrap()[..], &shared[..]);
+    }
+
+    #[test]
+    fn rejects_invalid_keys() {
+        let bob_pk = PublicKey::from_bytes(hex(BOB_PK)).unwrap();
+        let zero = SecretKey::from_bytes([0u8; 32]).unwrap();
+        assert!(zero.diffie_hellman(&bob_pk).is_err());
+
+        let alice = SecretKey::from_bytes(hex(ALICE_SK)).unwrap();
+        let off_curve = PublicKey::from_bytes(compressed_point(0x02, 0, 32)).unwrap();
+        assert!(alice.diffie_hellman(&off_curve).is_err());
+    }
+}
+
+mod x25519 {
+    use super::*;
+    use signatory::{
+        ed25519::{PublicKey, Seed},
+        public_key::PublicKeyed,
+    };
+
+    const ALICE_SEED: &str = "3c95565677112641314d6804be68bf17ace86c2fc6d5e4d0c8f850c1a3dfc87f";
+    const ALICE_PK: &str = "dde48d2cd27b8801c83460668d0d6787d23608b3d132388b6d35eea6f4ea8948";
+    const ALICE_X25519_SK: &str =
+        "98030541068add2a25017514790e2c7fb1366b6f0fde48a325ecb9372f346678";
+    const ALICE_X25519_PK: &str =
+        "13efeb0d954f536f802ac779de8090573d76f595439f66c6cb45eeda78fc7b20";
+    const BOB_SEED: &str = "d66cea65b2e3fc2c759f8fdb6bfaf942f49300ce8d6ff7428d505e9b89783582";
+    const BOB_PK: &str = "85e7ee6ae8cbce9052c3828c84cc5a79aeaaf3a5466711e36c3a7926f233438b";
+    const SHARED: &str = "1533be95e8a8ce3540b5cd5e31278e755ccefdd7f01ab4af6e0bdb47809a0303";
+
+    #[test]
+    fn key_conversion() {
+        let alice = Seed::from_bytes(hex(ALICE_SEED)).unwrap();
+        let alice_pk = alice.public_key().unwrap();
+        assert_eq!(alice_pk, PublicKey::from_bytes(hex(ALICE_PK)).unwrap());
+
+        assert_eq!(&alice.to_x25519_secret_key()[..], &hex(ALICE_X25519_SK)[..]);
+        assert_eq!(
+            &alice_pk.to_x25519_public_key().unwrap()[..],
+            &hex(ALICE_X25519_PK)[..]
+        );
+    }
+
+    #[test]
+    fn shared_secret() {
+        let alice = Seed::from_bytes(hex(ALICE_SEED)).unwrap();
+        let bob = Seed::from_bytes(hex(BOB_SEED)).unwrap();
+        let alice_pk = alice.public_key().unwrap();
+        let bob_pk = bob.public_key().unwrap();
+        assert_eq!(bob_pk, PublicKey::from_bytes(hex(BOB_PK)).unwrap());
+
+        let shared = alice.diffie_hellman(&bob_pk).unwrap();
+        assert_eq!(&shared[..], &hex(SHARED)[..]);
+        assert_eq!(&bob.diffie_hellman(&alice_pk).unwrap()[..], &shared[..]);
+    }
+
+    #[test]
+    fn rejects_invalid_keys() {
+        let alice = Seed::from_bytes(hex(ALICE_SEED)).unwrap();
+
+        // Identity (small order)
+        let mut identity = [0u8; 32];
+        identity[0] = 1;
+
+        // Non-canonical encoding of the identity (`y = p + 1`)
+        let mut non_canonical = [0xffu8; 32];
+        non_canonical[0] = 0xee;
+        non_canonical[31] = 0x7f;
+
+        for bytes in &[identity, non_canonical] {
+            let public_key = PublicKey::from_bytes(&bytes[..]).unwrap();
+            assert!(public_key.to_x25519_public_key().is_err());
+            assert!(alice.diffie_hellman(&public_key).is_err());
+        }
+    }
+}
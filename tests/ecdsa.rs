@@ -0,0 +1,655 @@
+//! Tests for the built-in pure-Rust ECDSA provider
+
+use signatory::{
+    public_key::PublicKeyed,
+    signature::{Signature as _, Signer as _, Verifier as _},
+};
+
+/// Add two big endian integers of the same size (e.g. to produce a
+/// non-canonical encoding of a field element or scalar)
+#[cfg(any(feature = "p384", feature = "p521"))]
+fn add_be(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut sum = vec![0u8; a.len()];
+    let mut carry = 0u16;
+
+    for i in (0..a.len()).rev() {
+        let n = u16::from(a[i]) + u16::from(b[i]) + carry;
+        sum[i] = n as u8;
+        carry = n >> 8;
+    }
+
+    assert_eq!(carry, 0, "sum doesn't fit in {} bytes", a.len());
+    sum
+}
+
+mod nistp256 {
+    use super::*;
+    use signatory::ecdsa::{
+        generic_array::GenericArray,
+        nistp256::{
+            test_vectors::{SHA256_FIXED_SIZE_TEST_VECTORS, SHA256_RFC6979_TEST_VECTORS},
+            Asn1Signature, FixedSignature, PublicKey, SecretKey, Signer, Verifier,
+        },
+    };
+
+    #[test]
+    fn rfc6979_vectors() {
+        for vector in SHA256_RFC6979_TEST_VECTORS {
+            let signer = Signer::new(&SecretKey::from_bytes(vector.sk).unwrap()).unwrap();
+            let public_key = PublicKey::from_untagged_point(GenericArray::from_slice(vector.pk));
+            assert_eq!(signer.public_key().unwrap(), public_key);
+
+            let signature: FixedSignature = signer.sign(vector.msg);
+            assert_eq!(signature.as_ref(), vector.sig);
+
+            let verifier = Verifier::from(&public_key);
+            assert!(verifier.verify(vector.msg, &signature).is_ok());
+        }
+    }
+
+    #[test]
+    fn fixed_signature_vectors() {
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let signer = Signer::new(&SecretKey::from_bytes(vector.sk).unwrap()).unwrap();
+            let public_key = PublicKey::from_untagged_point(GenericArray::from_slice(vector.pk));
+            assert_eq!(signer.public_key().unwrap(), public_key);
+
+            // Signatures are deterministic
+            let signature: FixedSignature = signer.sign(vector.msg);
+            assert_eq!(signature, signer.sign(vector.msg));
+
+            let verifier = Verifier::from(&public_key);
+            assert!(verifier.verify(vector.msg, &signature).is_ok());
+
+            // Make sure the vector signature verifies
+            let vector_signature = FixedSignature::from_bytes(vector.sig).unwrap();
+            assert!(verifier.verify(vector.msg, &vector_signature).is_ok());
+            assert!(verifier
+                .verify(b"wrong message", &vector_signature)
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn asn1_signature_roundtrip() {
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+        let signer = Signer::new(&SecretKey::from_bytes(vector.sk).unwrap()).unwrap();
+        let signature: Asn1Signature = signer.sign(vector.msg);
+
+        let fixed_signature: FixedSignature = signer.sign(vector.msg);
+        assert_eq!(signature.as_ref(), fixed_signature.to_asn1().as_ref());
+
+        let verifier = Verifier::from(&signer.public_key().unwrap());
+        assert!(verifier.verify(vector.msg, &signature).is_ok());
+    }
+
+    #[test]
+    fn rejects_tweaked_fixed_signature() {
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+        let signer = Signer::new(&SecretKey::from_bytes(vector.sk).unwrap()).unwrap();
+        let signature: FixedSignature = signer.sign(vector.msg);
+
+        let mut tweaked_signature = signature.as_ref().to_vec();
+        *tweaked_signature.iter_mut().last().unwrap() ^= 42;
+
+        let verifier = Verifier::from(&signer.public_key().unwrap());
+        let result = verifier.verify(
+            vector.msg,
+            &FixedSignature::from_bytes(tweaked_signature.as_ref()).unwrap(),
+        );
+
+        assert!(
+            result.is_err(),
+            "expected bad signature to cause validation error!"
+        );
+    }
+
+    #[test]
+    fn secret_key_public_key() {
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let secret_key = SecretKey::from_bytes(vector.sk).unwrap();
+            let public_key = PublicKey::from_untagged_point(GenericArray::from_slice(vector.pk));
+            assert_eq!(secret_key.public_key().unwrap(), public_key);
+        }
+
+        assert!(SecretKey::from_bytes([0u8; 32])
+            .unwrap()
+            .public_key()
+            .is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_secret_keys() {
+        let order = b"\xff\xff\xff\xff\x00\x00\x00\x00\xff\xff\xff\xff\xff\xff\xff\xff\
+                      \xbc\xe6\xfa\xad\xa7\x17\x9e\x84\xf3\xb9\xca\xc2\xfc\x63\x25\x51";
+
+        assert!(Signer::new(&SecretKey::from_bytes([0u8; 32]).unwrap()).is_err());
+        assert!(Signer::new(&SecretKey::from_bytes(order).unwrap()).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn batch_verify_fixed_signature_vectors() {
+        use signatory::batch::{BatchItem, BatchVerifier};
+
+        let keys_and_sigs = SHA256_FIXED_SIZE_TEST_VECTORS
+            .iter()
+            .map(|vector| {
+                (
+                    PublicKey::from_untagged_point(GenericArray::from_slice(vector.pk)),
+                    FixedSignature::from_bytes(vector.sig).unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let mut items = SHA256_FIXED_SIZE_TEST_VECTORS
+            .iter()
+            .zip(&keys_and_sigs)
+            .map(|(vector, (pk, sig))| BatchItem::new(pk, vector.msg, sig))
+            .collect::<Vec<_>>();
+
+        assert_eq!(Verifier::verify_batch(&items), Ok(()));
+
+        items[1].msg = b"tampered message";
+        let err = Verifier::verify_batch(&items).unwrap_err();
+        assert_eq!(err.failed(), &[1]);
+    }
+}
+
+#[cfg(feature = "p384")]
+mod nistp384 {
+    use super::*;
+    use signatory::ecdsa::{
+        generic_array::GenericArray,
+        nistp384::{
+            test_vectors::{SHA384_FIXED_SIZE_TEST_VECTORS, SHA384_RFC6979_TEST_VECTORS},
+            Asn1Signature, FixedSignature, PublicKey, SecretKey, Signer, Verifier,
+        },
+    };
+
+    /// NIST P-384 curve order
+    const ORDER: &[u8] = b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\
+        \xff\xff\xff\xff\xff\xff\xff\xff\xc7\x63\x4d\x81\xf4\x37\x2d\xdf\x58\x1a\x0d\xb2\
+        \x48\xb0\xa7\x7a\xec\xec\x19\x6a\xcc\xc5\x29\x73";
+
+    /// NIST P-384 field modulus
+    const MODULUS: &[u8] = b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\
+        \xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xfe\xff\xff\xff\xff\
+        \x00\x00\x00\x00\x00\x00\x00\x00\xff\xff\xff\xff";
+
+    #[test]
+    fn rfc6979_vectors() {
+        for vector in SHA384_RFC6979_TEST_VECTORS {
+            let signer = Signer::new(&SecretKey::from_bytes(vector.sk).unwrap()).unwrap();
+            let public_key = PublicKey::from_untagged_point(GenericArray::from_slice(vector.pk));
+            assert_eq!(signer.public_key().unwrap(), public_key);
+
+            let signature: FixedSignature = signer.sign(vector.msg);
+            assert_eq!(signature.as_ref(), vector.sig);
+
+            let verifier = Verifier::from(&public_key);
+            assert!(verifier.verify(vector.msg, &signature).is_ok());
+            assert!(verifier.verify(b"wrong message", &signature).is_err());
+        }
+    }
+
+    #[test]
+    fn fixed_signature_vectors() {
+        for vector in SHA384_FIXED_SIZE_TEST_VECTORS {
+            let signer = Signer::new(&SecretKey::from_bytes(vector.sk).unwrap()).unwrap();
+            let public_key = PublicKey::from_untagged_point(GenericArray::from_slice(vector.pk));
+            assert_eq!(signer.public_key().unwrap(), public_key);
+
+            // Signatures are deterministic
+            let signature: FixedSignature = signer.sign(vector.msg);
+            assert_eq!(signature, signer.sign(vector.msg));
+
+            let verifier = Verifier::from(&public_key);
+            assert!(verifier.verify(vector.msg, &signature).is_ok());
+
+            // Make sure the vector signature verifies
+            let vector_signature = FixedSignature::from_bytes(vector.sig).unwrap();
+            assert!(verifier.verify(vector.msg, &vector_signature).is_ok());
+            assert!(verifier
+                .verify(b"wrong message", &vector_signature)
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn asn1_signature_roundtrip() {
+        let vector = &SHA384_RFC6979_TEST_VECTORS[0];
+        let signer = Signer::new(&SecretKey::from_bytes(vector.sk).unwrap()).unwrap();
+        let signature: Asn1Signature = signer.sign(vector.msg);
+        assert_eq!(
+            FixedSignature::from_asn1(signature.as_ref())
+                .unwrap()
+                .as_ref(),
+            vector.sig
+        );
+
+        let verifier = Verifier::from(&signer.public_key().unwrap());
+        assert!(verifier.verify(vector.msg, &signature).is_ok());
+    }
+
+    #[test]
+    fn rejects_tweaked_fixed_signature() {
+        let vector = &SHA384_RFC6979_TEST_VECTORS[0];
+        let verifier = Verifier::from(&PublicKey::from_untagged_point(GenericArray::from_slice(
+            vector.pk,
+        )));
+
+        let mut tweaked_signature = vector.sig.to_vec();
+        *tweaked_signature.iter_mut().last().unwrap() ^= 42;
+
+        let result = verifier.verify(
+            vector.msg,
+            &FixedSignature::from_bytes(tweaked_signature.as_ref()).unwrap(),
+        );
+
+        assert!(
+            result.is_err(),
+            "expected bad signature to cause validation error!"
+        );
+    }
+
+    #[test]
+    fn rejects_out_of_range_signature_scalars() {
+        let vector = &SHA384_RFC6979_TEST_VECTORS[0];
+        let verifier = Verifier::from(&PublicKey::from_untagged_point(GenericArray::from_slice(
+            vector.pk,
+        )));
+        let (r, s) = vector.sig.split_at(48);
+
+        // Unlike P-521, `r + n` and `s + n` don't fit in 48 bytes
+        for (r, s) in &[
+            (&[0u8; 48][..], s),
+            (r, &[0u8; 48][..]),
+            (ORDER, s),
+            (r, ORDER),
+            (&[0xff; 48][..], s),
+            (r, &[0xff; 48][..]),
+        ] {
+            let signature = FixedSignature::from_bytes(&[*r, *s].concat()).unwrap();
+            assert!(verifier.verify(vector.msg, &signature).is_err());
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_public_key_points() {
+        use signatory::ecdsa::PointConversion;
+
+        let vector = &SHA384_RFC6979_TEST_VECTORS[0];
+        let signature = FixedSignature::from_bytes(vector.sig).unwrap();
+        let (x, y) = vector.pk.split_at(48);
+
+        let mut off_curve_y = y.to_vec();
+        off_curve_y[47] ^= 1;
+
+        for (x, y) in &[
+            (x, &off_curve_y[..]),
+            (MODULUS, y),
+            (x, MODULUS),
+            (&[0xff; 48][..], y),
+            (x, &[0xff; 48][..]),
+        ] {
+            let point = [*x, *y].concat();
+            let public_key = PublicKey::from_untagged_point(GenericArray::from_slice(&point));
+            assert!(public_key.to_compressed().is_err());
+            assert!(Verifier::from(&public_key)
+                .verify(vector.msg, &signature)
+                .is_err());
+        }
+
+        // `(2, y)` is on the curve, and `2 + p` (the only other encoding of
+        // `x = 2` which fits in 48 bytes) must be rejected
+        let mut x = [0u8; 48];
+        x[47] = 2;
+        let y = b"\x8c\xde\xad\xbb\xd0\x49\x11\xa3\xc1\x93\x1e\x26\xdf\x3f\xa6\x43\
+            \x9d\xca\x9c\x7e\xb2\x86\xfb\xd4\x6f\xc3\x19\xf0\xe2\xbb\x78\x02\x32\xba\xf5\x78\
+            \x25\xfc\x0c\x19\x12\xad\xa2\xfe\xfe\x84\x02\x4c";
+
+        let public_key =
+            PublicKey::from_untagged_point(GenericArray::from_slice(&[&x[..], y].concat()));
+        assert!(public_key.to_compressed().is_ok());
+
+        let x_plus_p = add_be(&x, MODULUS);
+
+        let public_key =
+            PublicKey::from_untagged_point(GenericArray::from_slice(&[&x_plus_p[..], y].concat()));
+        assert!(public_key.to_compressed().is_err());
+
+        let public_key = PublicKey::from_bytes([&[0x02][..], &x_plus_p].concat()).unwrap();
+        assert!(public_key.to_uncompressed().is_err());
+    }
+
+    #[test]
+    fn secret_key_public_key() {
+        for vector in SHA384_RFC6979_TEST_VECTORS
+            .iter()
+            .chain(SHA384_FIXED_SIZE_TEST_VECTORS)
+        {
+            let secret_key = SecretKey::from_bytes(vector.sk).unwrap();
+            let public_key = PublicKey::from_untagged_point(GenericArray::from_slice(vector.pk));
+            assert_eq!(secret_key.public_key().unwrap(), public_key);
+        }
+
+        assert!(SecretKey::from_bytes([0u8; 48])
+            .unwrap()
+            .public_key()
+            .is_err());
+        assert!(SecretKey::from_bytes(ORDER).unwrap().public_key().is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_secret_keys() {
+        assert!(Signer::new(&SecretKey::from_bytes([0u8; 48]).unwrap()).is_err());
+        assert!(Signer::new(&SecretKey::from_bytes(ORDER).unwrap()).is_err());
+    }
+
+    #[cfg(feature = "pkcs8")]
+    #[test]
+    fn pkcs8_vectors() {
+        use signatory::{
+            encoding::FromPkcs8,
+            test_vector::{TestVectorAlgorithm, ToPkcs8},
+        };
+
+        for vector in SHA384_RFC6979_TEST_VECTORS {
+            let pkcs8 = vector.to_pkcs8(TestVectorAlgorithm::NistP384);
+            let signer = Signer::from_pkcs8(&pkcs8).unwrap();
+
+            let signature: FixedSignature = signer.sign(vector.msg);
+            assert_eq!(signature.as_ref(), vector.sig);
+        }
+
+        // Mismatched public key
+        let vector = signatory::ecdsa::TestVector {
+            pk: SHA384_RFC6979_TEST_VECTORS[2].pk,
+            ..SHA384_RFC6979_TEST_VECTORS[0]
+        };
+        assert!(Signer::from_pkcs8(vector.to_pkcs8(TestVectorAlgorithm::NistP384)).is_err());
+    }
+}
+
+#[cfg(feature = "p521")]
+mod nistp521 {
+    use super::*;
+    use signatory::ecdsa::{
+        generic_array::GenericArray,
+        nistp521::{
+            test_vectors::SHA512_RFC6979_TEST_VECTORS, Asn1Signature, FixedSignature, PublicKey,
+            SecretKey, Signer, Verifier,
+        },
+    };
+
+    /// NIST P-521 curve order
+    const ORDER: &[u8] = b"\x01\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\
+        \xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xfa\x51\x86\
+        \x87\x83\xbf\x2f\x96\x6b\x7f\xcc\x01\x48\xf7\x09\xa5\xd0\x3b\xb5\xc9\xb8\x89\
+        \x9c\x47\xae\xbb\x6f\xb7\x1e\x91\x38\x64\x09";
+
+    /// NIST P-521 field modulus
+    const MODULUS: &[u8] = b"\x01\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\
+        \xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\
+        \xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\
+        \xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff";
+
+    #[test]
+    fn rfc6979_vectors() {
+        for vector in SHA512_RFC6979_TEST_VECTORS {
+            let signer = Signer::new(&SecretKey::from_bytes(vector.sk).unwrap()).unwrap();
+            let public_key = PublicKey::from_untagged_point(GenericArray::from_slice(vector.pk));
+            assert_eq!(signer.public_key().unwrap(), public_key);
+
+            let signature: FixedSignature = signer.sign(vector.msg);
+            assert_eq!(signature.as_ref(), vector.sig);
+
+            let verifier = Verifier::from(&public_key);
+            assert!(verifier.verify(vector.msg, &signature).is_ok());
+            assert!(verifier.verify(b"wrong message", &signature).is_err());
+        }
+    }
+
+    #[test]
+    fn asn1_signature_roundtrip() {
+        let vector = &SHA512_RFC6979_TEST_VECTORS[0];
+        let signer = Signer::new(&SecretKey::from_bytes(vector.sk).unwrap()).unwrap();
+        let signature: Asn1Signature = signer.sign(vector.msg);
+        assert_eq!(
+            FixedSignature::from_asn1(signature.as_ref())
+                .unwrap()
+                .as_ref(),
+            vector.sig
+        );
+
+        let verifier = Verifier::from(&signer.public_key().unwrap());
+        assert!(verifier.verify(vector.msg, &signature).is_ok());
+    }
+
+    #[test]
+    fn rejects_tweaked_fixed_signature() {
+        let vector = &SHA512_RFC6979_TEST_VECTORS[0];
+        let verifier = Verifier::from(&PublicKey::from_untagged_point(GenericArray::from_slice(
+            vector.pk,
+        )));
+
+        let mut tweaked_signature = vector.sig.to_vec();
+        *tweaked_signature.iter_mut().last().unwrap() ^= 42;
+
+        let result = verifier.verify(
+            vector.msg,
+            &FixedSignature::from_bytes(tweaked_signature.as_ref()).unwrap(),
+        );
+
+        assert!(
+            result.is_err(),
+            "expected bad signature to cause validation error!"
+        );
+    }
+
+    #[test]
+    fn rejects_out_of_range_signature_scalars() {
+        let vector = &SHA512_RFC6979_TEST_VECTORS[0];
+        let verifier = Verifier::from(&PublicKey::from_untagged_point(GenericArray::from_slice(
+            vector.pk,
+        )));
+        let (r, s) = vector.sig.split_at(66);
+
+        for (r, s) in &[
+            (vec![0u8; 66], s.to_vec()),
+            (r.to_vec(), vec![0u8; 66]),
+            (ORDER.to_vec(), s.to_vec()),
+            (r.to_vec(), ORDER.to_vec()),
+            // Congruent to the valid `r` and `s` modulo the order, but not reduced
+            (add_be(r, ORDER), s.to_vec()),
+            (r.to_vec(), add_be(s, ORDER)),
+        ] {
+            let signature = FixedSignature::from_bytes(&[&r[..], &s[..]].concat()).unwrap();
+            assert!(verifier.verify(vector.msg, &signature).is_err());
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_public_key_points() {
+        let vector = &SHA512_RFC6979_TEST_VECTORS[0];
+        let signature = FixedSignature::from_bytes(vector.sig).unwrap();
+        let (x, y) = vector.pk.split_at(66);
+
+        let mut off_curve_y = y.to_vec();
+        *off_curve_y.iter_mut().last().unwrap() ^= 1;
+
+        for (x, y) in &[
+            (x.to_vec(), off_curve_y),
+            // Congruent to the valid coordinates modulo p, but not reduced
+            (add_be(x, MODULUS), y.to_vec()),
+            (x.to_vec(), add_be(y, MODULUS)),
+        ] {
+            let point = [&x[..], &y[..]].concat();
+            let public_key = PublicKey::from_untagged_point(GenericArray::from_slice(&point));
+            assert!(Verifier::from(&public_key)
+                .verify(vector.msg, &signature)
+                .is_err());
+        }
+
+        // Compressed points with a non-canonical `x` are rejected too
+        let tag = if y[65] & 1 == 1 { 0x03 } else { 0x02 };
+        let public_key = PublicKey::from_bytes([&[tag][..], x].concat()).unwrap();
+        assert!(Verifier::from(&public_key)
+            .verify(vector.msg, &signature)
+            .is_ok());
+
+        let public_key = PublicKey::from_bytes([&[tag][..], &add_be(x, MODULUS)].concat()).unwrap();
+        assert!(Verifier::from(&public_key)
+            .verify(vector.msg, &signature)
+            .is_err());
+    }
+
+    #[test]
+    fn secret_key_public_key() {
+        for vector in SHA512_RFC6979_TEST_VECTORS {
+            let secret_key = SecretKey::from_bytes(vector.sk).unwrap();
+            let public_key = PublicKey::from_untagged_point(GenericArray::from_slice(vector.pk));
+            assert_eq!(secret_key.public_key().unwrap(), public_key);
+        }
+
+        assert!(SecretKey::from_bytes([0u8; 66])
+            .unwrap()
+            .public_key()
+            .is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_secret_keys() {
+        assert!(Signer::new(&SecretKey::from_bytes([0u8; 66]).unwrap()).is_err());
+        assert!(Signer::new(&SecretKey::from_bytes(ORDER).unwrap()).is_err());
+    }
+
+    #[cfg(feature = "pkcs8")]
+    #[test]
+    fn pkcs8_vectors() {
+        use signatory::{
+            encoding::FromPkcs8,
+            test_vector::{TestVectorAlgorithm, ToPkcs8},
+        };
+
+        for vector in SHA512_RFC6979_TEST_VECTORS {
+            let pkcs8 = vector.to_pkcs8(TestVectorAlgorithm::NistP521);
+            let signer = Signer::from_pkcs8(&pkcs8).unwrap();
+
+            let signature: FixedSignature = signer.sign(vector.msg);
+            assert_eq!(signature.as_ref(), vector.sig);
+        }
+
+        // Mismatched public key
+        let vector = signatory::ecdsa::TestVector {
+            pk: SHA512_RFC6979_TEST_VECTORS[2].pk,
+            ..SHA512_RFC6979_TEST_VECTORS[0]
+        };
+        assert!(Signer::from_pkcs8(vector.to_pkcs8(TestVectorAlgorithm::NistP521)).is_err());
+    }
+}
+
+mod secp256k1 {
+    use super::*;
+    use signatory::ecdsa::{
+        secp256k1::{
+            test_vectors::{SHA256_FIXED_SIZE_TEST_VECTORS, SHA256_RFC6979_TEST_VECTORS},
+            Asn1Signature, FixedSignature, PublicKey, SecretKey, Signer, Verifier,
+        },
+        PointEncoding, PublicKeyedWithEncoding,
+    };
+
+    /// Get the signer's public key as a compressed point (the form used by
+    /// the secp256k1 test vectors)
+    fn compressed_public_key(signer: &Signer) -> PublicKey {
+        signer
+            .public_key_with_encoding(PointEncoding::Compressed)
+            .unwrap()
+    }
+
+    #[test]
+    fn rfc6979_vectors() {
+        for vector in SHA256_RFC6979_TEST_VECTORS {
+            let signer = Signer::new(&SecretKey::from_bytes(vector.sk).unwrap()).unwrap();
+            let public_key = PublicKey::from_bytes(vector.pk).unwrap();
+            assert_eq!(compressed_public_key(&signer), public_key);
+
+            let signature: FixedSignature = signer.sign(vector.msg);
+            assert_eq!(signature.as_ref(), vector.sig);
+
+            let verifier = Verifier::from(&public_key);
+            assert!(verifier.verify(vector.msg, &signature).is_ok());
+        }
+    }
+
+    #[test]
+    fn secret_key_public_key() {
+        for vector in SHA256_RFC6979_TEST_VECTORS {
+            let mut public_key = SecretKey::from_bytes(vector.sk)
+                .unwrap()
+                .public_key()
+                .unwrap();
+            public_key.compress();
+            assert_eq!(public_key.as_bytes(), vector.pk);
+        }
+    }
+
+    #[test]
+    fn fixed_signature_vectors() {
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let signer = Signer::new(&SecretKey::from_bytes(vector.sk).unwrap()).unwrap();
+            let public_key = PublicKey::from_bytes(vector.pk).unwrap();
+            assert_eq!(compressed_public_key(&signer), public_key);
+
+            let signature: FixedSignature = signer.sign(vector.msg);
+            let verifier = Verifier::from(&public_key);
+            assert!(verifier.verify(vector.msg, &signature).is_ok());
+
+            let vector_signature = FixedSignature::from_bytes(vector.sig).unwrap();
+            assert!(verifier.verify(vector.msg, &vector_signature).is_ok());
+        }
+    }
+
+    #[test]
+    fn asn1_signature_roundtrip() {
+        let vector = &SHA256_RFC6979_TEST_VECTORS[0];
+        let signer = Signer::new(&SecretKey::from_bytes(vector.sk).unwrap()).unwrap();
+        let signature: Asn1Signature = signer.sign(vector.msg);
+        assert_eq!(
+            FixedSignature::from_asn1(signature.as_ref())
+                .unwrap()
+                .as_ref(),
+            vector.sig
+        );
+
+        let verifier = Verifier::from(&signer.public_key().unwrap());
+        assert!(verifier.verify(vector.msg, &signature).is_ok());
+    }
+
+    #[test]
+    fn rejects_high_s_signature() {
+        // secp256k1 curve order
+        let order = b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xfe\
+                      \xba\xae\xdc\xe6\xaf\x48\xa0\x3b\xbf\xd2\x5e\x8c\xd0\x36\x41\x41";
+
+        let vector = &SHA256_RFC6979_TEST_VECTORS[0];
+        let (r, s) = vector.sig.split_at(32);
+
+        // Compute `n - s`
+        let mut high_s = [0u8; 32];
+        let mut borrow = 0i16;
+        for i in (0..32).rev() {
+            let diff = i16::from(order[i]) - i16::from(s[i]) - borrow;
+            high_s[i] = diff as u8;
+            borrow = if diff < 0 { 1 } else { 0 };
+        }
+
+        let mut high_s_signature = r.to_vec();
+        high_s_signature.extend_from_slice(&high_s);
+
+        let verifier = Verifier::from(&PublicKey::from_bytes(vector.pk).unwrap());
+        let signature = FixedSignature::from_bytes(&high_s_signature).unwrap();
+        assert!(verifier.verify(vector.msg, &signature).is_err());
+    }
+}
@@ -0,0 +1,237 @@
+//! Tests for strict conversions between ASN.1 DER and fixed-width signatures
+
+use signatory::{
+    ecdsa::SignatureConversion,
+    signature::{Signature, Signer as _, Verifier as _},
+};
+
+/// Encode a signature from the given `INTEGER` contents, which aren't
+/// checked to be minimal
+fn encode(r: &[u8], s: &[u8]) -> Vec<u8> {
+    let len = r.len() + s.len() + 4;
+    let mut der = vec![0x30];
+
+    if len >= 0x80 {
+        der.push(0x81);
+    }
+
+    der.push(len as u8);
+
+    for integer in &[r, s] {
+        der.push(0x02);
+        der.push(integer.len() as u8);
+        der.extend_from_slice(integer);
+    }
+
+    der
+}
+
+mod nistp256 {
+    use super::*;
+    use signatory::{
+        ecdsa::nistp256::{
+            test_vectors::SHA256_RFC6979_TEST_VECTORS, Asn1Signature, FixedSignature, SecretKey,
+            Signer, Verifier,
+        },
+        public_key::PublicKeyed,
+    };
+
+    #[test]
+    fn rfc6979_vectors() {
+        for vector in SHA256_RFC6979_TEST_VECTORS {
+            let fixed = FixedSignature::from_bytes(vector.sig).unwrap();
+            let der = fixed.to_der();
+            assert_eq!(der.to_fixed(), fixed);
+            assert_eq!(der.to_der().as_ref(), der.as_ref());
+
+            let parsed = Asn1Signature::from_der(der.as_ref()).unwrap();
+            assert_eq!(parsed.as_ref(), der.as_ref());
+            assert_eq!(FixedSignature::from_der(der.as_ref()).unwrap(), fixed);
+        }
+    }
+
+    #[test]
+    fn sign_once_verify_both() {
+        let vector = &SHA256_RFC6979_TEST_VECTORS[0];
+        let signer = Signer::new(&SecretKey::from_bytes(vector.sk).unwrap()).unwrap();
+        let verifier = Verifier::from(&signer.public_key().unwrap());
+
+        let fixed: FixedSignature = signer.sign(vector.msg);
+        assert!(verifier.verify(vector.msg, &fixed.to_der()).is_ok());
+
+        let der: Asn1Signature = signer.sign(vector.msg);
+        assert!(verifier.verify(vector.msg, &der.to_fixed()).is_ok());
+        assert_eq!(der.to_fixed(), fixed);
+    }
+}
+
+mod nistp384 {
+    use super::*;
+    use signatory::ecdsa::nistp384::{
+        test_vectors::SHA384_FIXED_SIZE_TEST_VECTORS, Asn1Signature, FixedSignature,
+    };
+
+    #[test]
+    fn fixed_size_vectors() {
+        for vector in SHA384_FIXED_SIZE_TEST_VECTORS {
+            let fixed = FixedSignature::from_bytes(vector.sig).unwrap();
+            let der = fixed.to_der();
+            assert_eq!(der.to_fixed(), fixed);
+
+            let parsed = Asn1Signature::from_der(der.as_ref()).unwrap();
+            assert_eq!(parsed.as_ref(), der.as_ref());
+            assert_eq!(FixedSignature::from_der(der.as_ref()).unwrap(), fixed);
+        }
+    }
+}
+
+mod nistp521 {
+    use super::*;
+    use signatory::ecdsa::nistp521::{
+        test_vectors::SHA512_RFC6979_TEST_VECTORS, Asn1Signature, FixedSignature,
+    };
+
+    #[test]
+    fn rfc6979_vectors() {
+        for vector in SHA512_RFC6979_TEST_VECTORS {
+            let fixed = FixedSignature::from_bytes(vector.sig).unwrap();
+            let der = fixed.to_der();
+            assert_eq!(der.to_fixed(), fixed);
+
+            let parsed = Asn1Signature::from_der(der.as_ref()).unwrap();
+            assert_eq!(parsed.as_ref(), der.as_ref());
+            assert_eq!(FixedSignature::from_der(der.as_ref()).unwrap(), fixed);
+        }
+    }
+
+    #[test]
+    fn long_form_length() {
+        // 66-byte integers need the long form `SEQUENCE` length
+        let der = encode(&[0x01; 66], &[0x01; 66]);
+        assert_eq!(&der[..3], &[0x30, 0x81, 0x88]);
+        assert!(FixedSignature::from_der(&der).is_ok());
+
+        // Long form lengths below 128 must use the short form instead
+        let mut der = encode(&[0x01], &[0x01]);
+        der.insert(1, 0x81);
+        assert!(FixedSignature::from_der(&der).is_err());
+
+        // Multi-byte long form lengths are never minimal here
+        let mut der = encode(&[0x01; 66], &[0x01; 66]);
+        der[1] = 0x82;
+        der.insert(2, 0x00);
+        assert!(FixedSignature::from_der(&der).is_err());
+        assert!(Asn1Signature::from_der(&der).is_err());
+    }
+}
+
+mod secp256k1 {
+    use super::*;
+    use signatory::ecdsa::secp256k1::{
+        test_vectors::SHA256_RFC6979_TEST_VECTORS, Asn1Signature, FixedSignature,
+    };
+
+    /// Create a fixed-width signature from small `r` and `s` values
+    fn fixed_signature(r: u8, s: u8) -> FixedSignature {
+        let mut bytes = [0u8; 64];
+        bytes[31] = r;
+        bytes[63] = s;
+        FixedSignature::from_bytes(&bytes).unwrap()
+    }
+
+    #[test]
+    fn rfc6979_vectors() {
+        for vector in SHA256_RFC6979_TEST_VECTORS {
+            let fixed = FixedSignature::from_bytes(vector.sig).unwrap();
+            let der = fixed.to_der();
+            assert_eq!(der.to_fixed(), fixed);
+
+            let parsed = Asn1Signature::from_der(der.as_ref()).unwrap();
+            assert_eq!(parsed.as_ref(), der.as_ref());
+            assert_eq!(FixedSignature::from_der(der.as_ref()).unwrap(), fixed);
+        }
+    }
+
+    #[test]
+    fn minimal_integers() {
+        assert_eq!(
+            FixedSignature::from_der(&encode(&[0x01], &[0x7f])).unwrap(),
+            fixed_signature(0x01, 0x7f)
+        );
+
+        // A leading zero is needed when the high bit is set
+        let der = encode(&[0x00, 0x80], &[0x00, 0xff]);
+        assert_eq!(fixed_signature(0x80, 0xff).to_der().as_ref(), &der[..]);
+        assert_eq!(
+            FixedSignature::from_der(&der).unwrap(),
+            fixed_signature(0x80, 0xff)
+        );
+
+        let mut max = vec![0x00];
+        max.extend_from_slice(&[0xff; 32]);
+        assert!(FixedSignature::from_der(&encode(&max, &max)).is_ok());
+    }
+
+    #[test]
+    fn rejects_non_minimal_integers() {
+        // Superfluous leading zeroes are accepted by `Asn1Signature::from_bytes`
+        let der = encode(&[0x00, 0x01], &[0x01]);
+        assert!(Asn1Signature::from_bytes(&der).is_ok());
+        assert!(Asn1Signature::from_der(&der).is_err());
+        assert!(FixedSignature::from_der(&der).is_err());
+
+        for (r, s) in &[
+            (&[0x01][..], &[0x00, 0x00, 0x01][..]),
+            (&[0x00, 0x00, 0x80][..], &[0x01][..]),
+            // Negative
+            (&[0x80][..], &[0x01][..]),
+            (&[0x01][..], &[0xff, 0x01][..]),
+            // Empty
+            (&[][..], &[0x01][..]),
+            // Too large
+            (&[0x01; 33][..], &[0x01][..]),
+        ] {
+            assert!(FixedSignature::from_der(&encode(r, s)).is_err());
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_structure() {
+        let der = fixed_signature(0x01, 0x01).to_der();
+        assert!(FixedSignature::from_der(der.as_ref()).is_ok());
+
+        // Trailing data after the `SEQUENCE`
+        let mut trailing = der.as_ref().to_vec();
+        trailing.push(0x00);
+        assert!(FixedSignature::from_der(&trailing).is_err());
+
+        // Trailing data inside the `SEQUENCE`
+        let mut extra = der.as_ref().to_vec();
+        extra.extend_from_slice(&[0x02, 0x01, 0x01]);
+        extra[1] += 3;
+        assert!(FixedSignature::from_der(&extra).is_err());
+
+        // BER indefinite length
+        let mut indefinite = der.as_ref().to_vec();
+        indefinite[1] = 0x80;
+        indefinite.extend_from_slice(&[0x00, 0x00]);
+        assert!(FixedSignature::from_der(&indefinite).is_err());
+
+        // Wrong tags
+        let mut wrong_tag = der.as_ref().to_vec();
+        wrong_tag[0] = 0x31;
+        assert!(FixedSignature::from_der(&wrong_tag).is_err());
+
+        let mut wrong_tag = der.as_ref().to_vec();
+        wrong_tag[2] = 0x03;
+        assert!(FixedSignature::from_der(&wrong_tag).is_err());
+
+        // Truncated
+        for len in 0..der.as_ref().len() {
+            assert!(FixedSignature::from_der(&der.as_ref()[..len]).is_err());
+        }
+
+        // Missing `s`
+        assert!(FixedSignature::from_der(&[0x30, 0x03, 0x02, 0x01, 0x01]).is_err());
+    }
+}
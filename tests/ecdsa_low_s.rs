@@ -0,0 +1,211 @@
+//! Tests for "low S" normalization of ECDSA signatures
+
+use signatory::{
+    ecdsa::{LowS, StrictVerifier},
+    signature::{Signature, Verifier as _},
+};
+
+/// Normalize the given signature, checking the result is in "low S" form
+/// and is only different from the original if it wasn't
+fn normalize<S: LowS + Signature>(signature: &S) -> S {
+    let low_s = signature.to_low_s();
+    assert!(low_s.is_low_s());
+    assert_eq!(low_s.to_low_s().as_ref(), low_s.as_ref());
+
+    if signature.is_low_s() {
+        assert_eq!(low_s.as_ref(), signature.as_ref());
+    } else {
+        assert_ne!(low_s.as_ref(), signature.as_ref());
+    }
+
+    low_s
+}
+
+mod nistp256 {
+    use super::*;
+    use signatory::{
+        batch::{BatchItem, BatchVerifier},
+        ecdsa::{
+            generic_array::GenericArray,
+            nistp256::{
+                test_vectors::SHA256_RFC6979_TEST_VECTORS, FixedSignature, PublicKey, Verifier,
+            },
+        },
+    };
+
+    #[test]
+    fn normalize_rfc6979_vectors() {
+        let mut high_s = 0;
+
+        for vector in SHA256_RFC6979_TEST_VECTORS {
+            let public_key = PublicKey::from_untagged_point(GenericArray::from_slice(vector.pk));
+            let signature = FixedSignature::from_bytes(vector.sig).unwrap();
+            let low_s = normalize(&signature);
+            assert_eq!(low_s.r(), signature.r());
+
+            let asn1_low_s = normalize(&signature.to_asn1());
+            assert_eq!(asn1_low_s.as_ref(), low_s.to_asn1().as_ref());
+
+            // Both forms are valid, but only "low S" is accepted in strict mode
+            let verifier = Verifier::from(&public_key);
+            assert!(verifier.verify(vector.msg, &signature).is_ok());
+            assert!(verifier.verify(vector.msg, &low_s).is_ok());
+
+            let strict_verifier = StrictVerifier::<Verifier>::from(&public_key);
+            assert!(strict_verifier.verify(vector.msg, &low_s).is_ok());
+            assert!(strict_verifier.verify(vector.msg, &asn1_low_s).is_ok());
+
+            if !signature.is_low_s() {
+                high_s += 1;
+                assert!(strict_verifier.verify(vector.msg, &signature).is_err());
+                assert!(strict_verifier
+                    .verify(vector.msg, &signature.to_asn1())
+                    .is_err());
+            }
+        }
+
+        // Make sure the vectors exercise normalization
+        assert!(high_s > 0);
+    }
+
+    #[test]
+    fn strict_batch_verification() {
+        let keys_and_sigs = SHA256_RFC6979_TEST_VECTORS
+            .iter()
+            .map(|vector| {
+                (
+                    PublicKey::from_untagged_point(GenericArray::from_slice(vector.pk)),
+                    FixedSignature::from_bytes(vector.sig).unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let items = SHA256_RFC6979_TEST_VECTORS
+            .iter()
+            .zip(&keys_and_sigs)
+            .map(|(vector, (pk, sig))| BatchItem::new(pk, vector.msg, sig))
+            .collect::<Vec<_>>();
+
+        let expected_failures = keys_and_sigs
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, sig))| !sig.is_low_s())
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+
+        assert_eq!(Verifier::verify_batch(&items), Ok(()));
+        let err = StrictVerifier::<Verifier>::verify_batch(&items).unwrap_err();
+        assert_eq!(err.failed(), &expected_failures[..]);
+    }
+}
+
+mod nistp384 {
+    use super::*;
+    use signatory::ecdsa::nistp384::{
+        test_vectors::SHA384_FIXED_SIZE_TEST_VECTORS, FixedSignature,
+    };
+
+    #[test]
+    fn normalize_fixed_size_vectors() {
+        for vector in SHA384_FIXED_SIZE_TEST_VECTORS {
+            let signature = FixedSignature::from_bytes(vector.sig).unwrap();
+            let low_s = normalize(&signature);
+            assert_eq!(low_s.r(), signature.r());
+
+            let asn1_low_s = normalize(&signature.to_asn1());
+            assert_eq!(asn1_low_s.as_ref(), low_s.to_asn1().as_ref());
+        }
+    }
+}
+
+mod nistp521 {
+    use super::*;
+    use signatory::ecdsa::{
+        generic_array::GenericArray,
+        nistp521::{
+            test_vectors::SHA512_RFC6979_TEST_VECTORS, FixedSignature, PublicKey, Verifier,
+        },
+    };
+
+    #[test]
+    fn normalize_rfc6979_vectors() {
+        for vector in SHA512_RFC6979_TEST_VECTORS {
+            let public_key = PublicKey::from_untagged_point(GenericArray::from_slice(vector.pk));
+            let signature = FixedSignature::from_bytes(vector.sig).unwrap();
+            let low_s = normalize(&signature);
+
+            let asn1_low_s = normalize(&signature.to_asn1());
+            assert_eq!(asn1_low_s.as_ref(), low_s.to_asn1().as_ref());
+
+            let strict_verifier = StrictVerifier::<Verifier>::from(&public_key);
+            assert!(strict_verifier.verify(vector.msg, &low_s).is_ok());
+            assert_eq!(
+                strict_verifier.verify(vector.msg, &signature).is_ok(),
+                signature.is_low_s()
+            );
+        }
+    }
+}
+
+mod secp256k1 {
+    use super::*;
+    use signatory::ecdsa::secp256k1::{
+        test_vectors::SHA256_RFC6979_TEST_VECTORS, FixedSignature, PublicKey, Verifier,
+    };
+
+    /// `n / 2` (rounded down) for the secp256k1 curve order `n`
+    const HALF_ORDER: &[u8] = b"\x7f\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\
+        \x5d\x57\x6e\x73\x57\xa4\x50\x1d\xdf\xe9\x2f\x46\x68\x1b\x20\xa0";
+
+    /// First RFC 6979 test vector signature with `s` replaced by `n - s`
+    const HIGH_S_SIGNATURE: &[u8] = b"\x93\x4b\x1e\xa1\x0a\x4b\x3c\x17\x57\xe2\xb0\xc0\x17\xd0\xb6\x14\
+        \x3c\xe3\xc9\xa7\xe6\xa4\xa4\x98\x60\xd7\xa6\xab\x21\x0e\xe3\xd8\xdb\xbd\x31\x62\xd4\x6e\x9f\
+        \x9b\xef\x7f\xeb\x87\xc1\x6d\xc1\x3b\x4f\x65\x68\xa8\x7f\x4e\x83\xf7\x28\xe2\x44\x3b\xa5\x86\
+        \x67\x5c";
+
+    /// Create a signature with `r = 1` and the given `s`
+    fn signature_with_s(s: &[u8]) -> FixedSignature {
+        let mut bytes = [0u8; 64];
+        bytes[31] = 1;
+        bytes[32..].copy_from_slice(s);
+        FixedSignature::from_bytes(&bytes).unwrap()
+    }
+
+    #[test]
+    fn normalize_high_s_signature() {
+        let vector = &SHA256_RFC6979_TEST_VECTORS[0];
+        let public_key = PublicKey::from_bytes(vector.pk).unwrap();
+        let signature = FixedSignature::from_bytes(HIGH_S_SIGNATURE).unwrap();
+
+        let low_s = normalize(&signature);
+        assert_eq!(low_s.as_ref(), vector.sig);
+        assert_eq!(
+            normalize(&signature.to_asn1()).as_ref(),
+            low_s.to_asn1().as_ref()
+        );
+
+        let strict_verifier = StrictVerifier::<Verifier>::from(&public_key);
+        assert!(strict_verifier.verify(vector.msg, &low_s).is_ok());
+        assert!(strict_verifier.verify(vector.msg, &signature).is_err());
+    }
+
+    #[test]
+    fn normalize_half_order_boundary() {
+        // `s = n / 2` is the largest "low S" value
+        let half = signature_with_s(HALF_ORDER);
+        assert!(half.is_low_s());
+        assert_eq!(half.to_low_s(), half);
+
+        // `n - (n / 2 + 1) = n / 2`
+        let mut above_half = HALF_ORDER.to_vec();
+        *above_half.last_mut().unwrap() += 1;
+        let above_half = signature_with_s(&above_half);
+        assert!(!above_half.is_low_s());
+        assert_eq!(above_half.to_low_s(), half);
+
+        // Invalid signatures (with `s >= n`) are left unchanged
+        let invalid = signature_with_s(&[0xff; 32]);
+        assert!(!invalid.is_low_s());
+        assert_eq!(invalid.to_low_s(), invalid);
+    }
+}
@@ -0,0 +1,184 @@
+//! Tests for ECDSA public key compression and decompression
+
+use core::fmt::Debug;
+use signatory::ecdsa::{generic_array::GenericArray, PointConversion, PointEncoding};
+
+/// Check that converting the given public key to each encoding and back
+/// again is lossless
+fn check_roundtrip<K: PointConversion + Debug + PartialEq>(public_key: &K) {
+    let compressed = public_key.to_compressed().unwrap();
+    let uncompressed = public_key.to_uncompressed().unwrap();
+
+    assert_eq!(compressed.to_uncompressed().unwrap(), uncompressed);
+    assert_eq!(uncompressed.to_compressed().unwrap(), compressed);
+    assert_eq!(compressed.to_compressed().unwrap(), compressed);
+    assert_eq!(
+        public_key.to_encoding(PointEncoding::Uncompressed).unwrap(),
+        uncompressed
+    );
+}
+
+/// Build a compressed point with the given tag and `x`-coordinate
+fn compressed_point(tag: u8, x: &[u8]) -> Vec<u8> {
+    let mut bytes = vec![tag];
+    bytes.extend_from_slice(x);
+    bytes
+}
+
+mod nistp256 {
+    use super::*;
+    use signatory::ecdsa::nistp256::{test_vectors::SHA256_FIXED_SIZE_TEST_VECTORS, PublicKey};
+
+    #[test]
+    fn roundtrip_vectors() {
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS {
+            let public_key = PublicKey::from_untagged_point(GenericArray::from_slice(vector.pk));
+            check_roundtrip(&public_key);
+
+            let compressed = public_key.to_compressed().unwrap();
+            assert_eq!(&compressed.as_bytes()[1..], &vector.pk[..32]);
+            assert_eq!(compressed.as_bytes()[0], 0x02 | (vector.pk[63] & 1));
+        }
+    }
+
+    #[test]
+    fn rejects_points_not_on_curve() {
+        let vector = &SHA256_FIXED_SIZE_TEST_VECTORS[0];
+        let mut pk = vector.pk.to_vec();
+        pk[63] ^= 1;
+
+        let public_key = PublicKey::from_untagged_point(GenericArray::from_slice(&pk));
+        assert!(public_key.to_compressed().is_err());
+        assert!(public_key.to_uncompressed().is_err());
+
+        // `x = 1` isn't the `x`-coordinate of any point on P-256
+        let mut x = [0u8; 32];
+        x[31] = 1;
+
+        let public_key = PublicKey::from_bytes(compressed_point(0x02, &x)).unwrap();
+        assert!(public_key.to_uncompressed().is_err());
+    }
+}
+
+mod nistp384 {
+    use super::*;
+    use signatory::ecdsa::nistp384::{test_vectors::SHA384_FIXED_SIZE_TEST_VECTORS, PublicKey};
+
+    #[test]
+    fn roundtrip_vectors() {
+        for vector in SHA384_FIXED_SIZE_TEST_VECTORS {
+            let public_key = PublicKey::from_untagged_point(GenericArray::from_slice(vector.pk));
+            check_roundtrip(&public_key);
+
+            let compressed = public_key.to_compressed().unwrap();
+            assert_eq!(&compressed.as_bytes()[1..], &vector.pk[..48]);
+            assert_eq!(compressed.as_bytes()[0], 0x02 | (vector.pk[95] & 1));
+        }
+    }
+
+    #[test]
+    fn rejects_points_not_on_curve() {
+        let vector = &SHA384_FIXED_SIZE_TEST_VECTORS[0];
+        let mut pk = vector.pk.to_vec();
+        pk[95] ^= 1;
+
+        let public_key = PublicKey::from_untagged_point(GenericArray::from_slice(&pk));
+        assert!(public_key.to_compressed().is_err());
+        assert!(public_key.to_uncompressed().is_err());
+
+        // `x = 1` isn't the `x`-coordinate of any point on P-384
+        let mut x = [0u8; 48];
+        x[47] = 1;
+
+        let public_key = PublicKey::from_bytes(compressed_point(0x03, &x)).unwrap();
+        assert!(public_key.to_uncompressed().is_err());
+
+        // `x` must be less than the field modulus
+        let public_key = PublicKey::from_bytes(compressed_point(0x02, &[0xff; 48])).unwrap();
+        assert!(public_key.to_uncompressed().is_err());
+    }
+}
+
+mod secp256k1 {
+    use super::*;
+    use signatory::ecdsa::secp256k1::{
+        test_vectors::{SHA256_FIXED_SIZE_TEST_VECTORS, SHA256_RFC6979_TEST_VECTORS},
+        PublicKey,
+    };
+
+    /// Uncompressed encoding of the secp256k1 base point (i.e. the public key
+    /// for the secret key `1`)
+    const GENERATOR: &[u8] = b"\x04\
+        \x79\xbe\x66\x7e\xf9\xdc\xbb\xac\x55\xa0\x62\x95\xce\x87\x0b\x07\
+        \x02\x9b\xfc\xdb\x2d\xce\x28\xd9\x59\xf2\x81\x5b\x16\xf8\x17\x98\
+        \x48\x3a\xda\x77\x26\xa3\xc4\x65\x5d\xa4\xfb\xfc\x0e\x11\x08\xa8\
+        \xfd\x17\xb4\x48\xa6\x85\x54\x19\x9c\x47\xd0\x8f\xfb\x10\xd4\xb8";
+
+    #[test]
+    fn roundtrip_vectors() {
+        for vector in SHA256_FIXED_SIZE_TEST_VECTORS
+            .iter()
+            .chain(SHA256_RFC6979_TEST_VECTORS)
+        {
+            let public_key = PublicKey::from_bytes(vector.pk).unwrap();
+            check_roundtrip(&public_key);
+            assert_eq!(public_key.to_compressed().unwrap(), public_key);
+        }
+    }
+
+    #[test]
+    fn decompress_generator() {
+        let public_key = PublicKey::from_bytes(SHA256_RFC6979_TEST_VECTORS[0].pk).unwrap();
+        let uncompressed = public_key.to_uncompressed().unwrap();
+        assert_eq!(uncompressed.as_bytes(), GENERATOR);
+    }
+
+    #[test]
+    fn rejects_points_not_on_curve() {
+        let mut pk = GENERATOR.to_vec();
+        pk[64] ^= 1;
+
+        let public_key = PublicKey::from_bytes(&pk).unwrap();
+        assert!(public_key.to_compressed().is_err());
+
+        // `x = 5` isn't the `x`-coordinate of any point on secp256k1
+        let mut x = [0u8; 32];
+        x[31] = 5;
+
+        let public_key = PublicKey::from_bytes(compressed_point(0x02, &x)).unwrap();
+        assert!(public_key.to_uncompressed().is_err());
+    }
+}
+
+#[cfg(feature = "p521")]
+mod nistp521 {
+    use super::*;
+    use signatory::ecdsa::nistp521::{test_vectors::SHA512_RFC6979_TEST_VECTORS, PublicKey};
+
+    #[test]
+    fn roundtrip_vectors() {
+        for vector in SHA512_RFC6979_TEST_VECTORS {
+            let public_key = PublicKey::from_untagged_point(GenericArray::from_slice(vector.pk));
+            check_roundtrip(&public_key);
+
+            let compressed = public_key.to_compressed().unwrap();
+            assert_eq!(&compressed.as_bytes()[1..], &vector.pk[..66]);
+            assert_eq!(compressed.as_bytes()[0], 0x02 | (vector.pk[131] & 1));
+        }
+    }
+
+    #[test]
+    fn rejects_points_not_on_curve() {
+        let vector = &SHA512_RFC6979_TEST_VECTORS[0];
+        let mut pk = vector.pk.to_vec();
+        pk[131] ^= 1;
+
+        let public_key = PublicKey::from_untagged_point(GenericArray::from_slice(&pk));
+        assert!(public_key.to_compressed().is_err());
+        assert!(public_key.to_uncompressed().is_err());
+
+        // `x` must be less than the field modulus
+        let public_key = PublicKey::from_bytes(compressed_point(0x02, &[0xff; 66])).unwrap();
+        assert!(public_key.to_uncompressed().is_err());
+    }
+}
@@ -0,0 +1,108 @@
+//! Tests for the built-in pure-Rust Ed25519 provider
+
+use signatory::ed25519::{Signer, VerificationPolicy, Verifier};
+
+signatory::ed25519_tests!(Signer, Verifier, Rfc8032, ed25519ctx, ed25519ph);
+
+#[cfg(feature = "ed25519-batch")]
+signatory::ed25519_batch_tests!(Verifier);
+
+#[test]
+fn verify_edge_case_vectors_under_every_policy() {
+    use signatory::{ed25519::ED25519_EDGE_CASE_VECTORS, signature::Signature};
+
+    for &policy in &[
+        VerificationPolicy::Rfc8032,
+        VerificationPolicy::Zip215,
+        VerificationPolicy::Libsodium,
+    ] {
+        for vector in ED25519_EDGE_CASE_VECTORS {
+            let pk = ed25519::PublicKey::from_bytes(vector.pk).unwrap();
+            let verifier = Verifier::new(&pk, policy);
+            let sig = ed25519::Signature::from_bytes(vector.sig).unwrap();
+            assert_eq!(
+                verifier.verify(vector.msg, &sig).is_ok(),
+                vector.expected(policy),
+                "unexpected result for edge case under {:?}: {}",
+                policy,
+                vector.description
+            );
+        }
+    }
+}
+
+#[test]
+fn public_key_validation() {
+    use signatory::ed25519::{PublicKey, TEST_VECTORS};
+
+    for vector in TEST_VECTORS {
+        assert!(PublicKey::from_bytes_validated(vector.pk).is_some());
+    }
+
+    #[rustfmt::skip]
+    let invalid_keys: &[&[u8]] = &[
+        // identity (small order)
+        b"\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+        // identity with the sign bit set (non-canonical)
+        b"\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x80",
+        // y = p + 1 (non-canonical)
+        b"\xEE\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x7F",
+        // point of order 8
+        b"\xC7\x17\x6A\x70\x3D\x4D\xD8\x4F\xBA\x3C\x0B\x76\x0D\x10\x67\x0F\x2A\x20\x53\xFA\x2C\x39\xCC\xC6\x4E\xC7\xFD\x77\x92\xAC\x03\x7A",
+        // mixed-order point (prime-order point plus a point of order 8)
+        b"\xB5\x02\xFF\x3D\x92\xE3\x1D\x81\x90\xB4\xAA\x4E\xA0\x41\x40\x05\x16\x7F\xAD\x08\x9C\x4D\xE9\xDA\xC8\xA2\xFC\x85\x0F\xED\x4F\x58",
+        // not on the curve
+        b"\x02\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+    ];
+
+    for key in invalid_keys {
+        assert!(PublicKey::from_bytes(key).is_some());
+        assert!(PublicKey::from_bytes_validated(key).is_none());
+    }
+}
+
+#[test]
+fn seed_from_keypair_validated_checks_public_key() {
+    use signatory::ed25519::{Seed, TEST_VECTORS};
+
+    for vector in TEST_VECTORS {
+        let mut keypair = [0u8; 64];
+        keypair[..32].copy_from_slice(vector.sk);
+        keypair[32..].copy_from_slice(vector.pk);
+        assert_eq!(
+            Seed::from_keypair_validated(&keypair)
+                .unwrap()
+                .as_secret_slice(),
+            vector.sk
+        );
+
+        keypair[63] ^= 1;
+        assert!(Seed::from_keypair_validated(&keypair).is_none());
+
+        // The unvalidated constructors ignore the public key half
+        assert!(Seed::from_keypair(&keypair).is_some());
+
+        #[cfg(feature = "encoding")]
+        {
+            use signatory::encoding::Hex;
+
+            let hex_keypair: String = keypair.iter().map(|b| format!("{:02x}", b)).collect();
+            let hex = Hex::lower_case();
+            assert!(Seed::decode_keypair(hex_keypair.as_bytes(), &hex).is_ok());
+            assert!(Seed::decode_keypair_validated(hex_keypair.as_bytes(), &hex).is_err());
+        }
+    }
+}
+
+#[test]
+fn seed_public_key() {
+    use signatory::{
+        ed25519::{Seed, TEST_VECTORS},
+        public_key::PublicKeyed,
+    };
+
+    for vector in TEST_VECTORS {
+        let seed = Seed::from_bytes(vector.sk).unwrap();
+        assert_eq!(seed.public_key().unwrap().as_bytes(), vector.pk);
+    }
+}